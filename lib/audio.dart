@@ -1,10 +1,13 @@
 import 'package:audioplayers/audioplayers.dart';
 import 'dart:math';
+import 'package:amai_music_player/src/rust/api/path.dart';
 import 'package:amai_music_player/src/rust/api/utils.dart';
 
-void playMusic(AudioPlayer audioPlayer, List<Track> musicList, int index) {
-  final musicPath = musicList[index].path;
-  audioPlayer.play(DeviceFileSource(musicPath));
+Future<void> playMusic(
+    AudioPlayer audioPlayer, List<Track> musicList, int index) async {
+  // paths that aren't valid UTF-8 can't be passed as a dart string directly
+  final musicPath = await playbackPath(path: musicList[index].path);
+  await audioPlayer.play(DeviceFileSource(musicPath));
 }
 
 int getRandomIndex(int length, int index) {
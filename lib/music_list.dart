@@ -43,7 +43,8 @@ class MusicTile extends ConsumerWidget {
       title: Text(musicList[tileIndex].name),
       onTap: () =>
           ref.read(musicPlayerProvider.notifier).play(musicList, tileIndex),
-      selected: musicList[tileIndex].id == ref.watch(currentIndexProvider),
+      selected:
+          musicList[tileIndex].id == ref.watch(currentTrackProvider)?.id,
       selectedColor: selectedColor,
       selectedTileColor: selectedTileColor,
       shape: RoundedRectangleBorder(
@@ -7,8 +7,19 @@ import 'package:amai_music_player/src/rust/api/utils.dart';
 
 part 'providers.g.dart'; // needed for build_runner & riverpod_generator
 
+// track ids are stable across rescans, so look up where the current track sits in the list
 @riverpod
-int? currentIndex(CurrentIndexRef ref) => ref.watch(currentTrackProvider)?.id;
+int? currentIndex(CurrentIndexRef ref) {
+  final currentTrack = ref.watch(currentTrackProvider);
+  if (currentTrack == null) {
+    return null;
+  }
+
+  final index = ref
+      .watch(searchedMusicListProvider)
+      .indexWhere((track) => track.id == currentTrack.id);
+  return index >= 0 ? index : null;
+}
 
 @riverpod
 AudioPlayer trackPlayer(TrackPlayerRef ref) {
@@ -1,10 +0,0 @@
-// This file is automatically generated, so please do not edit it.
-// Generated by `flutter_rust_bridge`@ 2.0.0-dev.23.
-
-// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
-
-import '../frb_generated.dart';
-import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
-
-Future<List<String>> getMusicFiles({dynamic hint}) =>
-    RustLib.instance.api.getMusicFiles(hint: hint);
@@ -0,0 +1,82 @@
+
+        // This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.24.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'metadata.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+import 'path.dart';
+part 'artwork.freezed.dart';
+
+        /// Lists every picture embedded in the file, without their image data.
+Future<List<PictureInfo>> getPictures({required TrackPath path , dynamic hint}) => RustLib.instance.api.getPictures(path: path, hint: hint);
+
+/// Returns the image data of the picture at `index` in `get_pictures`.
+Future<Uint8List?> getPictureData({required TrackPath path , required int index , dynamic hint}) => RustLib.instance.api.getPictureData(path: path, index: index, hint: hint);
+
+        @freezed
+                sealed class ArtSource with _$ArtSource  {
+                     const factory ArtSource.embedded({   required PictureType pictureType , }) = ArtSource_Embedded;
+ /// An image file next to the track, or next to its disc folder.
+const factory ArtSource.folder({   required TrackPath path , }) = ArtSource_Folder;
+                }
+
+class PictureInfo  {
+                /// Pass to `get_picture_data` to load this picture.
+final int index;
+final PictureType pictureType;
+final String? mimeType;
+/// Only known for PNG and JPEG.
+final int? width;
+final int? height;
+final String? description;
+final int size;
+
+                const PictureInfo({required this.index ,required this.pictureType ,this.mimeType ,this.width ,this.height ,this.description ,required this.size ,});
+
+                
+
+                
+        @override
+        int get hashCode => index.hashCode^pictureType.hashCode^mimeType.hashCode^width.hashCode^height.hashCode^description.hashCode^size.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PictureInfo &&
+                runtimeType == other.runtimeType
+                && index == other.index&& pictureType == other.pictureType&& mimeType == other.mimeType&& width == other.width&& height == other.height&& description == other.description&& size == other.size;
+        
+            }
+
+@freezed
+                sealed class PictureType with _$PictureType  {
+                     const factory PictureType.other() = PictureType_Other;
+ const factory PictureType.icon() = PictureType_Icon;
+ const factory PictureType.otherIcon() = PictureType_OtherIcon;
+ const factory PictureType.coverFront() = PictureType_CoverFront;
+ const factory PictureType.coverBack() = PictureType_CoverBack;
+ const factory PictureType.leaflet() = PictureType_Leaflet;
+ const factory PictureType.media() = PictureType_Media;
+ const factory PictureType.leadArtist() = PictureType_LeadArtist;
+ const factory PictureType.artist() = PictureType_Artist;
+ const factory PictureType.conductor() = PictureType_Conductor;
+ const factory PictureType.band() = PictureType_Band;
+ const factory PictureType.composer() = PictureType_Composer;
+ const factory PictureType.lyricist() = PictureType_Lyricist;
+ const factory PictureType.recordingLocation() = PictureType_RecordingLocation;
+ const factory PictureType.duringRecording() = PictureType_DuringRecording;
+ const factory PictureType.duringPerformance() = PictureType_DuringPerformance;
+ const factory PictureType.screenCapture() = PictureType_ScreenCapture;
+ const factory PictureType.brightFish() = PictureType_BrightFish;
+ const factory PictureType.illustration() = PictureType_Illustration;
+ const factory PictureType.bandLogo() = PictureType_BandLogo;
+ const factory PictureType.publisherLogo() = PictureType_PublisherLogo;
+ const factory PictureType.undefined(  int field0,) = PictureType_Undefined;
+                }
+        
\ No newline at end of file
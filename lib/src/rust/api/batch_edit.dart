@@ -0,0 +1,176 @@
+
+        // This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.24.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'library.dart';
+import 'metadata.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'path.dart';
+
+
+        /// Shows what `apply_batch_edit` would change without writing anything.
+Future<List<BatchFileResult>> previewBatchEdit({required List<TrackPath> paths , required BatchEdit edit , dynamic hint}) => RustLib.instance.api.previewBatchEdit(paths: paths, edit: edit, hint: hint);
+
+/// Applies `edit` to every file, carrying on past files that fail.
+///
+/// The previous values of every written file are kept in one journal, so the
+/// whole batch can be rolled back with `undo_batch_edit`.
+Future<BatchOutcome> applyBatchEdit({required List<BatchFile> files , required BatchEdit edit , dynamic hint}) => RustLib.instance.api.applyBatchEdit(files: files, edit: edit, hint: hint);
+
+/// Restores the values a batch edit replaced.
+///
+/// Files edited again since are left alone and stay in the journal, the
+/// journal is deleted once every file was restored.
+Future<List<BatchFileResult>> undoBatchEdit({required String journalId , dynamic hint}) => RustLib.instance.api.undoBatchEdit(journalId: journalId, hint: hint);
+
+        /// The same changes for every file in a batch.
+class BatchEdit  {
+                final MetadataChanges changes;
+/// Numbers the tracks in the order they were passed in, overriding `changes.track_number`.
+final Renumber? renumber;
+
+                const BatchEdit({required this.changes ,this.renumber ,});
+
+                
+
+                
+        @override
+        int get hashCode => changes.hashCode^renumber.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BatchEdit &&
+                runtimeType == other.runtimeType
+                && changes == other.changes&& renumber == other.renumber;
+        
+            }
+
+/// A file as it was previewed, so the edit is refused if it changed since.
+class BatchFile  {
+                final TrackPath path;
+final FileStamp stamp;
+
+                const BatchFile({required this.path ,required this.stamp ,});
+
+                
+
+                
+        @override
+        int get hashCode => path.hashCode^stamp.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BatchFile &&
+                runtimeType == other.runtimeType
+                && path == other.path&& stamp == other.stamp;
+        
+            }
+
+class BatchFileResult  {
+                final TrackPath path;
+/// Stamp of the file after the preview or edit, `None` if it could not be read.
+final FileStamp? stamp;
+/// Only the fields that differ, empty when the file already matches.
+final List<FieldChange> changes;
+final MetadataError? error;
+
+                const BatchFileResult({required this.path ,this.stamp ,required this.changes ,this.error ,});
+
+                
+
+                
+        @override
+        int get hashCode => path.hashCode^stamp.hashCode^changes.hashCode^error.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BatchFileResult &&
+                runtimeType == other.runtimeType
+                && path == other.path&& stamp == other.stamp&& changes == other.changes&& error == other.error;
+        
+            }
+
+class BatchOutcome  {
+                /// Pass to `undo_batch_edit` to restore the previous values, `None` if nothing was written.
+final String? journalId;
+final List<BatchFileResult> results;
+
+                const BatchOutcome({this.journalId ,required this.results ,});
+
+                
+
+                
+        @override
+        int get hashCode => journalId.hashCode^results.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BatchOutcome &&
+                runtimeType == other.runtimeType
+                && journalId == other.journalId&& results == other.results;
+        
+            }
+
+class FieldChange  {
+                final MetadataField field;
+final String? before;
+final String? after;
+
+                const FieldChange({required this.field ,this.before ,this.after ,});
+
+                
+
+                
+        @override
+        int get hashCode => field.hashCode^before.hashCode^after.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is FieldChange &&
+                runtimeType == other.runtimeType
+                && field == other.field&& before == other.before&& after == other.after;
+        
+            }
+
+class Renumber  {
+                final int start;
+/// Also sets the track total to the number of files in the batch.
+final bool setTotal;
+
+                const Renumber({required this.start ,required this.setTotal ,});
+
+                
+
+                
+        @override
+        int get hashCode => start.hashCode^setTotal.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Renumber &&
+                runtimeType == other.runtimeType
+                && start == other.start&& setTotal == other.setTotal;
+        
+            }
+        
\ No newline at end of file
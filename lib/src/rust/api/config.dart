@@ -0,0 +1,47 @@
+
+        // This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.24.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'path.dart';
+
+
+        Future<List<LibraryRoot>> listLibraryRoots({dynamic hint}) => RustLib.instance.api.listLibraryRoots(hint: hint);
+
+Future<LibraryRoot> addLibraryRoot({required TrackPath path , dynamic hint}) => RustLib.instance.api.addLibraryRoot(path: path, hint: hint);
+
+Future<void> removeLibraryRoot({required TrackPath path , dynamic hint}) => RustLib.instance.api.removeLibraryRoot(path: path, hint: hint);
+
+Future<LibraryRoot> setLibraryRootEnabled({required TrackPath path , required bool enabled , dynamic hint}) => RustLib.instance.api.setLibraryRootEnabled(path: path, enabled: enabled, hint: hint);
+
+Future<List<String>> getIgnorePatterns({dynamic hint}) => RustLib.instance.api.getIgnorePatterns(hint: hint);
+
+/// Replaces the global ignore patterns, which use the same syntax as `.amaiignore` files.
+Future<void> setIgnorePatterns({required List<String> patterns , dynamic hint}) => RustLib.instance.api.setIgnorePatterns(patterns: patterns, hint: hint);
+
+        class LibraryRoot  {
+                final TrackPath path;
+final bool enabled;
+
+                const LibraryRoot({required this.path ,required this.enabled ,});
+
+                
+
+                
+        @override
+        int get hashCode => path.hashCode^enabled.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LibraryRoot &&
+                runtimeType == other.runtimeType
+                && path == other.path&& enabled == other.enabled;
+        
+            }
+        
\ No newline at end of file
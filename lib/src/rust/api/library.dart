@@ -0,0 +1,158 @@
+
+        // This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.24.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'path.dart';
+import 'utils.dart';
+
+
+        /// Rescans the library folders and reports what changed, so callers can update in place.
+Future<LibraryDiff> rescanLibrary({int? maxDepth , dynamic hint}) => RustLib.instance.api.rescanLibrary(maxDepth: maxDepth, hint: hint);
+
+/// Returns the library as of the last scan without touching the disk, so it is cheap to call at startup.
+Future<List<LibraryTrack>> getLibraryTracks({dynamic hint}) => RustLib.instance.api.getLibraryTracks(hint: hint);
+
+Future<LibraryTrack?> getLibraryTrack({required int id , dynamic hint}) => RustLib.instance.api.getLibraryTrack(id: id, hint: hint);
+
+Future<List<LibraryTrack>> searchLibrary({required String query , dynamic hint}) => RustLib.instance.api.searchLibrary(query: query, hint: hint);
+
+Future<List<Album>> getAlbums({dynamic hint}) => RustLib.instance.api.getAlbums(hint: hint);
+
+Future<List<LibraryTrack>> getAlbumTracks({required String album , String? artist , dynamic hint}) => RustLib.instance.api.getAlbumTracks(album: album, artist: artist, hint: hint);
+
+        class Album  {
+                final String name;
+final String? artist;
+final int trackCount;
+/// Sum of the durations of its tracks.
+final int durationMs;
+
+                const Album({required this.name ,this.artist ,required this.trackCount ,required this.durationMs ,});
+
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^artist.hashCode^trackCount.hashCode^durationMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Album &&
+                runtimeType == other.runtimeType
+                && name == other.name&& artist == other.artist&& trackCount == other.trackCount&& durationMs == other.durationMs;
+        
+            }
+
+/// Size and modification time of a file, used to tell whether it changed since it was read.
+class FileStamp  {
+                final int size;
+/// Milliseconds since the unix epoch.
+final int mtime;
+
+                const FileStamp({required this.size ,required this.mtime ,});
+
+                
+
+                
+        @override
+        int get hashCode => size.hashCode^mtime.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is FileStamp &&
+                runtimeType == other.runtimeType
+                && size == other.size&& mtime == other.mtime;
+        
+            }
+
+/// What changed in the library since the previous scan.
+class LibraryDiff  {
+                final List<LibraryTrack> added;
+/// Ids of tracks that are gone.
+final Uint64List removed;
+final List<LibraryTrack> modified;
+final List<TrackMove> moved;
+
+                const LibraryDiff({required this.added ,required this.removed ,required this.modified ,required this.moved ,});
+
+                
+
+                
+        @override
+        int get hashCode => added.hashCode^removed.hashCode^modified.hashCode^moved.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LibraryDiff &&
+                runtimeType == other.runtimeType
+                && added == other.added&& removed == other.removed&& modified == other.modified&& moved == other.moved;
+        
+            }
+
+class LibraryTrack  {
+                final Track track;
+final String? title;
+final String? artist;
+final String? album;
+final String? albumArtist;
+final String? genre;
+final int? year;
+final int? trackNumber;
+final int? discNumber;
+final int? durationMs;
+
+                const LibraryTrack({required this.track ,this.title ,this.artist ,this.album ,this.albumArtist ,this.genre ,this.year ,this.trackNumber ,this.discNumber ,this.durationMs ,});
+
+                
+
+                
+        @override
+        int get hashCode => track.hashCode^title.hashCode^artist.hashCode^album.hashCode^albumArtist.hashCode^genre.hashCode^year.hashCode^trackNumber.hashCode^discNumber.hashCode^durationMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LibraryTrack &&
+                runtimeType == other.runtimeType
+                && track == other.track&& title == other.title&& artist == other.artist&& album == other.album&& albumArtist == other.albumArtist&& genre == other.genre&& year == other.year&& trackNumber == other.trackNumber&& discNumber == other.discNumber&& durationMs == other.durationMs;
+        
+            }
+
+class TrackMove  {
+                final TrackPath from;
+final LibraryTrack track;
+
+                const TrackMove({required this.from ,required this.track ,});
+
+                
+
+                
+        @override
+        int get hashCode => from.hashCode^track.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TrackMove &&
+                runtimeType == other.runtimeType
+                && from == other.from&& track == other.track;
+        
+            }
+        
\ No newline at end of file
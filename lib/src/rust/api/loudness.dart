@@ -0,0 +1,146 @@
+
+        // This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.24.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'metadata.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+import 'path.dart';
+part 'loudness.freezed.dart';
+
+        /// Measures EBU R128 loudness of every track on all cores and stores the
+/// results in the library.
+///
+/// With `album` set the tracks are treated as one album and also get album
+/// values. With `write_tags` set the results are written as `REPLAYGAIN_*`
+/// tags, or as `R128_*` gains for Opus files.
+Future<LoudnessReport> analyzeLoudness({required List<TrackPath> paths , required bool album , required bool writeTags , dynamic hint}) => RustLib.instance.api.analyzeLoudness(paths: paths, album: album, writeTags: writeTags, hint: hint);
+
+/// Returns the stored analysis of a track, unless the file changed since.
+Future<TrackLoudness?> getTrackLoudness({required TrackPath path , dynamic hint}) => RustLib.instance.api.getTrackLoudness(path: path, hint: hint);
+
+/// Linear volume factor to play a track at, from `Metadata::replay_gain`.
+///
+/// Falls back to the other gain when the preferred one is missing, and to 1.0
+/// for untagged tracks. With `prevent_clipping` the factor is capped so the
+/// peak stays at full scale. Factors above 1.0 need headroom from the player
+/// volume, multiply them in before clamping.
+double replayGainVolume({required ReplayGain gain , required ReplayGainMode mode , required double preampDb , required bool preventClipping , dynamic hint}) => RustLib.instance.api.replayGainVolume(gain: gain, mode: mode, preampDb: preampDb, preventClipping: preventClipping, hint: hint);
+
+        class AlbumLoudness  {
+                final double integratedLufs;
+final double loudnessRangeLu;
+final double truePeak;
+final double gainDb;
+
+                const AlbumLoudness({required this.integratedLufs ,required this.loudnessRangeLu ,required this.truePeak ,required this.gainDb ,});
+
+                
+
+                
+        @override
+        int get hashCode => integratedLufs.hashCode^loudnessRangeLu.hashCode^truePeak.hashCode^gainDb.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is AlbumLoudness &&
+                runtimeType == other.runtimeType
+                && integratedLufs == other.integratedLufs&& loudnessRangeLu == other.loudnessRangeLu&& truePeak == other.truePeak&& gainDb == other.gainDb;
+        
+            }
+
+class LoudnessFailure  {
+                final TrackPath path;
+final String error;
+
+                const LoudnessFailure({required this.path ,required this.error ,});
+
+                
+
+                
+        @override
+        int get hashCode => path.hashCode^error.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LoudnessFailure &&
+                runtimeType == other.runtimeType
+                && path == other.path&& error == other.error;
+        
+            }
+
+class LoudnessReport  {
+                final List<TrackLoudness> tracks;
+/// Only for analyses run with `album` set.
+final AlbumLoudness? album;
+final List<LoudnessFailure> failed;
+
+                const LoudnessReport({required this.tracks ,this.album ,required this.failed ,});
+
+                
+
+                
+        @override
+        int get hashCode => tracks.hashCode^album.hashCode^failed.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LoudnessReport &&
+                runtimeType == other.runtimeType
+                && tracks == other.tracks&& album == other.album&& failed == other.failed;
+        
+            }
+
+@freezed
+                sealed class ReplayGainMode with _$ReplayGainMode  {
+                     const factory ReplayGainMode.off() = ReplayGainMode_Off;
+ const factory ReplayGainMode.track() = ReplayGainMode_Track;
+ const factory ReplayGainMode.album() = ReplayGainMode_Album;
+ /// Album gain while an album plays in order, track gain otherwise, e.g. on shuffle.
+const factory ReplayGainMode.smart({   required bool albumInOrder , }) = ReplayGainMode_Smart;
+                }
+
+class TrackLoudness  {
+                final TrackPath path;
+final double integratedLufs;
+final double loudnessRangeLu;
+/// Linear, 1.0 is full scale.
+final double truePeak;
+/// ReplayGain 2.0 gain that brings the track to -18 LUFS.
+final double trackGainDb;
+final double? albumGainDb;
+final double? albumTruePeak;
+/// Set when the analysis worked, but writing the tags did not.
+final String? tagError;
+
+                const TrackLoudness({required this.path ,required this.integratedLufs ,required this.loudnessRangeLu ,required this.truePeak ,required this.trackGainDb ,this.albumGainDb ,this.albumTruePeak ,this.tagError ,});
+
+                
+
+                
+        @override
+        int get hashCode => path.hashCode^integratedLufs.hashCode^loudnessRangeLu.hashCode^truePeak.hashCode^trackGainDb.hashCode^albumGainDb.hashCode^albumTruePeak.hashCode^tagError.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TrackLoudness &&
+                runtimeType == other.runtimeType
+                && path == other.path&& integratedLufs == other.integratedLufs&& loudnessRangeLu == other.loudnessRangeLu&& truePeak == other.truePeak&& trackGainDb == other.trackGainDb&& albumGainDb == other.albumGainDb&& albumTruePeak == other.albumTruePeak&& tagError == other.tagError;
+        
+            }
+        
\ No newline at end of file
@@ -0,0 +1,181 @@
+
+        // This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.24.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'metadata.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+import 'path.dart';
+part 'lyrics.freezed.dart';
+
+        /// Builds an LRC file from plain lyrics and the times a user tapped along to them.
+///
+/// Every non-empty line needs exactly one tap, in order; blank lines between
+/// verses are dropped. Save the result with `save_lrc_sidecar`, or into the
+/// tag by passing it as `MetadataChanges::lyrics` to `write_metadata`.
+Future<String> buildLrc({required String text , required Uint64List tapsMs , dynamic hint}) => RustLib.instance.api.buildLrc(text: text, tapsMs: tapsMs, hint: hint);
+
+/// Moves timestamps in an LRC file by `delta_ms`, positive values show lyrics later.
+///
+/// With `from_line` set, only that line of the file and the ones after it are
+/// moved, for when the sync drifts partway through. Word stamps move along
+/// with their lines, everything else is kept as is.
+Future<String> nudgeLrc({required String lrc , required int deltaMs , int? fromLine , dynamic hint}) => RustLib.instance.api.nudgeLrc(lrc: lrc, deltaMs: deltaMs, fromLine: fromLine, hint: hint);
+
+/// Writes `lrc` next to the track as `song.lrc`, or `song.<language>.lrc`,
+/// replacing any existing file of that name.
+Future<TrackPath> saveLrcSidecar({required TrackPath path , required String lrc , String? language , dynamic hint}) => RustLib.instance.api.saveLrcSidecar(path: path, lrc: lrc, language: language, hint: hint);
+
+/// Returns all lyrics of a track, `.lrc` files next to it first and then
+/// the ones in its tags.
+///
+/// Sidecars are matched by name, `song.lrc` and per language `song.en.lrc`.
+Future<List<Lyrics>> getLyrics({required TrackPath path , dynamic hint}) => RustLib.instance.api.getLyrics(path: path, hint: hint);
+
+        
+            // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::rust_async::RwLock<LyricsTimeline>>
+            @sealed class LyricsTimeline extends RustOpaque {
+                LyricsTimeline.dcoDecode(List<dynamic> wire): super.dcoDecode(wire, _kStaticData);
+
+                LyricsTimeline.sseDecode(int ptr, int externalSizeOnNative):
+                    super.sseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_LyricsTimeline,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_LyricsTimeline,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_LyricsTimelinePtr,
+                );
+
+                /// Index into `Lyrics::lines` of the line being sung at `position_ms`,
+/// `None` before the first line and for unsynced lyrics.
+ int?  lineIndexAt({ required int positionMs ,dynamic hint })=>RustLib.instance.api.lyricsTimelineLineIndexAt(that: this, positionMs:positionMs,);
+
+
+/// Line and word being sung at `position_ms`.
+///
+/// Both are binary searches, cheap enough to call on every frame.
+ LyricsPosition  lyricsAt({ required int positionMs ,dynamic hint })=>RustLib.instance.api.lyricsTimelineLyricsAt(that: this, positionMs:positionMs,);
+
+
+            }
+
+class LyricLine  {
+                /// `None` for unsynced lyrics.
+final int? startMs;
+final String text;
+/// Word timings from enhanced LRC or `SYLT`, empty if only the line is synced.
+final List<LyricWord> words;
+
+                const LyricLine({this.startMs ,required this.text ,required this.words ,});
+
+                
+
+                
+        @override
+        int get hashCode => startMs.hashCode^text.hashCode^words.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LyricLine &&
+                runtimeType == other.runtimeType
+                && startMs == other.startMs&& text == other.text&& words == other.words;
+        
+            }
+
+class LyricWord  {
+                final int startMs;
+/// Only known when the source marks it, otherwise the word lasts until the next one.
+final int? endMs;
+/// Includes the whitespace around it, so the words joined give the line.
+final String text;
+
+                const LyricWord({required this.startMs ,this.endMs ,required this.text ,});
+
+                
+
+                
+        @override
+        int get hashCode => startMs.hashCode^endMs.hashCode^text.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LyricWord &&
+                runtimeType == other.runtimeType
+                && startMs == other.startMs&& endMs == other.endMs&& text == other.text;
+        
+            }
+
+class Lyrics  {
+                final LyricsSource source;
+/// ISO 639 code, e.g. `eng` from a tag or `en` from `song.en.lrc`.
+final String? language;
+/// Whether the lines carry timestamps.
+final bool synced;
+/// Already applied to the timestamps, positive values show lines earlier.
+final int offsetMs;
+final List<LyricLine> lines;
+final LyricsTimeline timeline;
+
+                const Lyrics({required this.source ,this.language ,required this.synced ,required this.offsetMs ,required this.lines ,required this.timeline ,});
+
+                
+
+                
+        @override
+        int get hashCode => source.hashCode^language.hashCode^synced.hashCode^offsetMs.hashCode^lines.hashCode^timeline.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Lyrics &&
+                runtimeType == other.runtimeType
+                && source == other.source&& language == other.language&& synced == other.synced&& offsetMs == other.offsetMs&& lines == other.lines&& timeline == other.timeline;
+        
+            }
+
+class LyricsPosition  {
+                /// Index into `Lyrics::lines`, `None` before the first line and for unsynced lyrics.
+final int? line;
+/// Index into the line's `words`, `None` for lines without word timings.
+final int? word;
+/// How far into the current word playback is, from 0 to 1, for a smooth wipe.
+final double wordProgress;
+
+                const LyricsPosition({this.line ,this.word ,required this.wordProgress ,});
+
+                
+
+                
+        @override
+        int get hashCode => line.hashCode^word.hashCode^wordProgress.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LyricsPosition &&
+                runtimeType == other.runtimeType
+                && line == other.line&& word == other.word&& wordProgress == other.wordProgress;
+        
+            }
+
+@freezed
+                sealed class LyricsSource with _$LyricsSource  {
+                     const factory LyricsSource.tag() = LyricsSource_Tag;
+ /// An ID3 `SYLT` frame, which is always synced.
+const factory LyricsSource.syncedTag() = LyricsSource_SyncedTag;
+ const factory LyricsSource.sidecar({   required TrackPath path , }) = LyricsSource_Sidecar;
+                }
+        
\ No newline at end of file
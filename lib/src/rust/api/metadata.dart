@@ -1,52 +1,193 @@
-// This file is automatically generated, so please do not edit it.
+
+        // This file is automatically generated, so please do not edit it.
 // Generated by `flutter_rust_bridge`@ 2.0.0-dev.24.
 
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../frb_generated.dart';
+import 'artwork.dart';
+import 'library.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+import 'path.dart';
+part 'metadata.freezed.dart';
+
+        Future<Metadata> getMetadata({required TrackPath path , dynamic hint}) => RustLib.instance.api.getMetadata(path: path, hint: hint);
+
+/// Applies `changes` to the primary tag of the file, creating one if there is none.
+///
+/// Fails with `MetadataError::Modified` unless the file still matches `expected`
+/// from `get_metadata`. Returns the stamp of the written file for follow-up edits.
+Future<FileStamp> writeMetadata({required TrackPath path , required MetadataChanges changes , required FileStamp expected , dynamic hint}) => RustLib.instance.api.writeMetadata(path: path, changes: changes, expected: expected, hint: hint);
+
+/// Reads only the stream properties, skipping the artwork and tags.
+Future<AudioProperties> getAudioProperties({required TrackPath path , dynamic hint}) => RustLib.instance.api.getAudioProperties(path: path, hint: hint);
+
+        /// Stream properties read from the file headers, not the tags.
+class AudioProperties  {
+                final int durationMs;
+/// Bitrate of the whole file in kbps, including tags and container overhead.
+final int? overallBitrate;
+/// Bitrate of the audio stream alone in kbps.
+final int? audioBitrate;
+final int? sampleRate;
+/// Only known for lossless formats.
+final int? bitDepth;
+final int? channels;
+
+                const AudioProperties({required this.durationMs ,this.overallBitrate ,this.audioBitrate ,this.sampleRate ,this.bitDepth ,this.channels ,});
+
+                
+
+                
+        @override
+        int get hashCode => durationMs.hashCode^overallBitrate.hashCode^audioBitrate.hashCode^sampleRate.hashCode^bitDepth.hashCode^channels.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is AudioProperties &&
+                runtimeType == other.runtimeType
+                && durationMs == other.durationMs&& overallBitrate == other.overallBitrate&& audioBitrate == other.audioBitrate&& sampleRate == other.sampleRate&& bitDepth == other.bitDepth&& channels == other.channels;
+        
+            }
+
+class Metadata  {
+                /// The front cover, or the picture closest to one.
+final Uint8List? art;
+final ArtSource? artSource;
+final String? title;
+final String? artist;
+final String? album;
+final String? albumArtist;
+final int? trackNumber;
+final int? trackTotal;
+final int? discNumber;
+final int? discTotal;
+final int? year;
+final String? genre;
+final String? composer;
+final String? comment;
+final String? titleSort;
+final String? artistSort;
+final String? albumSort;
+final String? albumArtistSort;
+final AudioProperties properties;
+final ReplayGain replayGain;
+/// The file as it was read, hand this to `write_metadata` to detect concurrent changes.
+final FileStamp stamp;
+
+                const Metadata({this.art ,this.artSource ,this.title ,this.artist ,this.album ,this.albumArtist ,this.trackNumber ,this.trackTotal ,this.discNumber ,this.discTotal ,this.year ,this.genre ,this.composer ,this.comment ,this.titleSort ,this.artistSort ,this.albumSort ,this.albumArtistSort ,required this.properties ,required this.replayGain ,required this.stamp ,});
+
+                
+
+                
+        @override
+        int get hashCode => art.hashCode^artSource.hashCode^title.hashCode^artist.hashCode^album.hashCode^albumArtist.hashCode^trackNumber.hashCode^trackTotal.hashCode^discNumber.hashCode^discTotal.hashCode^year.hashCode^genre.hashCode^composer.hashCode^comment.hashCode^titleSort.hashCode^artistSort.hashCode^albumSort.hashCode^albumArtistSort.hashCode^properties.hashCode^replayGain.hashCode^stamp.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Metadata &&
+                runtimeType == other.runtimeType
+                && art == other.art&& artSource == other.artSource&& title == other.title&& artist == other.artist&& album == other.album&& albumArtist == other.albumArtist&& trackNumber == other.trackNumber&& trackTotal == other.trackTotal&& discNumber == other.discNumber&& discTotal == other.discTotal&& year == other.year&& genre == other.genre&& composer == other.composer&& comment == other.comment&& titleSort == other.titleSort&& artistSort == other.artistSort&& albumSort == other.albumSort&& albumArtistSort == other.albumArtistSort&& properties == other.properties&& replayGain == other.replayGain&& stamp == other.stamp;
+        
+            }
+
+/// A partial tag update, fields left as `None` keep their current value.
+class MetadataChanges  {
+                final String? title;
+final String? artist;
+final String? album;
+final String? albumArtist;
+final int? trackNumber;
+final int? trackTotal;
+final int? discNumber;
+final int? discTotal;
+final int? year;
+final String? genre;
+final String? composer;
+final String? comment;
+/// Unsynced lyrics, or a whole LRC file for players that read synced lyrics from tags.
+final String? lyrics;
+/// Fields to remove from the tag, applied before the values above.
+final List<MetadataField> clear;
+
+                const MetadataChanges({this.title ,this.artist ,this.album ,this.albumArtist ,this.trackNumber ,this.trackTotal ,this.discNumber ,this.discTotal ,this.year ,this.genre ,this.composer ,this.comment ,this.lyrics ,required this.clear ,});
+
+                
+
+                
+        @override
+        int get hashCode => title.hashCode^artist.hashCode^album.hashCode^albumArtist.hashCode^trackNumber.hashCode^trackTotal.hashCode^discNumber.hashCode^discTotal.hashCode^year.hashCode^genre.hashCode^composer.hashCode^comment.hashCode^lyrics.hashCode^clear.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is MetadataChanges &&
+                runtimeType == other.runtimeType
+                && title == other.title&& artist == other.artist&& album == other.album&& albumArtist == other.albumArtist&& trackNumber == other.trackNumber&& trackTotal == other.trackTotal&& discNumber == other.discNumber&& discTotal == other.discTotal&& year == other.year&& genre == other.genre&& composer == other.composer&& comment == other.comment&& lyrics == other.lyrics&& clear == other.clear;
+        
+            }
+
+@freezed
+                sealed class MetadataError with _$MetadataError implements FrbException {
+                     const factory MetadataError.notFound() = MetadataError_NotFound;
+ const factory MetadataError.unsupportedFormat() = MetadataError_UnsupportedFormat;
+ /// The format was recognized, but its tags or headers could not be parsed.
+const factory MetadataError.corruptTag(  String field0,) = MetadataError_CorruptTag;
+ /// The file changed on disk since it was read, read it again before writing.
+const factory MetadataError.modified() = MetadataError_Modified;
+ const factory MetadataError.io(  String field0,) = MetadataError_Io;
+                }
+
+enum MetadataField {
+                    title,
+artist,
+album,
+albumArtist,
+trackNumber,
+trackTotal,
+discNumber,
+discTotal,
+year,
+genre,
+composer,
+comment,
+lyrics,
+                }
+
+/// Gains from `REPLAYGAIN_*` or Opus `R128_*` tags, all relative to the
+/// ReplayGain reference level of -18 LUFS.
+class ReplayGain  {
+                final double? trackGainDb;
+/// Linear, 1.0 is full scale.
+final double? trackPeak;
+final double? albumGainDb;
+final double? albumPeak;
+
+                const ReplayGain({this.trackGainDb ,this.trackPeak ,this.albumGainDb ,this.albumPeak ,});
+
+                
+
+                
+        @override
+        int get hashCode => trackGainDb.hashCode^trackPeak.hashCode^albumGainDb.hashCode^albumPeak.hashCode;
+        
 
-Future<Metadata> getMetadata({required String path, dynamic hint}) =>
-    RustLib.instance.api.getMetadata(path: path, hint: hint);
-
-// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::rust_async::RwLock<Tag>>
-@sealed
-class Tag extends RustOpaque {
-  Tag.dcoDecode(List<dynamic> wire) : super.dcoDecode(wire, _kStaticData);
-
-  Tag.sseDecode(int ptr, int externalSizeOnNative)
-      : super.sseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount:
-        RustLib.instance.api.rust_arc_increment_strong_count_Tag,
-    rustArcDecrementStrongCount:
-        RustLib.instance.api.rust_arc_decrement_strong_count_Tag,
-    rustArcDecrementStrongCountPtr:
-        RustLib.instance.api.rust_arc_decrement_strong_count_TagPtr,
-  );
-}
-
-class Metadata {
-  final Tag? tag;
-  final Uint8List? art;
-  final String? title;
-
-  const Metadata({
-    this.tag,
-    this.art,
-    this.title,
-  });
-
-  @override
-  int get hashCode => tag.hashCode ^ art.hashCode ^ title.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is Metadata &&
-          runtimeType == other.runtimeType &&
-          tag == other.tag &&
-          art == other.art &&
-          title == other.title;
-}
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ReplayGain &&
+                runtimeType == other.runtimeType
+                && trackGainDb == other.trackGainDb&& trackPeak == other.trackPeak&& albumGainDb == other.albumGainDb&& albumPeak == other.albumPeak;
+        
+            }
+        
\ No newline at end of file
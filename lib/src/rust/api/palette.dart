@@ -0,0 +1,71 @@
+
+        // This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.24.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'path.dart';
+
+
+        /// Picks colors from the artwork of a track for tinting the player, or
+/// `None` if the track has no artwork.
+///
+/// Palettes are cached by artwork, so all tracks of an album share one.
+Future<Palette?> getPalette({required TrackPath path , dynamic hint}) => RustLib.instance.api.getPalette(path: path, hint: hint);
+
+        class Palette  {
+                /// The most common color.
+final Swatch dominant;
+/// A saturated color of medium lightness, `None` for grayscale artwork.
+final Swatch? vibrant;
+/// A desaturated color of medium lightness.
+final Swatch? muted;
+
+                const Palette({required this.dominant ,this.vibrant ,this.muted ,});
+
+                
+
+                
+        @override
+        int get hashCode => dominant.hashCode^vibrant.hashCode^muted.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Palette &&
+                runtimeType == other.runtimeType
+                && dominant == other.dominant&& vibrant == other.vibrant&& muted == other.muted;
+        
+            }
+
+class Swatch  {
+                /// `0xAARRGGBB`, ready for Dart's `Color`.
+final int color;
+/// Black or white, whichever is easier to read on `color`.
+final int foreground;
+/// Share of the artwork's pixels close to this color, from 0 to 1.
+final double population;
+
+                const Swatch({required this.color ,required this.foreground ,required this.population ,});
+
+                
+
+                
+        @override
+        int get hashCode => color.hashCode^foreground.hashCode^population.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Swatch &&
+                runtimeType == other.runtimeType
+                && color == other.color&& foreground == other.foreground&& population == other.population;
+        
+            }
+        
\ No newline at end of file
@@ -0,0 +1,43 @@
+
+        // This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.24.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+        /// Returns a path the Dart side can open for playback.
+///
+/// Dart strings can't hold paths that aren't valid UTF-8, those get a link
+/// with a UTF-8 name in the cache folder instead.
+Future<String> playbackPath({required TrackPath path , dynamic hint}) => RustLib.instance.api.playbackPath(path: path, hint: hint);
+
+        /// A file path exactly as the OS stores it, so names that aren't valid UTF-8
+/// survive the trip to Dart and back.
+class TrackPath  {
+                /// The raw OS path, hand this back to Rust as is.
+final Uint8List raw;
+/// Lossy UTF-8 version of the path, only meant for showing to the user.
+final String display;
+
+                const TrackPath({required this.raw ,required this.display ,});
+
+                
+
+                
+        @override
+        int get hashCode => raw.hashCode^display.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TrackPath &&
+                runtimeType == other.runtimeType
+                && raw == other.raw&& display == other.display;
+        
+            }
+        
\ No newline at end of file
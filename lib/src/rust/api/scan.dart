@@ -0,0 +1,148 @@
+
+        // This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.24.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'library.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+import 'path.dart';
+import 'utils.dart';
+part 'scan.freezed.dart';
+
+        /// Scans and stores the library like `get_music_files`, but also reports every
+/// file that was left out and why.
+Future<ScanReport> scanLibrary({int? maxDepth , dynamic hint}) => RustLib.instance.api.scanLibrary(maxDepth: maxDepth, hint: hint);
+
+/// Scans the library like `rescan_library`, reading files on all cores and
+/// streaming progress along the way. Stops early once `token` is cancelled.
+Stream<ScanEvent> scanLibraryWithProgress({required ScanCancelToken token , int? maxDepth , dynamic hint}) => RustLib.instance.api.scanLibraryWithProgress(token: token, maxDepth: maxDepth, hint: hint);
+
+        
+            // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::rust_async::RwLock<ScanCancelToken>>
+            @sealed class ScanCancelToken extends RustOpaque {
+                ScanCancelToken.dcoDecode(List<dynamic> wire): super.dcoDecode(wire, _kStaticData);
+
+                ScanCancelToken.sseDecode(int ptr, int externalSizeOnNative):
+                    super.sseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_ScanCancelToken,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_ScanCancelToken,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_ScanCancelTokenPtr,
+                );
+
+                 void  cancel({ dynamic hint })=>RustLib.instance.api.scanCancelTokenCancel(that: this, );
+
+
+static ScanCancelToken  newScanCancelToken({ dynamic hint })=>RustLib.instance.api.scanCancelTokenNew(hint: hint);
+
+
+            }
+
+@freezed
+                sealed class ScanEvent with _$ScanEvent  {
+                     const factory ScanEvent.progress(  ScanProgress field0,) = ScanEvent_Progress;
+ const factory ScanEvent.skipped(  SkippedFile field0,) = ScanEvent_Skipped;
+ const factory ScanEvent.finished(  LibraryDiff field0,) = ScanEvent_Finished;
+ const factory ScanEvent.cancelled() = ScanEvent_Cancelled;
+                }
+
+enum ScanPhase {
+                    /// Walking the library folders, only `files_seen` moves.
+discovering,
+/// Sniffing which of the files are audio.
+detecting,
+/// Reading tags of new and changed files.
+readingTags,
+/// Writing the results to the library database.
+saving,
+                }
+
+class ScanProgress  {
+                final ScanPhase phase;
+final int filesSeen;
+/// How much of the current phase is done, out of `files_total`.
+final int filesDone;
+final int filesTotal;
+final String? currentPath;
+
+                const ScanProgress({required this.phase ,required this.filesSeen ,required this.filesDone ,required this.filesTotal ,this.currentPath ,});
+
+                
+
+                
+        @override
+        int get hashCode => phase.hashCode^filesSeen.hashCode^filesDone.hashCode^filesTotal.hashCode^currentPath.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ScanProgress &&
+                runtimeType == other.runtimeType
+                && phase == other.phase&& filesSeen == other.filesSeen&& filesDone == other.filesDone&& filesTotal == other.filesTotal&& currentPath == other.currentPath;
+        
+            }
+
+class ScanReport  {
+                final List<Track> tracks;
+final List<SkippedFile> skipped;
+
+                const ScanReport({required this.tracks ,required this.skipped ,});
+
+                
+
+                
+        @override
+        int get hashCode => tracks.hashCode^skipped.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ScanReport &&
+                runtimeType == other.runtimeType
+                && tracks == other.tracks&& skipped == other.skipped;
+        
+            }
+
+@freezed
+                sealed class SkipReason with _$SkipReason  {
+                     const factory SkipReason.permissionDenied() = SkipReason_PermissionDenied;
+ /// Looks like audio, but isn't a format we can read.
+const factory SkipReason.unsupportedFormat() = SkipReason_UnsupportedFormat;
+ /// The format was recognized, but the file could not be parsed.
+const factory SkipReason.corruptHeader() = SkipReason_CorruptHeader;
+ /// A symlink pointing back to one of its own parent folders.
+const factory SkipReason.symlinkLoop() = SkipReason_SymlinkLoop;
+ const factory SkipReason.io(  String field0,) = SkipReason_Io;
+                }
+
+class SkippedFile  {
+                final TrackPath path;
+final SkipReason reason;
+
+                const SkippedFile({required this.path ,required this.reason ,});
+
+                
+
+                
+        @override
+        int get hashCode => path.hashCode^reason.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SkippedFile &&
+                runtimeType == other.runtimeType
+                && path == other.path&& reason == other.reason;
+        
+            }
+        
\ No newline at end of file
@@ -0,0 +1,50 @@
+
+        // This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.24.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'path.dart';
+
+
+        /// Returns the artwork of a track scaled down to fit into `size` pixels,
+/// or `None` if the track has no artwork.
+///
+/// Results are cached on disk by artwork and size, so every album cover is
+/// only decoded once per size.
+Future<Thumbnail?> getThumbnail({required TrackPath path , required int size , dynamic hint}) => RustLib.instance.api.getThumbnail(path: path, size: size, hint: hint);
+
+/// `get_thumbnail` for many tracks at once, e.g. a screen of the track list.
+///
+/// Tracks whose artwork could not be read get `None` rather than failing the rest.
+Future<List<Thumbnail?>> getThumbnails({required List<TrackPath> paths , required int size , dynamic hint}) => RustLib.instance.api.getThumbnails(paths: paths, size: size, hint: hint);
+
+Future<void> clearThumbnailCache({dynamic hint}) => RustLib.instance.api.clearThumbnailCache(hint: hint);
+
+        class Thumbnail  {
+                /// JPEG encoded image that fits into the requested size.
+final Uint8List data;
+/// Hash of the original artwork, tracks that share a cover share it.
+final String artHash;
+
+                const Thumbnail({required this.data ,required this.artHash ,});
+
+                
+
+                
+        @override
+        int get hashCode => data.hashCode^artHash.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Thumbnail &&
+                runtimeType == other.runtimeType
+                && data == other.data&& artHash == other.artHash;
+        
+            }
+        
\ No newline at end of file
@@ -1,34 +1,55 @@
-// This file is automatically generated, so please do not edit it.
+
+        // This file is automatically generated, so please do not edit it.
 // Generated by `flutter_rust_bridge`@ 2.0.0-dev.24.
 
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'path.dart';
+
+
+        /// Scans every enabled library folder and records the result in the library database.
+Future<List<Track>> getMusicFiles({int? maxDepth , dynamic hint}) => RustLib.instance.api.getMusicFiles(maxDepth: maxDepth, hint: hint);
+
+        enum AudioFormat {
+                    aac,
+aiff,
+ape,
+flac,
+mpeg,
+mp4,
+mpc,
+opus,
+vorbis,
+speex,
+wav,
+wavPack,
+                }
+
+class Track  {
+                final String name;
+final TrackPath path;
+/// Stays the same across rescans, renames and moves.
+final int? id;
+final AudioFormat format;
+
+                const Track({required this.name ,required this.path ,this.id ,required this.format ,});
+
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^path.hashCode^id.hashCode^format.hashCode;
+        
 
-Future<List<Track>> getMusicFiles({dynamic hint}) =>
-    RustLib.instance.api.getMusicFiles(hint: hint);
-
-class Track {
-  final String name;
-  final String path;
-  final int? id;
-
-  const Track({
-    required this.name,
-    required this.path,
-    this.id,
-  });
-
-  @override
-  int get hashCode => name.hashCode ^ path.hashCode ^ id.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is Track &&
-          runtimeType == other.runtimeType &&
-          name == other.name &&
-          path == other.path &&
-          id == other.id;
-}
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Track &&
+                runtimeType == other.runtimeType
+                && name == other.name&& path == other.path&& id == other.id&& format == other.format;
+        
+            }
+        
\ No newline at end of file
@@ -0,0 +1,29 @@
+
+        // This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.24.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'library.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+import 'path.dart';
+import 'utils.dart';
+part 'watcher.freezed.dart';
+
+        /// Watches every enabled library folder and streams changes to the library as they happen.
+///
+/// Replaces any previous watcher, e.g. after the library folders were changed.
+Stream<LibraryEvent> watchLibrary({dynamic hint}) => RustLib.instance.api.watchLibrary(hint: hint);
+
+Future<void> stopWatchingLibrary({dynamic hint}) => RustLib.instance.api.stopWatchingLibrary(hint: hint);
+
+        @freezed
+                sealed class LibraryEvent with _$LibraryEvent  {
+                     const factory LibraryEvent.trackAdded(  LibraryTrack field0,) = LibraryEvent_TrackAdded;
+ const factory LibraryEvent.trackRemoved({   required int id , }) = LibraryEvent_TrackRemoved;
+ const factory LibraryEvent.trackChanged(  LibraryTrack field0,) = LibraryEvent_TrackChanged;
+ const factory LibraryEvent.trackMoved({   required TrackPath from ,  required LibraryTrack track , }) = LibraryEvent_TrackMoved;
+                }
+        
\ No newline at end of file
@@ -3,557 +3,3370 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+import 'api/artwork.dart';
+import 'api/batch_edit.dart';
+import 'api/config.dart';
+import 'api/library.dart';
+import 'api/loudness.dart';
+import 'api/lyrics.dart';
 import 'api/metadata.dart';
+import 'api/palette.dart';
+import 'api/path.dart';
+import 'api/scan.dart';
+import 'api/thumbnail.dart';
 import 'api/utils.dart';
+import 'api/watcher.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.io.dart' if (dart.library.html) 'frb_generated.web.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-/// Main entrypoint of the Rust API
-class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
-  @internal
-  static final instance = RustLib._();
-
-  RustLib._();
-
-  /// Initialize flutter_rust_bridge
-  static Future<void> init({
-    RustLibApi? api,
-    BaseHandler? handler,
-    ExternalLibrary? externalLibrary,
-  }) async {
-    await instance.initImpl(
-      api: api,
-      handler: handler,
-      externalLibrary: externalLibrary,
-    );
-  }
-
-  /// Dispose flutter_rust_bridge
-  ///
-  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
-  /// is automatically disposed when the app stops.
-  static void dispose() => instance.disposeImpl();
-
-  @override
-  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor =>
-      RustLibApiImpl.new;
-
-  @override
-  WireConstructor<RustLibWire> get wireConstructor =>
-      RustLibWire.fromExternalLibrary;
-
-  @override
-  Future<void> executeRustInitializers() async {}
-
-  @override
-  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig =>
-      kDefaultExternalLibraryLoaderConfig;
-
-  @override
-  String get codegenVersion => '2.0.0-dev.24';
-
-  static const kDefaultExternalLibraryLoaderConfig =
-      ExternalLibraryLoaderConfig(
-    stem: 'rust_lib',
-    ioDirectory: 'rust/target/release/',
-    webPrefix: 'pkg/',
-  );
-}
-
-abstract class RustLibApi extends BaseApi {
-  Future<Metadata> getMetadata({required String path, dynamic hint});
-
-  Future<List<Track>> getMusicFiles({dynamic hint});
-
-  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Tag;
-
-  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Tag;
-
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_TagPtr;
-}
-
-class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
-  RustLibApiImpl({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
-
-  @override
-  Future<Metadata> getMetadata({required String path, dynamic hint}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(path, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 1, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_metadata,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kGetMetadataConstMeta,
-      argValues: [path],
-      apiImpl: this,
-      hint: hint,
-    ));
-  }
-
-  TaskConstMeta get kGetMetadataConstMeta => const TaskConstMeta(
-        debugName: "get_metadata",
-        argNames: ["path"],
-      );
-
-  @override
-  Future<List<Track>> getMusicFiles({dynamic hint}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 2, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_list_track,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kGetMusicFilesConstMeta,
-      argValues: [],
-      apiImpl: this,
-      hint: hint,
-    ));
-  }
-
-  TaskConstMeta get kGetMusicFilesConstMeta => const TaskConstMeta(
-        debugName: "get_music_files",
-        argNames: [],
-      );
-
-  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Tag => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockTag;
-
-  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Tag => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockTag;
-
-  @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AnyhowException(raw as String);
-  }
-
-  @protected
-  Tag dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockTag(
-      dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Tag.dcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  Tag dco_decode_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockTag(
-      dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Tag.dcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
-  }
-
-  @protected
-  Tag dco_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockTag(
-      dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Tag;
-  }
-
-  @protected
-  int dco_decode_box_autoadd_usize(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_usize(raw);
-  }
-
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
-  }
-
-  @protected
-  List<Track> dco_decode_list_track(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_track).toList();
-  }
-
-  @protected
-  Metadata dco_decode_metadata(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return Metadata(
-      tag:
-          dco_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockTag(
-              arr[0]),
-      art: dco_decode_opt_list_prim_u_8_strict(arr[1]),
-      title: dco_decode_opt_String(arr[2]),
-    );
-  }
-
-  @protected
-  String? dco_decode_opt_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_String(raw);
-  }
-
-  @protected
-  Tag?
-      dco_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockTag(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null
-        ? null
-        : dco_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockTag(
-            raw);
-  }
-
-  @protected
-  int? dco_decode_opt_box_autoadd_usize(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_usize(raw);
-  }
-
-  @protected
-  Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_list_prim_u_8_strict(raw);
-  }
-
-  @protected
-  Track dco_decode_track(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return Track(
-      name: dco_decode_String(arr[0]),
-      path: dco_decode_String(arr[1]),
-      id: dco_decode_opt_box_autoadd_usize(arr[2]),
-    );
-  }
-
-  @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  int dco_decode_usize(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeI64OrU64(raw);
-  }
-
-  @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_String(deserializer);
-    return AnyhowException(inner);
-  }
-
-  @protected
-  Tag sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockTag(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return Tag.sseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  Tag sse_decode_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockTag(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return Tag.sseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  String sse_decode_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
-  }
-
-  @protected
-  Tag sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockTag(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockTag(
-        deserializer));
-  }
-
-  @protected
-  int sse_decode_box_autoadd_usize(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_usize(deserializer));
-  }
-
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
-  }
-
-  @protected
-  List<Track> sse_decode_list_track(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <Track>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_track(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  Metadata sse_decode_metadata(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_tag =
-        sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockTag(
-            deserializer);
-    var var_art = sse_decode_opt_list_prim_u_8_strict(deserializer);
-    var var_title = sse_decode_opt_String(deserializer);
-    return Metadata(tag: var_tag, art: var_art, title: var_title);
-  }
-
-  @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_String(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  Tag?
-      sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockTag(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockTag(
-          deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  int? sse_decode_opt_box_autoadd_usize(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_usize(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_list_prim_u_8_strict(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  Track sse_decode_track(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_name = sse_decode_String(deserializer);
-    var var_path = sse_decode_String(deserializer);
-    var var_id = sse_decode_opt_box_autoadd_usize(deserializer);
-    return Track(name: var_name, path: var_path, id: var_id);
-  }
-
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
-  }
-
-  @protected
-  int sse_decode_usize(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint64();
-  }
-
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
-  }
-
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
-  }
-
-  @protected
-  void sse_encode_AnyhowException(
-      AnyhowException self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    throw UnimplementedError('Unreachable ((');
-  }
-
-  @protected
-  void
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockTag(
-          Tag self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(self.sseEncode(move: true), serializer);
-  }
-
-  @protected
-  void
-      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockTag(
-          Tag self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(self.sseEncode(move: null), serializer);
-  }
-
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
-  }
-
-  @protected
-  void
-      sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockTag(
-          Tag self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockTag(
-        self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_usize(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(self, serializer);
-  }
-
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-      Uint8List self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
-  }
-
-  @protected
-  void sse_encode_list_track(List<Track> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_track(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_metadata(Metadata self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockTag(
-        self.tag, serializer);
-    sse_encode_opt_list_prim_u_8_strict(self.art, serializer);
-    sse_encode_opt_String(self.title, serializer);
-  }
-
-  @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_String(self, serializer);
-    }
-  }
-
-  @protected
-  void
-      sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockTag(
-          Tag? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockTag(
-          self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_usize(int? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_usize(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_list_prim_u_8_strict(
-      Uint8List? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_list_prim_u_8_strict(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_track(Track self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.name, serializer);
-    sse_encode_String(self.path, serializer);
-    sse_encode_opt_box_autoadd_usize(self.id, serializer);
-  }
-
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self);
-  }
-
-  @protected
-  void sse_encode_usize(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint64(self);
-  }
-
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
-  }
-
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
-  }
-}
+
+                /// Main entrypoint of the Rust API
+                class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
+                  @internal
+                  static final instance = RustLib._();
+
+                  RustLib._();
+
+                  /// Initialize flutter_rust_bridge
+                  static Future<void> init({
+                    RustLibApi? api,
+                    BaseHandler? handler,
+                    ExternalLibrary? externalLibrary,
+                  }) async {
+                    await instance.initImpl(
+                      api: api,
+                      handler: handler,
+                      externalLibrary: externalLibrary,
+                    );
+                  }
+                  
+                  /// Dispose flutter_rust_bridge
+                  ///
+                  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
+                  /// is automatically disposed when the app stops.
+                  static void dispose() => instance.disposeImpl();
+
+                  @override
+                  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor => RustLibApiImpl.new;
+
+                  @override
+                  WireConstructor<RustLibWire> get wireConstructor => RustLibWire.fromExternalLibrary;
+                  
+                  @override
+                  Future<void> executeRustInitializers() async {
+                    
+                  }
+
+                  @override
+                  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig => kDefaultExternalLibraryLoaderConfig;
+
+                  @override
+                  String get codegenVersion => '2.0.0-dev.24';
+                  
+                  static const kDefaultExternalLibraryLoaderConfig = ExternalLibraryLoaderConfig(
+                    stem: 'rust_lib',
+                    ioDirectory: 'rust/target/release/',
+                    webPrefix: 'pkg/',
+                  );
+                }
+                
+
+                abstract class RustLibApi extends BaseApi {
+                  Future<Uint8List?> getPictureData({required TrackPath path , required int index , dynamic hint});
+
+Future<List<PictureInfo>> getPictures({required TrackPath path , dynamic hint});
+
+Future<BatchOutcome> applyBatchEdit({required List<BatchFile> files , required BatchEdit edit , dynamic hint});
+
+Future<List<BatchFileResult>> previewBatchEdit({required List<TrackPath> paths , required BatchEdit edit , dynamic hint});
+
+Future<List<BatchFileResult>> undoBatchEdit({required String journalId , dynamic hint});
+
+Future<LibraryRoot> addLibraryRoot({required TrackPath path , dynamic hint});
+
+Future<List<String>> getIgnorePatterns({dynamic hint});
+
+Future<List<LibraryRoot>> listLibraryRoots({dynamic hint});
+
+Future<void> removeLibraryRoot({required TrackPath path , dynamic hint});
+
+Future<void> setIgnorePatterns({required List<String> patterns , dynamic hint});
+
+Future<LibraryRoot> setLibraryRootEnabled({required TrackPath path , required bool enabled , dynamic hint});
+
+Future<List<LibraryTrack>> getAlbumTracks({required String album , String? artist , dynamic hint});
+
+Future<List<Album>> getAlbums({dynamic hint});
+
+Future<LibraryTrack?> getLibraryTrack({required int id , dynamic hint});
+
+Future<List<LibraryTrack>> getLibraryTracks({dynamic hint});
+
+Future<LibraryDiff> rescanLibrary({int? maxDepth , dynamic hint});
+
+Future<List<LibraryTrack>> searchLibrary({required String query , dynamic hint});
+
+Future<LoudnessReport> analyzeLoudness({required List<TrackPath> paths , required bool album , required bool writeTags , dynamic hint});
+
+Future<TrackLoudness?> getTrackLoudness({required TrackPath path , dynamic hint});
+
+double replayGainVolume({required ReplayGain gain , required ReplayGainMode mode , required double preampDb , required bool preventClipping , dynamic hint});
+
+int? lyricsTimelineLineIndexAt({required LyricsTimeline that , required int positionMs , dynamic hint});
+
+LyricsPosition lyricsTimelineLyricsAt({required LyricsTimeline that , required int positionMs , dynamic hint});
+
+Future<String> buildLrc({required String text , required Uint64List tapsMs , dynamic hint});
+
+Future<List<Lyrics>> getLyrics({required TrackPath path , dynamic hint});
+
+Future<String> nudgeLrc({required String lrc , required int deltaMs , int? fromLine , dynamic hint});
+
+Future<TrackPath> saveLrcSidecar({required TrackPath path , required String lrc , String? language , dynamic hint});
+
+Future<AudioProperties> getAudioProperties({required TrackPath path , dynamic hint});
+
+Future<Metadata> getMetadata({required TrackPath path , dynamic hint});
+
+Future<FileStamp> writeMetadata({required TrackPath path , required MetadataChanges changes , required FileStamp expected , dynamic hint});
+
+Future<Palette?> getPalette({required TrackPath path , dynamic hint});
+
+Future<String> playbackPath({required TrackPath path , dynamic hint});
+
+void scanCancelTokenCancel({required ScanCancelToken that , dynamic hint});
+
+ScanCancelToken scanCancelTokenNew({dynamic hint});
+
+Future<ScanReport> scanLibrary({int? maxDepth , dynamic hint});
+
+Stream<ScanEvent> scanLibraryWithProgress({required ScanCancelToken token , int? maxDepth , dynamic hint});
+
+Future<void> clearThumbnailCache({dynamic hint});
+
+Future<Thumbnail?> getThumbnail({required TrackPath path , required int size , dynamic hint});
+
+Future<List<Thumbnail?>> getThumbnails({required List<TrackPath> paths , required int size , dynamic hint});
+
+Future<List<Track>> getMusicFiles({int? maxDepth , dynamic hint});
+
+Future<void> stopWatchingLibrary({dynamic hint});
+
+Stream<LibraryEvent> watchLibrary({dynamic hint});
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_LyricsTimeline;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_LyricsTimeline;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_LyricsTimelinePtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_ScanCancelToken;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_ScanCancelToken;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ScanCancelTokenPtr;
+
+
+                }
+                
+
+                class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
+                  RustLibApiImpl({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
+
+                  @override Future<Uint8List?> getPictureData({required TrackPath path , required int index , dynamic hint}) {
+            return handler.executeNormal(NormalTask(
+                callFfi: (port_) {
+                  
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_track_path(path, serializer);
+sse_encode_usize(index, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2, port: port_);
+            
+                },
+                codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_metadata_error,
+        )
+        ,
+                constMeta: kGetPictureDataConstMeta,
+                argValues: [path, index],
+                apiImpl: this,
+                hint: hint,
+            ));
+        }
+
+
+        TaskConstMeta get kGetPictureDataConstMeta => const TaskConstMeta(
+            debugName: "get_picture_data",
+            argNames: ["path", "index"],
+        );
+        
+
+@override Future<List<PictureInfo>> getPictures({required TrackPath path , dynamic hint}) {
+            return handler.executeNormal(NormalTask(
+                callFfi: (port_) {
+                  
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_track_path(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1, port: port_);
+            
+                },
+                codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_picture_info,
+          decodeErrorData: sse_decode_metadata_error,
+        )
+        ,
+                constMeta: kGetPicturesConstMeta,
+                argValues: [path],
+                apiImpl: this,
+                hint: hint,
+            ));
+        }
+
+
+        TaskConstMeta get kGetPicturesConstMeta => const TaskConstMeta(
+            debugName: "get_pictures",
+            argNames: ["path"],
+        );
+        
+
+@override Future<BatchOutcome> applyBatchEdit({required List<BatchFile> files , required BatchEdit edit , dynamic hint}) {
+            return handler.executeNormal(NormalTask(
+                callFfi: (port_) {
+                  
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_batch_file(files, serializer);
+sse_encode_box_autoadd_batch_edit(edit, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4, port: port_);
+            
+                },
+                codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_batch_outcome,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+                constMeta: kApplyBatchEditConstMeta,
+                argValues: [files, edit],
+                apiImpl: this,
+                hint: hint,
+            ));
+        }
+
+
+        TaskConstMeta get kApplyBatchEditConstMeta => const TaskConstMeta(
+            debugName: "apply_batch_edit",
+            argNames: ["files", "edit"],
+        );
+        
+
+@override Future<List<BatchFileResult>> previewBatchEdit({required List<TrackPath> paths , required BatchEdit edit , dynamic hint}) {
+            return handler.executeNormal(NormalTask(
+                callFfi: (port_) {
+                  
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_track_path(paths, serializer);
+sse_encode_box_autoadd_batch_edit(edit, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3, port: port_);
+            
+                },
+                codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_batch_file_result,
+          decodeErrorData: null,
+        )
+        ,
+                constMeta: kPreviewBatchEditConstMeta,
+                argValues: [paths, edit],
+                apiImpl: this,
+                hint: hint,
+            ));
+        }
+
+
+        TaskConstMeta get kPreviewBatchEditConstMeta => const TaskConstMeta(
+            debugName: "preview_batch_edit",
+            argNames: ["paths", "edit"],
+        );
+        
+
+@override Future<List<BatchFileResult>> undoBatchEdit({required String journalId , dynamic hint}) {
+            return handler.executeNormal(NormalTask(
+                callFfi: (port_) {
+                  
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(journalId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5, port: port_);
+            
+                },
+                codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_batch_file_result,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+                constMeta: kUndoBatchEditConstMeta,
+                argValues: [journalId],
+                apiImpl: this,
+                hint: hint,
+            ));
+        }
+
+
+        TaskConstMeta get kUndoBatchEditConstMeta => const TaskConstMeta(
+            debugName: "undo_batch_edit",
+            argNames: ["journalId"],
+        );
+        
+
+@override Future<LibraryRoot> addLibraryRoot({required TrackPath path , dynamic hint}) {
+            return handler.executeNormal(NormalTask(
+                callFfi: (port_) {
+                  
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_track_path(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7, port: port_);
+            
+                },
+                codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_library_root,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+                constMeta: kAddLibraryRootConstMeta,
+                argValues: [path],
+                apiImpl: this,
+                hint: hint,
+            ));
+        }
+
+
+        TaskConstMeta get kAddLibraryRootConstMeta => const TaskConstMeta(
+            debugName: "add_library_root",
+            argNames: ["path"],
+        );
+        
+
+@override Future<List<String>> getIgnorePatterns({dynamic hint}) {
+            return handler.executeNormal(NormalTask(
+                callFfi: (port_) {
+                  
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10, port: port_);
+            
+                },
+                codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+                constMeta: kGetIgnorePatternsConstMeta,
+                argValues: [],
+                apiImpl: this,
+                hint: hint,
+            ));
+        }
+
+
+        TaskConstMeta get kGetIgnorePatternsConstMeta => const TaskConstMeta(
+            debugName: "get_ignore_patterns",
+            argNames: [],
+        );
+        
+
+@override Future<List<LibraryRoot>> listLibraryRoots({dynamic hint}) {
+            return handler.executeNormal(NormalTask(
+                callFfi: (port_) {
+                  
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6, port: port_);
+            
+                },
+                codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_library_root,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+                constMeta: kListLibraryRootsConstMeta,
+                argValues: [],
+                apiImpl: this,
+                hint: hint,
+            ));
+        }
+
+
+        TaskConstMeta get kListLibraryRootsConstMeta => const TaskConstMeta(
+            debugName: "list_library_roots",
+            argNames: [],
+        );
+        
+
+@override Future<void> removeLibraryRoot({required TrackPath path , dynamic hint}) {
+            return handler.executeNormal(NormalTask(
+                callFfi: (port_) {
+                  
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_track_path(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8, port: port_);
+            
+                },
+                codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+                constMeta: kRemoveLibraryRootConstMeta,
+                argValues: [path],
+                apiImpl: this,
+                hint: hint,
+            ));
+        }
+
+
+        TaskConstMeta get kRemoveLibraryRootConstMeta => const TaskConstMeta(
+            debugName: "remove_library_root",
+            argNames: ["path"],
+        );
+        
+
+@override Future<void> setIgnorePatterns({required List<String> patterns , dynamic hint}) {
+            return handler.executeNormal(NormalTask(
+                callFfi: (port_) {
+                  
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_String(patterns, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11, port: port_);
+            
+                },
+                codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+                constMeta: kSetIgnorePatternsConstMeta,
+                argValues: [patterns],
+                apiImpl: this,
+                hint: hint,
+            ));
+        }
+
+
+        TaskConstMeta get kSetIgnorePatternsConstMeta => const TaskConstMeta(
+            debugName: "set_ignore_patterns",
+            argNames: ["patterns"],
+        );
+        
+
+@override Future<LibraryRoot> setLibraryRootEnabled({required TrackPath path , required bool enabled , dynamic hint}) {
+            return handler.executeNormal(NormalTask(
+                callFfi: (port_) {
+                  
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_track_path(path, serializer);
+sse_encode_bool(enabled, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9, port: port_);
+            
+                },
+                codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_library_root,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+                constMeta: kSetLibraryRootEnabledConstMeta,
+                argValues: [path, enabled],
+                apiImpl: this,
+                hint: hint,
+            ));
+        }
+
+
+        TaskConstMeta get kSetLibraryRootEnabledConstMeta => const TaskConstMeta(
+            debugName: "set_library_root_enabled",
+            argNames: ["path", "enabled"],
+        );
+        
+
+@override Future<List<LibraryTrack>> getAlbumTracks({required String album , String? artist , dynamic hint}) {
+            return handler.executeNormal(NormalTask(
+                callFfi: (port_) {
+                  
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(album, serializer);
+sse_encode_opt_String(artist, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17, port: port_);
+            
+                },
+                codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_library_track,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+                constMeta: kGetAlbumTracksConstMeta,
+                argValues: [album, artist],
+                apiImpl: this,
+                hint: hint,
+            ));
+        }
+
+
+        TaskConstMeta get kGetAlbumTracksConstMeta => const TaskConstMeta(
+            debugName: "get_album_tracks",
+            argNames: ["album", "artist"],
+        );
+        
+
+@override Future<List<Album>> getAlbums({dynamic hint}) {
+            return handler.executeNormal(NormalTask(
+                callFfi: (port_) {
+                  
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16, port: port_);
+            
+                },
+                codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_album,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+                constMeta: kGetAlbumsConstMeta,
+                argValues: [],
+                apiImpl: this,
+                hint: hint,
+            ));
+        }
+
+
+        TaskConstMeta get kGetAlbumsConstMeta => const TaskConstMeta(
+            debugName: "get_albums",
+            argNames: [],
+        );
+        
+
+@override Future<LibraryTrack?> getLibraryTrack({required int id , dynamic hint}) {
+            return handler.executeNormal(NormalTask(
+                callFfi: (port_) {
+                  
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_usize(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14, port: port_);
+            
+                },
+                codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_library_track,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+                constMeta: kGetLibraryTrackConstMeta,
+                argValues: [id],
+                apiImpl: this,
+                hint: hint,
+            ));
+        }
+
+
+        TaskConstMeta get kGetLibraryTrackConstMeta => const TaskConstMeta(
+            debugName: "get_library_track",
+            argNames: ["id"],
+        );
+        
+
+@override Future<List<LibraryTrack>> getLibraryTracks({dynamic hint}) {
+            return handler.executeNormal(NormalTask(
+                callFfi: (port_) {
+                  
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13, port: port_);
+            
+                },
+                codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_library_track,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+                constMeta: kGetLibraryTracksConstMeta,
+                argValues: [],
+                apiImpl: this,
+                hint: hint,
+            ));
+        }
+
+
+        TaskConstMeta get kGetLibraryTracksConstMeta => const TaskConstMeta(
+            debugName: "get_library_tracks",
+            argNames: [],
+        );
+        
+
+@override Future<LibraryDiff> rescanLibrary({int? maxDepth , dynamic hint}) {
+            return handler.executeNormal(NormalTask(
+                callFfi: (port_) {
+                  
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_opt_box_autoadd_usize(maxDepth, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12, port: port_);
+            
+                },
+                codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_library_diff,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+                constMeta: kRescanLibraryConstMeta,
+                argValues: [maxDepth],
+                apiImpl: this,
+                hint: hint,
+            ));
+        }
+
+
+        TaskConstMeta get kRescanLibraryConstMeta => const TaskConstMeta(
+            debugName: "rescan_library",
+            argNames: ["maxDepth"],
+        );
+        
+
+@override Future<List<LibraryTrack>> searchLibrary({required String query , dynamic hint}) {
+            return handler.executeNormal(NormalTask(
+                callFfi: (port_) {
+                  
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(query, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15, port: port_);
+            
+                },
+                codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_library_track,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+                constMeta: kSearchLibraryConstMeta,
+                argValues: [query],
+                apiImpl: this,
+                hint: hint,
+            ));
+        }
+
+
+        TaskConstMeta get kSearchLibraryConstMeta => const TaskConstMeta(
+            debugName: "search_library",
+            argNames: ["query"],
+        );
+        
+
+@override Future<LoudnessReport> analyzeLoudness({required List<TrackPath> paths , required bool album , required bool writeTags , dynamic hint}) {
+            return handler.executeNormal(NormalTask(
+                callFfi: (port_) {
+                  
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_track_path(paths, serializer);
+sse_encode_bool(album, serializer);
+sse_encode_bool(writeTags, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18, port: port_);
+            
+                },
+                codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_loudness_report,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+                constMeta: kAnalyzeLoudnessConstMeta,
+                argValues: [paths, album, writeTags],
+                apiImpl: this,
+                hint: hint,
+            ));
+        }
+
+
+        TaskConstMeta get kAnalyzeLoudnessConstMeta => const TaskConstMeta(
+            debugName: "analyze_loudness",
+            argNames: ["paths", "album", "writeTags"],
+        );
+        
+
+@override Future<TrackLoudness?> getTrackLoudness({required TrackPath path , dynamic hint}) {
+            return handler.executeNormal(NormalTask(
+                callFfi: (port_) {
+                  
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_track_path(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19, port: port_);
+            
+                },
+                codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_track_loudness,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+                constMeta: kGetTrackLoudnessConstMeta,
+                argValues: [path],
+                apiImpl: this,
+                hint: hint,
+            ));
+        }
+
+
+        TaskConstMeta get kGetTrackLoudnessConstMeta => const TaskConstMeta(
+            debugName: "get_track_loudness",
+            argNames: ["path"],
+        );
+        
+
+@override double replayGainVolume({required ReplayGain gain , required ReplayGainMode mode , required double preampDb , required bool preventClipping , dynamic hint}) {
+            return handler.executeSync(SyncTask(
+                callFfi: () {
+                  
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_replay_gain(gain, serializer);
+sse_encode_box_autoadd_replay_gain_mode(mode, serializer);
+sse_encode_f_64(preampDb, serializer);
+sse_encode_bool(preventClipping, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20)!;
+            
+                },
+                codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_f_64,
+          decodeErrorData: null,
+        )
+        ,
+                constMeta: kReplayGainVolumeConstMeta,
+                argValues: [gain, mode, preampDb, preventClipping],
+                apiImpl: this,
+                hint: hint,
+            ));
+        }
+
+
+        TaskConstMeta get kReplayGainVolumeConstMeta => const TaskConstMeta(
+            debugName: "replay_gain_volume",
+            argNames: ["gain", "mode", "preampDb", "preventClipping"],
+        );
+        
+
+@override int? lyricsTimelineLineIndexAt({required LyricsTimeline that , required int positionMs , dynamic hint}) {
+            return handler.executeSync(SyncTask(
+                callFfi: () {
+                  
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockLyricsTimeline(that, serializer);
+sse_encode_u_64(positionMs, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25)!;
+            
+                },
+                codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_usize,
+          decodeErrorData: null,
+        )
+        ,
+                constMeta: kLyricsTimelineLineIndexAtConstMeta,
+                argValues: [that, positionMs],
+                apiImpl: this,
+                hint: hint,
+            ));
+        }
+
+
+        TaskConstMeta get kLyricsTimelineLineIndexAtConstMeta => const TaskConstMeta(
+            debugName: "LyricsTimeline_line_index_at",
+            argNames: ["that", "positionMs"],
+        );
+        
+
+@override LyricsPosition lyricsTimelineLyricsAt({required LyricsTimeline that , required int positionMs , dynamic hint}) {
+            return handler.executeSync(SyncTask(
+                callFfi: () {
+                  
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockLyricsTimeline(that, serializer);
+sse_encode_u_64(positionMs, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26)!;
+            
+                },
+                codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_lyrics_position,
+          decodeErrorData: null,
+        )
+        ,
+                constMeta: kLyricsTimelineLyricsAtConstMeta,
+                argValues: [that, positionMs],
+                apiImpl: this,
+                hint: hint,
+            ));
+        }
+
+
+        TaskConstMeta get kLyricsTimelineLyricsAtConstMeta => const TaskConstMeta(
+            debugName: "LyricsTimeline_lyrics_at",
+            argNames: ["that", "positionMs"],
+        );
+        
+
+@override Future<String> buildLrc({required String text , required Uint64List tapsMs , dynamic hint}) {
+            return handler.executeNormal(NormalTask(
+                callFfi: (port_) {
+                  
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(text, serializer);
+sse_encode_list_prim_u_64_strict(tapsMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21, port: port_);
+            
+                },
+                codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+                constMeta: kBuildLrcConstMeta,
+                argValues: [text, tapsMs],
+                apiImpl: this,
+                hint: hint,
+            ));
+        }
+
+
+        TaskConstMeta get kBuildLrcConstMeta => const TaskConstMeta(
+            debugName: "build_lrc",
+            argNames: ["text", "tapsMs"],
+        );
+        
+
+@override Future<List<Lyrics>> getLyrics({required TrackPath path , dynamic hint}) {
+            return handler.executeNormal(NormalTask(
+                callFfi: (port_) {
+                  
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_track_path(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24, port: port_);
+            
+                },
+                codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_lyrics,
+          decodeErrorData: sse_decode_metadata_error,
+        )
+        ,
+                constMeta: kGetLyricsConstMeta,
+                argValues: [path],
+                apiImpl: this,
+                hint: hint,
+            ));
+        }
+
+
+        TaskConstMeta get kGetLyricsConstMeta => const TaskConstMeta(
+            debugName: "get_lyrics",
+            argNames: ["path"],
+        );
+        
+
+@override Future<String> nudgeLrc({required String lrc , required int deltaMs , int? fromLine , dynamic hint}) {
+            return handler.executeNormal(NormalTask(
+                callFfi: (port_) {
+                  
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(lrc, serializer);
+sse_encode_i_64(deltaMs, serializer);
+sse_encode_opt_box_autoadd_usize(fromLine, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22, port: port_);
+            
+                },
+                codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+                constMeta: kNudgeLrcConstMeta,
+                argValues: [lrc, deltaMs, fromLine],
+                apiImpl: this,
+                hint: hint,
+            ));
+        }
+
+
+        TaskConstMeta get kNudgeLrcConstMeta => const TaskConstMeta(
+            debugName: "nudge_lrc",
+            argNames: ["lrc", "deltaMs", "fromLine"],
+        );
+        
+
+@override Future<TrackPath> saveLrcSidecar({required TrackPath path , required String lrc , String? language , dynamic hint}) {
+            return handler.executeNormal(NormalTask(
+                callFfi: (port_) {
+                  
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_track_path(path, serializer);
+sse_encode_String(lrc, serializer);
+sse_encode_opt_String(language, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23, port: port_);
+            
+                },
+                codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_track_path,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+                constMeta: kSaveLrcSidecarConstMeta,
+                argValues: [path, lrc, language],
+                apiImpl: this,
+                hint: hint,
+            ));
+        }
+
+
+        TaskConstMeta get kSaveLrcSidecarConstMeta => const TaskConstMeta(
+            debugName: "save_lrc_sidecar",
+            argNames: ["path", "lrc", "language"],
+        );
+        
+
+@override Future<AudioProperties> getAudioProperties({required TrackPath path , dynamic hint}) {
+            return handler.executeNormal(NormalTask(
+                callFfi: (port_) {
+                  
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_track_path(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 29, port: port_);
+            
+                },
+                codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_audio_properties,
+          decodeErrorData: sse_decode_metadata_error,
+        )
+        ,
+                constMeta: kGetAudioPropertiesConstMeta,
+                argValues: [path],
+                apiImpl: this,
+                hint: hint,
+            ));
+        }
+
+
+        TaskConstMeta get kGetAudioPropertiesConstMeta => const TaskConstMeta(
+            debugName: "get_audio_properties",
+            argNames: ["path"],
+        );
+        
+
+@override Future<Metadata> getMetadata({required TrackPath path , dynamic hint}) {
+            return handler.executeNormal(NormalTask(
+                callFfi: (port_) {
+                  
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_track_path(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27, port: port_);
+            
+                },
+                codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_metadata,
+          decodeErrorData: sse_decode_metadata_error,
+        )
+        ,
+                constMeta: kGetMetadataConstMeta,
+                argValues: [path],
+                apiImpl: this,
+                hint: hint,
+            ));
+        }
+
+
+        TaskConstMeta get kGetMetadataConstMeta => const TaskConstMeta(
+            debugName: "get_metadata",
+            argNames: ["path"],
+        );
+        
+
+@override Future<FileStamp> writeMetadata({required TrackPath path , required MetadataChanges changes , required FileStamp expected , dynamic hint}) {
+            return handler.executeNormal(NormalTask(
+                callFfi: (port_) {
+                  
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_track_path(path, serializer);
+sse_encode_box_autoadd_metadata_changes(changes, serializer);
+sse_encode_box_autoadd_file_stamp(expected, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28, port: port_);
+            
+                },
+                codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_file_stamp,
+          decodeErrorData: sse_decode_metadata_error,
+        )
+        ,
+                constMeta: kWriteMetadataConstMeta,
+                argValues: [path, changes, expected],
+                apiImpl: this,
+                hint: hint,
+            ));
+        }
+
+
+        TaskConstMeta get kWriteMetadataConstMeta => const TaskConstMeta(
+            debugName: "write_metadata",
+            argNames: ["path", "changes", "expected"],
+        );
+        
+
+@override Future<Palette?> getPalette({required TrackPath path , dynamic hint}) {
+            return handler.executeNormal(NormalTask(
+                callFfi: (port_) {
+                  
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_track_path(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30, port: port_);
+            
+                },
+                codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_palette,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+                constMeta: kGetPaletteConstMeta,
+                argValues: [path],
+                apiImpl: this,
+                hint: hint,
+            ));
+        }
+
+
+        TaskConstMeta get kGetPaletteConstMeta => const TaskConstMeta(
+            debugName: "get_palette",
+            argNames: ["path"],
+        );
+        
+
+@override Future<String> playbackPath({required TrackPath path , dynamic hint}) {
+            return handler.executeNormal(NormalTask(
+                callFfi: (port_) {
+                  
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_track_path(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 31, port: port_);
+            
+                },
+                codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+                constMeta: kPlaybackPathConstMeta,
+                argValues: [path],
+                apiImpl: this,
+                hint: hint,
+            ));
+        }
+
+
+        TaskConstMeta get kPlaybackPathConstMeta => const TaskConstMeta(
+            debugName: "playback_path",
+            argNames: ["path"],
+        );
+        
+
+@override void scanCancelTokenCancel({required ScanCancelToken that , dynamic hint}) {
+            return handler.executeSync(SyncTask(
+                callFfi: () {
+                  
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 35)!;
+            
+                },
+                codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+                constMeta: kScanCancelTokenCancelConstMeta,
+                argValues: [that],
+                apiImpl: this,
+                hint: hint,
+            ));
+        }
+
+
+        TaskConstMeta get kScanCancelTokenCancelConstMeta => const TaskConstMeta(
+            debugName: "ScanCancelToken_cancel",
+            argNames: ["that"],
+        );
+        
+
+@override ScanCancelToken scanCancelTokenNew({dynamic hint}) {
+            return handler.executeSync(SyncTask(
+                callFfi: () {
+                  
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 34)!;
+            
+                },
+                codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken,
+          decodeErrorData: null,
+        )
+        ,
+                constMeta: kScanCancelTokenNewConstMeta,
+                argValues: [],
+                apiImpl: this,
+                hint: hint,
+            ));
+        }
+
+
+        TaskConstMeta get kScanCancelTokenNewConstMeta => const TaskConstMeta(
+            debugName: "ScanCancelToken_new",
+            argNames: [],
+        );
+        
+
+@override Future<ScanReport> scanLibrary({int? maxDepth , dynamic hint}) {
+            return handler.executeNormal(NormalTask(
+                callFfi: (port_) {
+                  
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_opt_box_autoadd_usize(maxDepth, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 32, port: port_);
+            
+                },
+                codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_scan_report,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+                constMeta: kScanLibraryConstMeta,
+                argValues: [maxDepth],
+                apiImpl: this,
+                hint: hint,
+            ));
+        }
+
+
+        TaskConstMeta get kScanLibraryConstMeta => const TaskConstMeta(
+            debugName: "scan_library",
+            argNames: ["maxDepth"],
+        );
+        
+
+@override Stream<ScanEvent> scanLibraryWithProgress({required ScanCancelToken token , int? maxDepth , dynamic hint}) {
+            return handler.executeStream(StreamTask(
+                callFfi: (port_) {
+                  
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken(token, serializer);
+sse_encode_opt_box_autoadd_usize(maxDepth, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 33, port: port_);
+            
+                },
+                codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_scan_event,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+                constMeta: kScanLibraryWithProgressConstMeta,
+                argValues: [token, maxDepth],
+                apiImpl: this,
+                hint: hint,
+            ));
+        }
+
+
+        TaskConstMeta get kScanLibraryWithProgressConstMeta => const TaskConstMeta(
+            debugName: "scan_library_with_progress",
+            argNames: ["token", "maxDepth"],
+        );
+        
+
+@override Future<void> clearThumbnailCache({dynamic hint}) {
+            return handler.executeNormal(NormalTask(
+                callFfi: (port_) {
+                  
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 38, port: port_);
+            
+                },
+                codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+                constMeta: kClearThumbnailCacheConstMeta,
+                argValues: [],
+                apiImpl: this,
+                hint: hint,
+            ));
+        }
+
+
+        TaskConstMeta get kClearThumbnailCacheConstMeta => const TaskConstMeta(
+            debugName: "clear_thumbnail_cache",
+            argNames: [],
+        );
+        
+
+@override Future<Thumbnail?> getThumbnail({required TrackPath path , required int size , dynamic hint}) {
+            return handler.executeNormal(NormalTask(
+                callFfi: (port_) {
+                  
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_track_path(path, serializer);
+sse_encode_u_32(size, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 36, port: port_);
+            
+                },
+                codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_thumbnail,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+                constMeta: kGetThumbnailConstMeta,
+                argValues: [path, size],
+                apiImpl: this,
+                hint: hint,
+            ));
+        }
+
+
+        TaskConstMeta get kGetThumbnailConstMeta => const TaskConstMeta(
+            debugName: "get_thumbnail",
+            argNames: ["path", "size"],
+        );
+        
+
+@override Future<List<Thumbnail?>> getThumbnails({required List<TrackPath> paths , required int size , dynamic hint}) {
+            return handler.executeNormal(NormalTask(
+                callFfi: (port_) {
+                  
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_track_path(paths, serializer);
+sse_encode_u_32(size, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 37, port: port_);
+            
+                },
+                codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_opt_box_autoadd_thumbnail,
+          decodeErrorData: null,
+        )
+        ,
+                constMeta: kGetThumbnailsConstMeta,
+                argValues: [paths, size],
+                apiImpl: this,
+                hint: hint,
+            ));
+        }
+
+
+        TaskConstMeta get kGetThumbnailsConstMeta => const TaskConstMeta(
+            debugName: "get_thumbnails",
+            argNames: ["paths", "size"],
+        );
+        
+
+@override Future<List<Track>> getMusicFiles({int? maxDepth , dynamic hint}) {
+            return handler.executeNormal(NormalTask(
+                callFfi: (port_) {
+                  
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_opt_box_autoadd_usize(maxDepth, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 39, port: port_);
+            
+                },
+                codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_track,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+                constMeta: kGetMusicFilesConstMeta,
+                argValues: [maxDepth],
+                apiImpl: this,
+                hint: hint,
+            ));
+        }
+
+
+        TaskConstMeta get kGetMusicFilesConstMeta => const TaskConstMeta(
+            debugName: "get_music_files",
+            argNames: ["maxDepth"],
+        );
+        
+
+@override Future<void> stopWatchingLibrary({dynamic hint}) {
+            return handler.executeNormal(NormalTask(
+                callFfi: (port_) {
+                  
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 41, port: port_);
+            
+                },
+                codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+                constMeta: kStopWatchingLibraryConstMeta,
+                argValues: [],
+                apiImpl: this,
+                hint: hint,
+            ));
+        }
+
+
+        TaskConstMeta get kStopWatchingLibraryConstMeta => const TaskConstMeta(
+            debugName: "stop_watching_library",
+            argNames: [],
+        );
+        
+
+@override Stream<LibraryEvent> watchLibrary({dynamic hint}) {
+            return handler.executeStream(StreamTask(
+                callFfi: (port_) {
+                  
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 40, port: port_);
+            
+                },
+                codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_library_event,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+                constMeta: kWatchLibraryConstMeta,
+                argValues: [],
+                apiImpl: this,
+                hint: hint,
+            ));
+        }
+
+
+        TaskConstMeta get kWatchLibraryConstMeta => const TaskConstMeta(
+            debugName: "watch_library",
+            argNames: [],
+        );
+        
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_LyricsTimeline => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockLyricsTimeline;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_LyricsTimeline => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockLyricsTimeline;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_ScanCancelToken => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_ScanCancelToken => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken;
+
+
+
+                  @protected AnyhowException dco_decode_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return AnyhowException(raw as String); }
+
+@protected LyricsTimeline dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockLyricsTimeline(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return LyricsTimeline.dcoDecode(raw as List<dynamic>); }
+
+@protected ScanCancelToken dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ScanCancelToken.dcoDecode(raw as List<dynamic>); }
+
+@protected LyricsTimeline dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockLyricsTimeline(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return LyricsTimeline.dcoDecode(raw as List<dynamic>); }
+
+@protected ScanCancelToken dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ScanCancelToken.dcoDecode(raw as List<dynamic>); }
+
+@protected LyricsTimeline dco_decode_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockLyricsTimeline(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return LyricsTimeline.dcoDecode(raw as List<dynamic>); }
+
+@protected ScanCancelToken dco_decode_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ScanCancelToken.dcoDecode(raw as List<dynamic>); }
+
+@protected String dco_decode_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as String; }
+
+@protected Album dco_decode_album(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return Album(name: dco_decode_String(arr[0]),
+artist: dco_decode_opt_String(arr[1]),
+trackCount: dco_decode_usize(arr[2]),
+durationMs: dco_decode_u_64(arr[3]),); }
+
+@protected AlbumLoudness dco_decode_album_loudness(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return AlbumLoudness(integratedLufs: dco_decode_f_64(arr[0]),
+loudnessRangeLu: dco_decode_f_64(arr[1]),
+truePeak: dco_decode_f_64(arr[2]),
+gainDb: dco_decode_f_64(arr[3]),); }
+
+@protected ArtSource dco_decode_art_source(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return ArtSource_Embedded(pictureType: dco_decode_box_autoadd_picture_type(raw[1]),);
+case 1: return ArtSource_Folder(path: dco_decode_box_autoadd_track_path(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected AudioFormat dco_decode_audio_format(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return AudioFormat.values[raw as int]; }
+
+@protected AudioProperties dco_decode_audio_properties(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return AudioProperties(durationMs: dco_decode_u_64(arr[0]),
+overallBitrate: dco_decode_opt_box_autoadd_u_32(arr[1]),
+audioBitrate: dco_decode_opt_box_autoadd_u_32(arr[2]),
+sampleRate: dco_decode_opt_box_autoadd_u_32(arr[3]),
+bitDepth: dco_decode_opt_box_autoadd_u_8(arr[4]),
+channels: dco_decode_opt_box_autoadd_u_8(arr[5]),); }
+
+@protected BatchEdit dco_decode_batch_edit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return BatchEdit(changes: dco_decode_metadata_changes(arr[0]),
+renumber: dco_decode_opt_box_autoadd_renumber(arr[1]),); }
+
+@protected BatchFile dco_decode_batch_file(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return BatchFile(path: dco_decode_track_path(arr[0]),
+stamp: dco_decode_file_stamp(arr[1]),); }
+
+@protected BatchFileResult dco_decode_batch_file_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return BatchFileResult(path: dco_decode_track_path(arr[0]),
+stamp: dco_decode_opt_box_autoadd_file_stamp(arr[1]),
+changes: dco_decode_list_field_change(arr[2]),
+error: dco_decode_opt_box_autoadd_metadata_error(arr[3]),); }
+
+@protected BatchOutcome dco_decode_batch_outcome(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return BatchOutcome(journalId: dco_decode_opt_String(arr[0]),
+results: dco_decode_list_batch_file_result(arr[1]),); }
+
+@protected bool dco_decode_bool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as bool; }
+
+@protected AlbumLoudness dco_decode_box_autoadd_album_loudness(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_album_loudness(raw); }
+
+@protected ArtSource dco_decode_box_autoadd_art_source(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_art_source(raw); }
+
+@protected BatchEdit dco_decode_box_autoadd_batch_edit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_batch_edit(raw); }
+
+@protected double dco_decode_box_autoadd_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected FileStamp dco_decode_box_autoadd_file_stamp(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_file_stamp(raw); }
+
+@protected LibraryDiff dco_decode_box_autoadd_library_diff(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_library_diff(raw); }
+
+@protected LibraryTrack dco_decode_box_autoadd_library_track(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_library_track(raw); }
+
+@protected MetadataChanges dco_decode_box_autoadd_metadata_changes(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_metadata_changes(raw); }
+
+@protected MetadataError dco_decode_box_autoadd_metadata_error(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_metadata_error(raw); }
+
+@protected Palette dco_decode_box_autoadd_palette(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_palette(raw); }
+
+@protected PictureType dco_decode_box_autoadd_picture_type(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_picture_type(raw); }
+
+@protected Renumber dco_decode_box_autoadd_renumber(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_renumber(raw); }
+
+@protected ReplayGain dco_decode_box_autoadd_replay_gain(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_replay_gain(raw); }
+
+@protected ReplayGainMode dco_decode_box_autoadd_replay_gain_mode(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_replay_gain_mode(raw); }
+
+@protected ScanProgress dco_decode_box_autoadd_scan_progress(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_scan_progress(raw); }
+
+@protected SkippedFile dco_decode_box_autoadd_skipped_file(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_skipped_file(raw); }
+
+@protected Swatch dco_decode_box_autoadd_swatch(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_swatch(raw); }
+
+@protected Thumbnail dco_decode_box_autoadd_thumbnail(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_thumbnail(raw); }
+
+@protected TrackLoudness dco_decode_box_autoadd_track_loudness(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_track_loudness(raw); }
+
+@protected TrackPath dco_decode_box_autoadd_track_path(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_track_path(raw); }
+
+@protected int dco_decode_box_autoadd_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected int dco_decode_box_autoadd_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_u_64(raw); }
+
+@protected int dco_decode_box_autoadd_u_8(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected int dco_decode_box_autoadd_usize(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_usize(raw); }
+
+@protected double dco_decode_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected FieldChange dco_decode_field_change(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return FieldChange(field: dco_decode_metadata_field(arr[0]),
+before: dco_decode_opt_String(arr[1]),
+after: dco_decode_opt_String(arr[2]),); }
+
+@protected FileStamp dco_decode_file_stamp(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return FileStamp(size: dco_decode_i_64(arr[0]),
+mtime: dco_decode_i_64(arr[1]),); }
+
+@protected int dco_decode_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected int dco_decode_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeI64OrU64(raw); }
+
+@protected LibraryDiff dco_decode_library_diff(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return LibraryDiff(added: dco_decode_list_library_track(arr[0]),
+removed: dco_decode_list_prim_u_64_strict(arr[1]),
+modified: dco_decode_list_library_track(arr[2]),
+moved: dco_decode_list_track_move(arr[3]),); }
+
+@protected LibraryEvent dco_decode_library_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return LibraryEvent_TrackAdded(dco_decode_box_autoadd_library_track(raw[1]),);
+case 1: return LibraryEvent_TrackRemoved(id: dco_decode_usize(raw[1]),);
+case 2: return LibraryEvent_TrackChanged(dco_decode_box_autoadd_library_track(raw[1]),);
+case 3: return LibraryEvent_TrackMoved(from: dco_decode_box_autoadd_track_path(raw[1]),track: dco_decode_box_autoadd_library_track(raw[2]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected LibraryRoot dco_decode_library_root(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return LibraryRoot(path: dco_decode_track_path(arr[0]),
+enabled: dco_decode_bool(arr[1]),); }
+
+@protected LibraryTrack dco_decode_library_track(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 10) throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+                return LibraryTrack(track: dco_decode_track(arr[0]),
+title: dco_decode_opt_String(arr[1]),
+artist: dco_decode_opt_String(arr[2]),
+album: dco_decode_opt_String(arr[3]),
+albumArtist: dco_decode_opt_String(arr[4]),
+genre: dco_decode_opt_String(arr[5]),
+year: dco_decode_opt_box_autoadd_u_32(arr[6]),
+trackNumber: dco_decode_opt_box_autoadd_u_32(arr[7]),
+discNumber: dco_decode_opt_box_autoadd_u_32(arr[8]),
+durationMs: dco_decode_opt_box_autoadd_u_64(arr[9]),); }
+
+@protected List<String> dco_decode_list_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_String).toList(); }
+
+@protected List<Album> dco_decode_list_album(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_album).toList(); }
+
+@protected List<BatchFile> dco_decode_list_batch_file(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_batch_file).toList(); }
+
+@protected List<BatchFileResult> dco_decode_list_batch_file_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_batch_file_result).toList(); }
+
+@protected List<FieldChange> dco_decode_list_field_change(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_field_change).toList(); }
+
+@protected List<LibraryRoot> dco_decode_list_library_root(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_library_root).toList(); }
+
+@protected List<LibraryTrack> dco_decode_list_library_track(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_library_track).toList(); }
+
+@protected List<LoudnessFailure> dco_decode_list_loudness_failure(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_loudness_failure).toList(); }
+
+@protected List<LyricLine> dco_decode_list_lyric_line(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_lyric_line).toList(); }
+
+@protected List<LyricWord> dco_decode_list_lyric_word(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_lyric_word).toList(); }
+
+@protected List<Lyrics> dco_decode_list_lyrics(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_lyrics).toList(); }
+
+@protected List<MetadataField> dco_decode_list_metadata_field(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_metadata_field).toList(); }
+
+@protected List<Thumbnail?> dco_decode_list_opt_box_autoadd_thumbnail(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_opt_box_autoadd_thumbnail).toList(); }
+
+@protected List<PictureInfo> dco_decode_list_picture_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_picture_info).toList(); }
+
+@protected Uint64List dco_decode_list_prim_u_64_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Uint64List.from(raw); }
+
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Uint8List; }
+
+@protected List<SkippedFile> dco_decode_list_skipped_file(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_skipped_file).toList(); }
+
+@protected List<Track> dco_decode_list_track(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_track).toList(); }
+
+@protected List<TrackLoudness> dco_decode_list_track_loudness(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_track_loudness).toList(); }
+
+@protected List<TrackMove> dco_decode_list_track_move(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_track_move).toList(); }
+
+@protected List<TrackPath> dco_decode_list_track_path(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_track_path).toList(); }
+
+@protected LoudnessFailure dco_decode_loudness_failure(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return LoudnessFailure(path: dco_decode_track_path(arr[0]),
+error: dco_decode_String(arr[1]),); }
+
+@protected LoudnessReport dco_decode_loudness_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return LoudnessReport(tracks: dco_decode_list_track_loudness(arr[0]),
+album: dco_decode_opt_box_autoadd_album_loudness(arr[1]),
+failed: dco_decode_list_loudness_failure(arr[2]),); }
+
+@protected LyricLine dco_decode_lyric_line(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return LyricLine(startMs: dco_decode_opt_box_autoadd_u_64(arr[0]),
+text: dco_decode_String(arr[1]),
+words: dco_decode_list_lyric_word(arr[2]),); }
+
+@protected LyricWord dco_decode_lyric_word(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return LyricWord(startMs: dco_decode_u_64(arr[0]),
+endMs: dco_decode_opt_box_autoadd_u_64(arr[1]),
+text: dco_decode_String(arr[2]),); }
+
+@protected Lyrics dco_decode_lyrics(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return Lyrics(source: dco_decode_lyrics_source(arr[0]),
+language: dco_decode_opt_String(arr[1]),
+synced: dco_decode_bool(arr[2]),
+offsetMs: dco_decode_i_64(arr[3]),
+lines: dco_decode_list_lyric_line(arr[4]),
+timeline: dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockLyricsTimeline(arr[5]),); }
+
+@protected LyricsPosition dco_decode_lyrics_position(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return LyricsPosition(line: dco_decode_opt_box_autoadd_usize(arr[0]),
+word: dco_decode_opt_box_autoadd_usize(arr[1]),
+wordProgress: dco_decode_f_64(arr[2]),); }
+
+@protected LyricsSource dco_decode_lyrics_source(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return LyricsSource_Tag();
+case 1: return LyricsSource_SyncedTag();
+case 2: return LyricsSource_Sidecar(path: dco_decode_box_autoadd_track_path(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected Metadata dco_decode_metadata(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 21) throw Exception('unexpected arr length: expect 21 but see ${arr.length}');
+                return Metadata(art: dco_decode_opt_list_prim_u_8_strict(arr[0]),
+artSource: dco_decode_opt_box_autoadd_art_source(arr[1]),
+title: dco_decode_opt_String(arr[2]),
+artist: dco_decode_opt_String(arr[3]),
+album: dco_decode_opt_String(arr[4]),
+albumArtist: dco_decode_opt_String(arr[5]),
+trackNumber: dco_decode_opt_box_autoadd_u_32(arr[6]),
+trackTotal: dco_decode_opt_box_autoadd_u_32(arr[7]),
+discNumber: dco_decode_opt_box_autoadd_u_32(arr[8]),
+discTotal: dco_decode_opt_box_autoadd_u_32(arr[9]),
+year: dco_decode_opt_box_autoadd_u_32(arr[10]),
+genre: dco_decode_opt_String(arr[11]),
+composer: dco_decode_opt_String(arr[12]),
+comment: dco_decode_opt_String(arr[13]),
+titleSort: dco_decode_opt_String(arr[14]),
+artistSort: dco_decode_opt_String(arr[15]),
+albumSort: dco_decode_opt_String(arr[16]),
+albumArtistSort: dco_decode_opt_String(arr[17]),
+properties: dco_decode_audio_properties(arr[18]),
+replayGain: dco_decode_replay_gain(arr[19]),
+stamp: dco_decode_file_stamp(arr[20]),); }
+
+@protected MetadataChanges dco_decode_metadata_changes(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 14) throw Exception('unexpected arr length: expect 14 but see ${arr.length}');
+                return MetadataChanges(title: dco_decode_opt_String(arr[0]),
+artist: dco_decode_opt_String(arr[1]),
+album: dco_decode_opt_String(arr[2]),
+albumArtist: dco_decode_opt_String(arr[3]),
+trackNumber: dco_decode_opt_box_autoadd_u_32(arr[4]),
+trackTotal: dco_decode_opt_box_autoadd_u_32(arr[5]),
+discNumber: dco_decode_opt_box_autoadd_u_32(arr[6]),
+discTotal: dco_decode_opt_box_autoadd_u_32(arr[7]),
+year: dco_decode_opt_box_autoadd_u_32(arr[8]),
+genre: dco_decode_opt_String(arr[9]),
+composer: dco_decode_opt_String(arr[10]),
+comment: dco_decode_opt_String(arr[11]),
+lyrics: dco_decode_opt_String(arr[12]),
+clear: dco_decode_list_metadata_field(arr[13]),); }
+
+@protected MetadataError dco_decode_metadata_error(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return MetadataError_NotFound();
+case 1: return MetadataError_UnsupportedFormat();
+case 2: return MetadataError_CorruptTag(dco_decode_String(raw[1]),);
+case 3: return MetadataError_Modified();
+case 4: return MetadataError_Io(dco_decode_String(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected MetadataField dco_decode_metadata_field(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return MetadataField.values[raw as int]; }
+
+@protected String? dco_decode_opt_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_String(raw); }
+
+@protected AlbumLoudness? dco_decode_opt_box_autoadd_album_loudness(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_album_loudness(raw); }
+
+@protected ArtSource? dco_decode_opt_box_autoadd_art_source(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_art_source(raw); }
+
+@protected double? dco_decode_opt_box_autoadd_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_f_64(raw); }
+
+@protected FileStamp? dco_decode_opt_box_autoadd_file_stamp(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_file_stamp(raw); }
+
+@protected LibraryTrack? dco_decode_opt_box_autoadd_library_track(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_library_track(raw); }
+
+@protected MetadataError? dco_decode_opt_box_autoadd_metadata_error(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_metadata_error(raw); }
+
+@protected Palette? dco_decode_opt_box_autoadd_palette(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_palette(raw); }
+
+@protected Renumber? dco_decode_opt_box_autoadd_renumber(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_renumber(raw); }
+
+@protected Swatch? dco_decode_opt_box_autoadd_swatch(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_swatch(raw); }
+
+@protected Thumbnail? dco_decode_opt_box_autoadd_thumbnail(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_thumbnail(raw); }
+
+@protected TrackLoudness? dco_decode_opt_box_autoadd_track_loudness(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_track_loudness(raw); }
+
+@protected int? dco_decode_opt_box_autoadd_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_32(raw); }
+
+@protected int? dco_decode_opt_box_autoadd_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_64(raw); }
+
+@protected int? dco_decode_opt_box_autoadd_u_8(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_8(raw); }
+
+@protected int? dco_decode_opt_box_autoadd_usize(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_usize(raw); }
+
+@protected Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_list_prim_u_8_strict(raw); }
+
+@protected Palette dco_decode_palette(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return Palette(dominant: dco_decode_swatch(arr[0]),
+vibrant: dco_decode_opt_box_autoadd_swatch(arr[1]),
+muted: dco_decode_opt_box_autoadd_swatch(arr[2]),); }
+
+@protected PictureInfo dco_decode_picture_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return PictureInfo(index: dco_decode_usize(arr[0]),
+pictureType: dco_decode_picture_type(arr[1]),
+mimeType: dco_decode_opt_String(arr[2]),
+width: dco_decode_opt_box_autoadd_u_32(arr[3]),
+height: dco_decode_opt_box_autoadd_u_32(arr[4]),
+description: dco_decode_opt_String(arr[5]),
+size: dco_decode_usize(arr[6]),); }
+
+@protected PictureType dco_decode_picture_type(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return PictureType_Other();
+case 1: return PictureType_Icon();
+case 2: return PictureType_OtherIcon();
+case 3: return PictureType_CoverFront();
+case 4: return PictureType_CoverBack();
+case 5: return PictureType_Leaflet();
+case 6: return PictureType_Media();
+case 7: return PictureType_LeadArtist();
+case 8: return PictureType_Artist();
+case 9: return PictureType_Conductor();
+case 10: return PictureType_Band();
+case 11: return PictureType_Composer();
+case 12: return PictureType_Lyricist();
+case 13: return PictureType_RecordingLocation();
+case 14: return PictureType_DuringRecording();
+case 15: return PictureType_DuringPerformance();
+case 16: return PictureType_ScreenCapture();
+case 17: return PictureType_BrightFish();
+case 18: return PictureType_Illustration();
+case 19: return PictureType_BandLogo();
+case 20: return PictureType_PublisherLogo();
+case 21: return PictureType_Undefined(dco_decode_u_8(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected Renumber dco_decode_renumber(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return Renumber(start: dco_decode_u_32(arr[0]),
+setTotal: dco_decode_bool(arr[1]),); }
+
+@protected ReplayGain dco_decode_replay_gain(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return ReplayGain(trackGainDb: dco_decode_opt_box_autoadd_f_64(arr[0]),
+trackPeak: dco_decode_opt_box_autoadd_f_64(arr[1]),
+albumGainDb: dco_decode_opt_box_autoadd_f_64(arr[2]),
+albumPeak: dco_decode_opt_box_autoadd_f_64(arr[3]),); }
+
+@protected ReplayGainMode dco_decode_replay_gain_mode(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return ReplayGainMode_Off();
+case 1: return ReplayGainMode_Track();
+case 2: return ReplayGainMode_Album();
+case 3: return ReplayGainMode_Smart(albumInOrder: dco_decode_bool(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected ScanEvent dco_decode_scan_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return ScanEvent_Progress(dco_decode_box_autoadd_scan_progress(raw[1]),);
+case 1: return ScanEvent_Skipped(dco_decode_box_autoadd_skipped_file(raw[1]),);
+case 2: return ScanEvent_Finished(dco_decode_box_autoadd_library_diff(raw[1]),);
+case 3: return ScanEvent_Cancelled();
+                default: throw Exception("unreachable");
+            } }
+
+@protected ScanPhase dco_decode_scan_phase(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ScanPhase.values[raw as int]; }
+
+@protected ScanProgress dco_decode_scan_progress(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return ScanProgress(phase: dco_decode_scan_phase(arr[0]),
+filesSeen: dco_decode_usize(arr[1]),
+filesDone: dco_decode_usize(arr[2]),
+filesTotal: dco_decode_usize(arr[3]),
+currentPath: dco_decode_opt_String(arr[4]),); }
+
+@protected ScanReport dco_decode_scan_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return ScanReport(tracks: dco_decode_list_track(arr[0]),
+skipped: dco_decode_list_skipped_file(arr[1]),); }
+
+@protected SkipReason dco_decode_skip_reason(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return SkipReason_PermissionDenied();
+case 1: return SkipReason_UnsupportedFormat();
+case 2: return SkipReason_CorruptHeader();
+case 3: return SkipReason_SymlinkLoop();
+case 4: return SkipReason_Io(dco_decode_String(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected SkippedFile dco_decode_skipped_file(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return SkippedFile(path: dco_decode_track_path(arr[0]),
+reason: dco_decode_skip_reason(arr[1]),); }
+
+@protected Swatch dco_decode_swatch(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return Swatch(color: dco_decode_u_32(arr[0]),
+foreground: dco_decode_u_32(arr[1]),
+population: dco_decode_f_64(arr[2]),); }
+
+@protected Thumbnail dco_decode_thumbnail(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return Thumbnail(data: dco_decode_list_prim_u_8_strict(arr[0]),
+artHash: dco_decode_String(arr[1]),); }
+
+@protected Track dco_decode_track(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return Track(name: dco_decode_String(arr[0]),
+path: dco_decode_track_path(arr[1]),
+id: dco_decode_opt_box_autoadd_usize(arr[2]),
+format: dco_decode_audio_format(arr[3]),); }
+
+@protected TrackLoudness dco_decode_track_loudness(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return TrackLoudness(path: dco_decode_track_path(arr[0]),
+integratedLufs: dco_decode_f_64(arr[1]),
+loudnessRangeLu: dco_decode_f_64(arr[2]),
+truePeak: dco_decode_f_64(arr[3]),
+trackGainDb: dco_decode_f_64(arr[4]),
+albumGainDb: dco_decode_opt_box_autoadd_f_64(arr[5]),
+albumTruePeak: dco_decode_opt_box_autoadd_f_64(arr[6]),
+tagError: dco_decode_opt_String(arr[7]),); }
+
+@protected TrackMove dco_decode_track_move(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return TrackMove(from: dco_decode_track_path(arr[0]),
+track: dco_decode_library_track(arr[1]),); }
+
+@protected TrackPath dco_decode_track_path(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return TrackPath(raw: dco_decode_list_prim_u_8_strict(arr[0]),
+display: dco_decode_String(arr[1]),); }
+
+@protected int dco_decode_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected int dco_decode_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeI64OrU64(raw); }
+
+@protected int dco_decode_u_8(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected void dco_decode_unit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return; }
+
+@protected int dco_decode_usize(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeI64OrU64(raw); }
+
+@protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_String(deserializer);
+        return AnyhowException(inner); }
+
+@protected LyricsTimeline sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockLyricsTimeline(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return LyricsTimeline.sseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected ScanCancelToken sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return ScanCancelToken.sseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected LyricsTimeline sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockLyricsTimeline(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return LyricsTimeline.sseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected ScanCancelToken sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return ScanCancelToken.sseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected LyricsTimeline sse_decode_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockLyricsTimeline(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return LyricsTimeline.sseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected ScanCancelToken sse_decode_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return ScanCancelToken.sseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected String sse_decode_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_prim_u_8_strict(deserializer);
+        return utf8.decoder.convert(inner); }
+
+@protected Album sse_decode_album(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_artist = sse_decode_opt_String(deserializer);
+var var_trackCount = sse_decode_usize(deserializer);
+var var_durationMs = sse_decode_u_64(deserializer);
+return Album(name: var_name, artist: var_artist, trackCount: var_trackCount, durationMs: var_durationMs); }
+
+@protected AlbumLoudness sse_decode_album_loudness(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_integratedLufs = sse_decode_f_64(deserializer);
+var var_loudnessRangeLu = sse_decode_f_64(deserializer);
+var var_truePeak = sse_decode_f_64(deserializer);
+var var_gainDb = sse_decode_f_64(deserializer);
+return AlbumLoudness(integratedLufs: var_integratedLufs, loudnessRangeLu: var_loudnessRangeLu, truePeak: var_truePeak, gainDb: var_gainDb); }
+
+@protected ArtSource sse_decode_art_source(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_pictureType = sse_decode_box_autoadd_picture_type(deserializer);
+return ArtSource_Embedded(pictureType: var_pictureType);case 1: var var_path = sse_decode_box_autoadd_track_path(deserializer);
+return ArtSource_Folder(path: var_path); default: throw UnimplementedError(''); }
+             }
+
+@protected AudioFormat sse_decode_audio_format(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return AudioFormat.values[inner]; }
+
+@protected AudioProperties sse_decode_audio_properties(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_durationMs = sse_decode_u_64(deserializer);
+var var_overallBitrate = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_audioBitrate = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_sampleRate = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_bitDepth = sse_decode_opt_box_autoadd_u_8(deserializer);
+var var_channels = sse_decode_opt_box_autoadd_u_8(deserializer);
+return AudioProperties(durationMs: var_durationMs, overallBitrate: var_overallBitrate, audioBitrate: var_audioBitrate, sampleRate: var_sampleRate, bitDepth: var_bitDepth, channels: var_channels); }
+
+@protected BatchEdit sse_decode_batch_edit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_changes = sse_decode_metadata_changes(deserializer);
+var var_renumber = sse_decode_opt_box_autoadd_renumber(deserializer);
+return BatchEdit(changes: var_changes, renumber: var_renumber); }
+
+@protected BatchFile sse_decode_batch_file(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_path = sse_decode_track_path(deserializer);
+var var_stamp = sse_decode_file_stamp(deserializer);
+return BatchFile(path: var_path, stamp: var_stamp); }
+
+@protected BatchFileResult sse_decode_batch_file_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_path = sse_decode_track_path(deserializer);
+var var_stamp = sse_decode_opt_box_autoadd_file_stamp(deserializer);
+var var_changes = sse_decode_list_field_change(deserializer);
+var var_error = sse_decode_opt_box_autoadd_metadata_error(deserializer);
+return BatchFileResult(path: var_path, stamp: var_stamp, changes: var_changes, error: var_error); }
+
+@protected BatchOutcome sse_decode_batch_outcome(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_journalId = sse_decode_opt_String(deserializer);
+var var_results = sse_decode_list_batch_file_result(deserializer);
+return BatchOutcome(journalId: var_journalId, results: var_results); }
+
+@protected bool sse_decode_bool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8() != 0; }
+
+@protected AlbumLoudness sse_decode_box_autoadd_album_loudness(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_album_loudness(deserializer)); }
+
+@protected ArtSource sse_decode_box_autoadd_art_source(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_art_source(deserializer)); }
+
+@protected BatchEdit sse_decode_box_autoadd_batch_edit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_batch_edit(deserializer)); }
+
+@protected double sse_decode_box_autoadd_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_f_64(deserializer)); }
+
+@protected FileStamp sse_decode_box_autoadd_file_stamp(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_file_stamp(deserializer)); }
+
+@protected LibraryDiff sse_decode_box_autoadd_library_diff(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_library_diff(deserializer)); }
+
+@protected LibraryTrack sse_decode_box_autoadd_library_track(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_library_track(deserializer)); }
+
+@protected MetadataChanges sse_decode_box_autoadd_metadata_changes(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_metadata_changes(deserializer)); }
+
+@protected MetadataError sse_decode_box_autoadd_metadata_error(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_metadata_error(deserializer)); }
+
+@protected Palette sse_decode_box_autoadd_palette(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_palette(deserializer)); }
+
+@protected PictureType sse_decode_box_autoadd_picture_type(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_picture_type(deserializer)); }
+
+@protected Renumber sse_decode_box_autoadd_renumber(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_renumber(deserializer)); }
+
+@protected ReplayGain sse_decode_box_autoadd_replay_gain(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_replay_gain(deserializer)); }
+
+@protected ReplayGainMode sse_decode_box_autoadd_replay_gain_mode(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_replay_gain_mode(deserializer)); }
+
+@protected ScanProgress sse_decode_box_autoadd_scan_progress(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_scan_progress(deserializer)); }
+
+@protected SkippedFile sse_decode_box_autoadd_skipped_file(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_skipped_file(deserializer)); }
+
+@protected Swatch sse_decode_box_autoadd_swatch(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_swatch(deserializer)); }
+
+@protected Thumbnail sse_decode_box_autoadd_thumbnail(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_thumbnail(deserializer)); }
+
+@protected TrackLoudness sse_decode_box_autoadd_track_loudness(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_track_loudness(deserializer)); }
+
+@protected TrackPath sse_decode_box_autoadd_track_path(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_track_path(deserializer)); }
+
+@protected int sse_decode_box_autoadd_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_32(deserializer)); }
+
+@protected int sse_decode_box_autoadd_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_64(deserializer)); }
+
+@protected int sse_decode_box_autoadd_u_8(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_8(deserializer)); }
+
+@protected int sse_decode_box_autoadd_usize(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_usize(deserializer)); }
+
+@protected double sse_decode_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getFloat64(); }
+
+@protected FieldChange sse_decode_field_change(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field = sse_decode_metadata_field(deserializer);
+var var_before = sse_decode_opt_String(deserializer);
+var var_after = sse_decode_opt_String(deserializer);
+return FieldChange(field: var_field, before: var_before, after: var_after); }
+
+@protected FileStamp sse_decode_file_stamp(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_size = sse_decode_i_64(deserializer);
+var var_mtime = sse_decode_i_64(deserializer);
+return FileStamp(size: var_size, mtime: var_mtime); }
+
+@protected int sse_decode_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getInt32(); }
+
+@protected int sse_decode_i_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getInt64(); }
+
+@protected LibraryDiff sse_decode_library_diff(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_added = sse_decode_list_library_track(deserializer);
+var var_removed = sse_decode_list_prim_u_64_strict(deserializer);
+var var_modified = sse_decode_list_library_track(deserializer);
+var var_moved = sse_decode_list_track_move(deserializer);
+return LibraryDiff(added: var_added, removed: var_removed, modified: var_modified, moved: var_moved); }
+
+@protected LibraryEvent sse_decode_library_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_field0 = sse_decode_box_autoadd_library_track(deserializer);
+return LibraryEvent_TrackAdded(var_field0);case 1: var var_id = sse_decode_usize(deserializer);
+return LibraryEvent_TrackRemoved(id: var_id);case 2: var var_field0 = sse_decode_box_autoadd_library_track(deserializer);
+return LibraryEvent_TrackChanged(var_field0);case 3: var var_from = sse_decode_box_autoadd_track_path(deserializer);
+var var_track = sse_decode_box_autoadd_library_track(deserializer);
+return LibraryEvent_TrackMoved(from: var_from, track: var_track); default: throw UnimplementedError(''); }
+             }
+
+@protected LibraryRoot sse_decode_library_root(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_path = sse_decode_track_path(deserializer);
+var var_enabled = sse_decode_bool(deserializer);
+return LibraryRoot(path: var_path, enabled: var_enabled); }
+
+@protected LibraryTrack sse_decode_library_track(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_track = sse_decode_track(deserializer);
+var var_title = sse_decode_opt_String(deserializer);
+var var_artist = sse_decode_opt_String(deserializer);
+var var_album = sse_decode_opt_String(deserializer);
+var var_albumArtist = sse_decode_opt_String(deserializer);
+var var_genre = sse_decode_opt_String(deserializer);
+var var_year = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_trackNumber = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_discNumber = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_durationMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+return LibraryTrack(track: var_track, title: var_title, artist: var_artist, album: var_album, albumArtist: var_albumArtist, genre: var_genre, year: var_year, trackNumber: var_trackNumber, discNumber: var_discNumber, durationMs: var_durationMs); }
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <String>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_String(deserializer)); }
+        return ans_;
+         }
+
+@protected List<Album> sse_decode_list_album(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <Album>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_album(deserializer)); }
+        return ans_;
+         }
+
+@protected List<BatchFile> sse_decode_list_batch_file(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <BatchFile>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_batch_file(deserializer)); }
+        return ans_;
+         }
+
+@protected List<BatchFileResult> sse_decode_list_batch_file_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <BatchFileResult>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_batch_file_result(deserializer)); }
+        return ans_;
+         }
+
+@protected List<FieldChange> sse_decode_list_field_change(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <FieldChange>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_field_change(deserializer)); }
+        return ans_;
+         }
+
+@protected List<LibraryRoot> sse_decode_list_library_root(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <LibraryRoot>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_library_root(deserializer)); }
+        return ans_;
+         }
+
+@protected List<LibraryTrack> sse_decode_list_library_track(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <LibraryTrack>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_library_track(deserializer)); }
+        return ans_;
+         }
+
+@protected List<LoudnessFailure> sse_decode_list_loudness_failure(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <LoudnessFailure>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_loudness_failure(deserializer)); }
+        return ans_;
+         }
+
+@protected List<LyricLine> sse_decode_list_lyric_line(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <LyricLine>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_lyric_line(deserializer)); }
+        return ans_;
+         }
+
+@protected List<LyricWord> sse_decode_list_lyric_word(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <LyricWord>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_lyric_word(deserializer)); }
+        return ans_;
+         }
+
+@protected List<Lyrics> sse_decode_list_lyrics(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <Lyrics>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_lyrics(deserializer)); }
+        return ans_;
+         }
+
+@protected List<MetadataField> sse_decode_list_metadata_field(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <MetadataField>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_metadata_field(deserializer)); }
+        return ans_;
+         }
+
+@protected List<Thumbnail?> sse_decode_list_opt_box_autoadd_thumbnail(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <Thumbnail?>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_opt_box_autoadd_thumbnail(deserializer)); }
+        return ans_;
+         }
+
+@protected List<PictureInfo> sse_decode_list_picture_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <PictureInfo>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_picture_info(deserializer)); }
+        return ans_;
+         }
+
+@protected Uint64List sse_decode_list_prim_u_64_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint64List(len_); }
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint8List(len_); }
+
+@protected List<SkippedFile> sse_decode_list_skipped_file(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SkippedFile>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_skipped_file(deserializer)); }
+        return ans_;
+         }
+
+@protected List<Track> sse_decode_list_track(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <Track>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_track(deserializer)); }
+        return ans_;
+         }
+
+@protected List<TrackLoudness> sse_decode_list_track_loudness(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <TrackLoudness>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_track_loudness(deserializer)); }
+        return ans_;
+         }
+
+@protected List<TrackMove> sse_decode_list_track_move(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <TrackMove>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_track_move(deserializer)); }
+        return ans_;
+         }
+
+@protected List<TrackPath> sse_decode_list_track_path(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <TrackPath>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_track_path(deserializer)); }
+        return ans_;
+         }
+
+@protected LoudnessFailure sse_decode_loudness_failure(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_path = sse_decode_track_path(deserializer);
+var var_error = sse_decode_String(deserializer);
+return LoudnessFailure(path: var_path, error: var_error); }
+
+@protected LoudnessReport sse_decode_loudness_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_tracks = sse_decode_list_track_loudness(deserializer);
+var var_album = sse_decode_opt_box_autoadd_album_loudness(deserializer);
+var var_failed = sse_decode_list_loudness_failure(deserializer);
+return LoudnessReport(tracks: var_tracks, album: var_album, failed: var_failed); }
+
+@protected LyricLine sse_decode_lyric_line(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_startMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_text = sse_decode_String(deserializer);
+var var_words = sse_decode_list_lyric_word(deserializer);
+return LyricLine(startMs: var_startMs, text: var_text, words: var_words); }
+
+@protected LyricWord sse_decode_lyric_word(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_startMs = sse_decode_u_64(deserializer);
+var var_endMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_text = sse_decode_String(deserializer);
+return LyricWord(startMs: var_startMs, endMs: var_endMs, text: var_text); }
+
+@protected Lyrics sse_decode_lyrics(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_source = sse_decode_lyrics_source(deserializer);
+var var_language = sse_decode_opt_String(deserializer);
+var var_synced = sse_decode_bool(deserializer);
+var var_offsetMs = sse_decode_i_64(deserializer);
+var var_lines = sse_decode_list_lyric_line(deserializer);
+var var_timeline = sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockLyricsTimeline(deserializer);
+return Lyrics(source: var_source, language: var_language, synced: var_synced, offsetMs: var_offsetMs, lines: var_lines, timeline: var_timeline); }
+
+@protected LyricsPosition sse_decode_lyrics_position(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_line = sse_decode_opt_box_autoadd_usize(deserializer);
+var var_word = sse_decode_opt_box_autoadd_usize(deserializer);
+var var_wordProgress = sse_decode_f_64(deserializer);
+return LyricsPosition(line: var_line, word: var_word, wordProgress: var_wordProgress); }
+
+@protected LyricsSource sse_decode_lyrics_source(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: return LyricsSource_Tag();case 1: return LyricsSource_SyncedTag();case 2: var var_path = sse_decode_box_autoadd_track_path(deserializer);
+return LyricsSource_Sidecar(path: var_path); default: throw UnimplementedError(''); }
+             }
+
+@protected Metadata sse_decode_metadata(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_art = sse_decode_opt_list_prim_u_8_strict(deserializer);
+var var_artSource = sse_decode_opt_box_autoadd_art_source(deserializer);
+var var_title = sse_decode_opt_String(deserializer);
+var var_artist = sse_decode_opt_String(deserializer);
+var var_album = sse_decode_opt_String(deserializer);
+var var_albumArtist = sse_decode_opt_String(deserializer);
+var var_trackNumber = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_trackTotal = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_discNumber = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_discTotal = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_year = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_genre = sse_decode_opt_String(deserializer);
+var var_composer = sse_decode_opt_String(deserializer);
+var var_comment = sse_decode_opt_String(deserializer);
+var var_titleSort = sse_decode_opt_String(deserializer);
+var var_artistSort = sse_decode_opt_String(deserializer);
+var var_albumSort = sse_decode_opt_String(deserializer);
+var var_albumArtistSort = sse_decode_opt_String(deserializer);
+var var_properties = sse_decode_audio_properties(deserializer);
+var var_replayGain = sse_decode_replay_gain(deserializer);
+var var_stamp = sse_decode_file_stamp(deserializer);
+return Metadata(art: var_art, artSource: var_artSource, title: var_title, artist: var_artist, album: var_album, albumArtist: var_albumArtist, trackNumber: var_trackNumber, trackTotal: var_trackTotal, discNumber: var_discNumber, discTotal: var_discTotal, year: var_year, genre: var_genre, composer: var_composer, comment: var_comment, titleSort: var_titleSort, artistSort: var_artistSort, albumSort: var_albumSort, albumArtistSort: var_albumArtistSort, properties: var_properties, replayGain: var_replayGain, stamp: var_stamp); }
+
+@protected MetadataChanges sse_decode_metadata_changes(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_title = sse_decode_opt_String(deserializer);
+var var_artist = sse_decode_opt_String(deserializer);
+var var_album = sse_decode_opt_String(deserializer);
+var var_albumArtist = sse_decode_opt_String(deserializer);
+var var_trackNumber = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_trackTotal = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_discNumber = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_discTotal = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_year = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_genre = sse_decode_opt_String(deserializer);
+var var_composer = sse_decode_opt_String(deserializer);
+var var_comment = sse_decode_opt_String(deserializer);
+var var_lyrics = sse_decode_opt_String(deserializer);
+var var_clear = sse_decode_list_metadata_field(deserializer);
+return MetadataChanges(title: var_title, artist: var_artist, album: var_album, albumArtist: var_albumArtist, trackNumber: var_trackNumber, trackTotal: var_trackTotal, discNumber: var_discNumber, discTotal: var_discTotal, year: var_year, genre: var_genre, composer: var_composer, comment: var_comment, lyrics: var_lyrics, clear: var_clear); }
+
+@protected MetadataError sse_decode_metadata_error(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: return MetadataError_NotFound();case 1: return MetadataError_UnsupportedFormat();case 2: var var_field0 = sse_decode_String(deserializer);
+return MetadataError_CorruptTag(var_field0);case 3: return MetadataError_Modified();case 4: var var_field0 = sse_decode_String(deserializer);
+return MetadataError_Io(var_field0); default: throw UnimplementedError(''); }
+             }
+
+@protected MetadataField sse_decode_metadata_field(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return MetadataField.values[inner]; }
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_String(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected AlbumLoudness? sse_decode_opt_box_autoadd_album_loudness(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_album_loudness(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected ArtSource? sse_decode_opt_box_autoadd_art_source(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_art_source(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_f_64(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected FileStamp? sse_decode_opt_box_autoadd_file_stamp(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_file_stamp(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected LibraryTrack? sse_decode_opt_box_autoadd_library_track(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_library_track(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected MetadataError? sse_decode_opt_box_autoadd_metadata_error(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_metadata_error(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected Palette? sse_decode_opt_box_autoadd_palette(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_palette(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected Renumber? sse_decode_opt_box_autoadd_renumber(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_renumber(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected Swatch? sse_decode_opt_box_autoadd_swatch(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_swatch(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected Thumbnail? sse_decode_opt_box_autoadd_thumbnail(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_thumbnail(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected TrackLoudness? sse_decode_opt_box_autoadd_track_loudness(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_track_loudness(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_32(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected int? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_64(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected int? sse_decode_opt_box_autoadd_u_8(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_8(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected int? sse_decode_opt_box_autoadd_usize(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_usize(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_list_prim_u_8_strict(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected Palette sse_decode_palette(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_dominant = sse_decode_swatch(deserializer);
+var var_vibrant = sse_decode_opt_box_autoadd_swatch(deserializer);
+var var_muted = sse_decode_opt_box_autoadd_swatch(deserializer);
+return Palette(dominant: var_dominant, vibrant: var_vibrant, muted: var_muted); }
+
+@protected PictureInfo sse_decode_picture_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_index = sse_decode_usize(deserializer);
+var var_pictureType = sse_decode_picture_type(deserializer);
+var var_mimeType = sse_decode_opt_String(deserializer);
+var var_width = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_height = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_description = sse_decode_opt_String(deserializer);
+var var_size = sse_decode_usize(deserializer);
+return PictureInfo(index: var_index, pictureType: var_pictureType, mimeType: var_mimeType, width: var_width, height: var_height, description: var_description, size: var_size); }
+
+@protected PictureType sse_decode_picture_type(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: return PictureType_Other();case 1: return PictureType_Icon();case 2: return PictureType_OtherIcon();case 3: return PictureType_CoverFront();case 4: return PictureType_CoverBack();case 5: return PictureType_Leaflet();case 6: return PictureType_Media();case 7: return PictureType_LeadArtist();case 8: return PictureType_Artist();case 9: return PictureType_Conductor();case 10: return PictureType_Band();case 11: return PictureType_Composer();case 12: return PictureType_Lyricist();case 13: return PictureType_RecordingLocation();case 14: return PictureType_DuringRecording();case 15: return PictureType_DuringPerformance();case 16: return PictureType_ScreenCapture();case 17: return PictureType_BrightFish();case 18: return PictureType_Illustration();case 19: return PictureType_BandLogo();case 20: return PictureType_PublisherLogo();case 21: var var_field0 = sse_decode_u_8(deserializer);
+return PictureType_Undefined(var_field0); default: throw UnimplementedError(''); }
+             }
+
+@protected Renumber sse_decode_renumber(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_start = sse_decode_u_32(deserializer);
+var var_setTotal = sse_decode_bool(deserializer);
+return Renumber(start: var_start, setTotal: var_setTotal); }
+
+@protected ReplayGain sse_decode_replay_gain(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_trackGainDb = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_trackPeak = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_albumGainDb = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_albumPeak = sse_decode_opt_box_autoadd_f_64(deserializer);
+return ReplayGain(trackGainDb: var_trackGainDb, trackPeak: var_trackPeak, albumGainDb: var_albumGainDb, albumPeak: var_albumPeak); }
+
+@protected ReplayGainMode sse_decode_replay_gain_mode(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: return ReplayGainMode_Off();case 1: return ReplayGainMode_Track();case 2: return ReplayGainMode_Album();case 3: var var_albumInOrder = sse_decode_bool(deserializer);
+return ReplayGainMode_Smart(albumInOrder: var_albumInOrder); default: throw UnimplementedError(''); }
+             }
+
+@protected ScanEvent sse_decode_scan_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_field0 = sse_decode_box_autoadd_scan_progress(deserializer);
+return ScanEvent_Progress(var_field0);case 1: var var_field0 = sse_decode_box_autoadd_skipped_file(deserializer);
+return ScanEvent_Skipped(var_field0);case 2: var var_field0 = sse_decode_box_autoadd_library_diff(deserializer);
+return ScanEvent_Finished(var_field0);case 3: return ScanEvent_Cancelled(); default: throw UnimplementedError(''); }
+             }
+
+@protected ScanPhase sse_decode_scan_phase(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ScanPhase.values[inner]; }
+
+@protected ScanProgress sse_decode_scan_progress(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_phase = sse_decode_scan_phase(deserializer);
+var var_filesSeen = sse_decode_usize(deserializer);
+var var_filesDone = sse_decode_usize(deserializer);
+var var_filesTotal = sse_decode_usize(deserializer);
+var var_currentPath = sse_decode_opt_String(deserializer);
+return ScanProgress(phase: var_phase, filesSeen: var_filesSeen, filesDone: var_filesDone, filesTotal: var_filesTotal, currentPath: var_currentPath); }
+
+@protected ScanReport sse_decode_scan_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_tracks = sse_decode_list_track(deserializer);
+var var_skipped = sse_decode_list_skipped_file(deserializer);
+return ScanReport(tracks: var_tracks, skipped: var_skipped); }
+
+@protected SkipReason sse_decode_skip_reason(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: return SkipReason_PermissionDenied();case 1: return SkipReason_UnsupportedFormat();case 2: return SkipReason_CorruptHeader();case 3: return SkipReason_SymlinkLoop();case 4: var var_field0 = sse_decode_String(deserializer);
+return SkipReason_Io(var_field0); default: throw UnimplementedError(''); }
+             }
+
+@protected SkippedFile sse_decode_skipped_file(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_path = sse_decode_track_path(deserializer);
+var var_reason = sse_decode_skip_reason(deserializer);
+return SkippedFile(path: var_path, reason: var_reason); }
+
+@protected Swatch sse_decode_swatch(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_color = sse_decode_u_32(deserializer);
+var var_foreground = sse_decode_u_32(deserializer);
+var var_population = sse_decode_f_64(deserializer);
+return Swatch(color: var_color, foreground: var_foreground, population: var_population); }
+
+@protected Thumbnail sse_decode_thumbnail(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_data = sse_decode_list_prim_u_8_strict(deserializer);
+var var_artHash = sse_decode_String(deserializer);
+return Thumbnail(data: var_data, artHash: var_artHash); }
+
+@protected Track sse_decode_track(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_path = sse_decode_track_path(deserializer);
+var var_id = sse_decode_opt_box_autoadd_usize(deserializer);
+var var_format = sse_decode_audio_format(deserializer);
+return Track(name: var_name, path: var_path, id: var_id, format: var_format); }
+
+@protected TrackLoudness sse_decode_track_loudness(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_path = sse_decode_track_path(deserializer);
+var var_integratedLufs = sse_decode_f_64(deserializer);
+var var_loudnessRangeLu = sse_decode_f_64(deserializer);
+var var_truePeak = sse_decode_f_64(deserializer);
+var var_trackGainDb = sse_decode_f_64(deserializer);
+var var_albumGainDb = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_albumTruePeak = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_tagError = sse_decode_opt_String(deserializer);
+return TrackLoudness(path: var_path, integratedLufs: var_integratedLufs, loudnessRangeLu: var_loudnessRangeLu, truePeak: var_truePeak, trackGainDb: var_trackGainDb, albumGainDb: var_albumGainDb, albumTruePeak: var_albumTruePeak, tagError: var_tagError); }
+
+@protected TrackMove sse_decode_track_move(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_from = sse_decode_track_path(deserializer);
+var var_track = sse_decode_library_track(deserializer);
+return TrackMove(from: var_from, track: var_track); }
+
+@protected TrackPath sse_decode_track_path(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_raw = sse_decode_list_prim_u_8_strict(deserializer);
+var var_display = sse_decode_String(deserializer);
+return TrackPath(raw: var_raw, display: var_display); }
+
+@protected int sse_decode_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint32(); }
+
+@protected int sse_decode_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint64(); }
+
+@protected int sse_decode_u_8(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8(); }
+
+@protected void sse_decode_unit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected int sse_decode_usize(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint64(); }
+
+@protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable (('); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockLyricsTimeline(LyricsTimeline self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize(self.sseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken(ScanCancelToken self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize(self.sseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockLyricsTimeline(LyricsTimeline self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize(self.sseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken(ScanCancelToken self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize(self.sseEncode(move: false), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockLyricsTimeline(LyricsTimeline self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize(self.sseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken(ScanCancelToken self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize(self.sseEncode(move: null), serializer); }
+
+@protected void sse_encode_String(String self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer); }
+
+@protected void sse_encode_album(Album self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_opt_String(self.artist, serializer);
+sse_encode_usize(self.trackCount, serializer);
+sse_encode_u_64(self.durationMs, serializer);
+ }
+
+@protected void sse_encode_album_loudness(AlbumLoudness self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_64(self.integratedLufs, serializer);
+sse_encode_f_64(self.loudnessRangeLu, serializer);
+sse_encode_f_64(self.truePeak, serializer);
+sse_encode_f_64(self.gainDb, serializer);
+ }
+
+@protected void sse_encode_art_source(ArtSource self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case ArtSource_Embedded(pictureType: final pictureType): sse_encode_i_32(0, serializer); sse_encode_box_autoadd_picture_type(pictureType, serializer);
+case ArtSource_Folder(path: final path): sse_encode_i_32(1, serializer); sse_encode_box_autoadd_track_path(path, serializer);
+  } }
+
+@protected void sse_encode_audio_format(AudioFormat self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_audio_properties(AudioProperties self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.durationMs, serializer);
+sse_encode_opt_box_autoadd_u_32(self.overallBitrate, serializer);
+sse_encode_opt_box_autoadd_u_32(self.audioBitrate, serializer);
+sse_encode_opt_box_autoadd_u_32(self.sampleRate, serializer);
+sse_encode_opt_box_autoadd_u_8(self.bitDepth, serializer);
+sse_encode_opt_box_autoadd_u_8(self.channels, serializer);
+ }
+
+@protected void sse_encode_batch_edit(BatchEdit self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_metadata_changes(self.changes, serializer);
+sse_encode_opt_box_autoadd_renumber(self.renumber, serializer);
+ }
+
+@protected void sse_encode_batch_file(BatchFile self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_track_path(self.path, serializer);
+sse_encode_file_stamp(self.stamp, serializer);
+ }
+
+@protected void sse_encode_batch_file_result(BatchFileResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_track_path(self.path, serializer);
+sse_encode_opt_box_autoadd_file_stamp(self.stamp, serializer);
+sse_encode_list_field_change(self.changes, serializer);
+sse_encode_opt_box_autoadd_metadata_error(self.error, serializer);
+ }
+
+@protected void sse_encode_batch_outcome(BatchOutcome self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_String(self.journalId, serializer);
+sse_encode_list_batch_file_result(self.results, serializer);
+ }
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self ? 1 : 0); }
+
+@protected void sse_encode_box_autoadd_album_loudness(AlbumLoudness self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_album_loudness(self, serializer); }
+
+@protected void sse_encode_box_autoadd_art_source(ArtSource self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_art_source(self, serializer); }
+
+@protected void sse_encode_box_autoadd_batch_edit(BatchEdit self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_batch_edit(self, serializer); }
+
+@protected void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_64(self, serializer); }
+
+@protected void sse_encode_box_autoadd_file_stamp(FileStamp self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_file_stamp(self, serializer); }
+
+@protected void sse_encode_box_autoadd_library_diff(LibraryDiff self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_library_diff(self, serializer); }
+
+@protected void sse_encode_box_autoadd_library_track(LibraryTrack self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_library_track(self, serializer); }
+
+@protected void sse_encode_box_autoadd_metadata_changes(MetadataChanges self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_metadata_changes(self, serializer); }
+
+@protected void sse_encode_box_autoadd_metadata_error(MetadataError self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_metadata_error(self, serializer); }
+
+@protected void sse_encode_box_autoadd_palette(Palette self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_palette(self, serializer); }
+
+@protected void sse_encode_box_autoadd_picture_type(PictureType self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_picture_type(self, serializer); }
+
+@protected void sse_encode_box_autoadd_renumber(Renumber self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_renumber(self, serializer); }
+
+@protected void sse_encode_box_autoadd_replay_gain(ReplayGain self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_replay_gain(self, serializer); }
+
+@protected void sse_encode_box_autoadd_replay_gain_mode(ReplayGainMode self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_replay_gain_mode(self, serializer); }
+
+@protected void sse_encode_box_autoadd_scan_progress(ScanProgress self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_scan_progress(self, serializer); }
+
+@protected void sse_encode_box_autoadd_skipped_file(SkippedFile self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_skipped_file(self, serializer); }
+
+@protected void sse_encode_box_autoadd_swatch(Swatch self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_swatch(self, serializer); }
+
+@protected void sse_encode_box_autoadd_thumbnail(Thumbnail self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_thumbnail(self, serializer); }
+
+@protected void sse_encode_box_autoadd_track_loudness(TrackLoudness self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_track_loudness(self, serializer); }
+
+@protected void sse_encode_box_autoadd_track_path(TrackPath self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_track_path(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_64(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_8(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_8(self, serializer); }
+
+@protected void sse_encode_box_autoadd_usize(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize(self, serializer); }
+
+@protected void sse_encode_f_64(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putFloat64(self); }
+
+@protected void sse_encode_field_change(FieldChange self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_metadata_field(self.field, serializer);
+sse_encode_opt_String(self.before, serializer);
+sse_encode_opt_String(self.after, serializer);
+ }
+
+@protected void sse_encode_file_stamp(FileStamp self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.size, serializer);
+sse_encode_i_64(self.mtime, serializer);
+ }
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putInt32(self); }
+
+@protected void sse_encode_i_64(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putInt64(self); }
+
+@protected void sse_encode_library_diff(LibraryDiff self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_library_track(self.added, serializer);
+sse_encode_list_prim_u_64_strict(self.removed, serializer);
+sse_encode_list_library_track(self.modified, serializer);
+sse_encode_list_track_move(self.moved, serializer);
+ }
+
+@protected void sse_encode_library_event(LibraryEvent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case LibraryEvent_TrackAdded(field0: final field0): sse_encode_i_32(0, serializer); sse_encode_box_autoadd_library_track(field0, serializer);
+case LibraryEvent_TrackRemoved(id: final id): sse_encode_i_32(1, serializer); sse_encode_usize(id, serializer);
+case LibraryEvent_TrackChanged(field0: final field0): sse_encode_i_32(2, serializer); sse_encode_box_autoadd_library_track(field0, serializer);
+case LibraryEvent_TrackMoved(from: final from,track: final track): sse_encode_i_32(3, serializer); sse_encode_box_autoadd_track_path(from, serializer);
+sse_encode_box_autoadd_library_track(track, serializer);
+  } }
+
+@protected void sse_encode_library_root(LibraryRoot self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_track_path(self.path, serializer);
+sse_encode_bool(self.enabled, serializer);
+ }
+
+@protected void sse_encode_library_track(LibraryTrack self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_track(self.track, serializer);
+sse_encode_opt_String(self.title, serializer);
+sse_encode_opt_String(self.artist, serializer);
+sse_encode_opt_String(self.album, serializer);
+sse_encode_opt_String(self.albumArtist, serializer);
+sse_encode_opt_String(self.genre, serializer);
+sse_encode_opt_box_autoadd_u_32(self.year, serializer);
+sse_encode_opt_box_autoadd_u_32(self.trackNumber, serializer);
+sse_encode_opt_box_autoadd_u_32(self.discNumber, serializer);
+sse_encode_opt_box_autoadd_u_64(self.durationMs, serializer);
+ }
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_String(item, serializer); } }
+
+@protected void sse_encode_list_album(List<Album> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_album(item, serializer); } }
+
+@protected void sse_encode_list_batch_file(List<BatchFile> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_batch_file(item, serializer); } }
+
+@protected void sse_encode_list_batch_file_result(List<BatchFileResult> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_batch_file_result(item, serializer); } }
+
+@protected void sse_encode_list_field_change(List<FieldChange> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_field_change(item, serializer); } }
+
+@protected void sse_encode_list_library_root(List<LibraryRoot> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_library_root(item, serializer); } }
+
+@protected void sse_encode_list_library_track(List<LibraryTrack> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_library_track(item, serializer); } }
+
+@protected void sse_encode_list_loudness_failure(List<LoudnessFailure> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_loudness_failure(item, serializer); } }
+
+@protected void sse_encode_list_lyric_line(List<LyricLine> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_lyric_line(item, serializer); } }
+
+@protected void sse_encode_list_lyric_word(List<LyricWord> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_lyric_word(item, serializer); } }
+
+@protected void sse_encode_list_lyrics(List<Lyrics> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_lyrics(item, serializer); } }
+
+@protected void sse_encode_list_metadata_field(List<MetadataField> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_metadata_field(item, serializer); } }
+
+@protected void sse_encode_list_opt_box_autoadd_thumbnail(List<Thumbnail?> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_opt_box_autoadd_thumbnail(item, serializer); } }
+
+@protected void sse_encode_list_picture_info(List<PictureInfo> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_picture_info(item, serializer); } }
+
+@protected void sse_encode_list_prim_u_64_strict(Uint64List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint64List(self); }
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint8List(self); }
+
+@protected void sse_encode_list_skipped_file(List<SkippedFile> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_skipped_file(item, serializer); } }
+
+@protected void sse_encode_list_track(List<Track> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_track(item, serializer); } }
+
+@protected void sse_encode_list_track_loudness(List<TrackLoudness> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_track_loudness(item, serializer); } }
+
+@protected void sse_encode_list_track_move(List<TrackMove> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_track_move(item, serializer); } }
+
+@protected void sse_encode_list_track_path(List<TrackPath> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_track_path(item, serializer); } }
+
+@protected void sse_encode_loudness_failure(LoudnessFailure self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_track_path(self.path, serializer);
+sse_encode_String(self.error, serializer);
+ }
+
+@protected void sse_encode_loudness_report(LoudnessReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_track_loudness(self.tracks, serializer);
+sse_encode_opt_box_autoadd_album_loudness(self.album, serializer);
+sse_encode_list_loudness_failure(self.failed, serializer);
+ }
+
+@protected void sse_encode_lyric_line(LyricLine self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_box_autoadd_u_64(self.startMs, serializer);
+sse_encode_String(self.text, serializer);
+sse_encode_list_lyric_word(self.words, serializer);
+ }
+
+@protected void sse_encode_lyric_word(LyricWord self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.startMs, serializer);
+sse_encode_opt_box_autoadd_u_64(self.endMs, serializer);
+sse_encode_String(self.text, serializer);
+ }
+
+@protected void sse_encode_lyrics(Lyrics self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_lyrics_source(self.source, serializer);
+sse_encode_opt_String(self.language, serializer);
+sse_encode_bool(self.synced, serializer);
+sse_encode_i_64(self.offsetMs, serializer);
+sse_encode_list_lyric_line(self.lines, serializer);
+sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockLyricsTimeline(self.timeline, serializer);
+ }
+
+@protected void sse_encode_lyrics_position(LyricsPosition self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_box_autoadd_usize(self.line, serializer);
+sse_encode_opt_box_autoadd_usize(self.word, serializer);
+sse_encode_f_64(self.wordProgress, serializer);
+ }
+
+@protected void sse_encode_lyrics_source(LyricsSource self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case LyricsSource_Tag(): sse_encode_i_32(0, serializer); case LyricsSource_SyncedTag(): sse_encode_i_32(1, serializer); case LyricsSource_Sidecar(path: final path): sse_encode_i_32(2, serializer); sse_encode_box_autoadd_track_path(path, serializer);
+  } }
+
+@protected void sse_encode_metadata(Metadata self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_list_prim_u_8_strict(self.art, serializer);
+sse_encode_opt_box_autoadd_art_source(self.artSource, serializer);
+sse_encode_opt_String(self.title, serializer);
+sse_encode_opt_String(self.artist, serializer);
+sse_encode_opt_String(self.album, serializer);
+sse_encode_opt_String(self.albumArtist, serializer);
+sse_encode_opt_box_autoadd_u_32(self.trackNumber, serializer);
+sse_encode_opt_box_autoadd_u_32(self.trackTotal, serializer);
+sse_encode_opt_box_autoadd_u_32(self.discNumber, serializer);
+sse_encode_opt_box_autoadd_u_32(self.discTotal, serializer);
+sse_encode_opt_box_autoadd_u_32(self.year, serializer);
+sse_encode_opt_String(self.genre, serializer);
+sse_encode_opt_String(self.composer, serializer);
+sse_encode_opt_String(self.comment, serializer);
+sse_encode_opt_String(self.titleSort, serializer);
+sse_encode_opt_String(self.artistSort, serializer);
+sse_encode_opt_String(self.albumSort, serializer);
+sse_encode_opt_String(self.albumArtistSort, serializer);
+sse_encode_audio_properties(self.properties, serializer);
+sse_encode_replay_gain(self.replayGain, serializer);
+sse_encode_file_stamp(self.stamp, serializer);
+ }
+
+@protected void sse_encode_metadata_changes(MetadataChanges self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_String(self.title, serializer);
+sse_encode_opt_String(self.artist, serializer);
+sse_encode_opt_String(self.album, serializer);
+sse_encode_opt_String(self.albumArtist, serializer);
+sse_encode_opt_box_autoadd_u_32(self.trackNumber, serializer);
+sse_encode_opt_box_autoadd_u_32(self.trackTotal, serializer);
+sse_encode_opt_box_autoadd_u_32(self.discNumber, serializer);
+sse_encode_opt_box_autoadd_u_32(self.discTotal, serializer);
+sse_encode_opt_box_autoadd_u_32(self.year, serializer);
+sse_encode_opt_String(self.genre, serializer);
+sse_encode_opt_String(self.composer, serializer);
+sse_encode_opt_String(self.comment, serializer);
+sse_encode_opt_String(self.lyrics, serializer);
+sse_encode_list_metadata_field(self.clear, serializer);
+ }
+
+@protected void sse_encode_metadata_error(MetadataError self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case MetadataError_NotFound(): sse_encode_i_32(0, serializer); case MetadataError_UnsupportedFormat(): sse_encode_i_32(1, serializer); case MetadataError_CorruptTag(field0: final field0): sse_encode_i_32(2, serializer); sse_encode_String(field0, serializer);
+case MetadataError_Modified(): sse_encode_i_32(3, serializer); case MetadataError_Io(field0: final field0): sse_encode_i_32(4, serializer); sse_encode_String(field0, serializer);
+  } }
+
+@protected void sse_encode_metadata_field(MetadataField self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_String(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_album_loudness(AlbumLoudness? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_album_loudness(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_art_source(ArtSource? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_art_source(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_f_64(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_file_stamp(FileStamp? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_file_stamp(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_library_track(LibraryTrack? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_library_track(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_metadata_error(MetadataError? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_metadata_error(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_palette(Palette? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_palette(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_renumber(Renumber? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_renumber(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_swatch(Swatch? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_swatch(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_thumbnail(Thumbnail? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_thumbnail(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_track_loudness(TrackLoudness? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_track_loudness(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_32(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_64(int? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_64(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_8(int? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_8(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_usize(int? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_usize(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_list_prim_u_8_strict(Uint8List? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_list_prim_u_8_strict(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_palette(Palette self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_swatch(self.dominant, serializer);
+sse_encode_opt_box_autoadd_swatch(self.vibrant, serializer);
+sse_encode_opt_box_autoadd_swatch(self.muted, serializer);
+ }
+
+@protected void sse_encode_picture_info(PictureInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize(self.index, serializer);
+sse_encode_picture_type(self.pictureType, serializer);
+sse_encode_opt_String(self.mimeType, serializer);
+sse_encode_opt_box_autoadd_u_32(self.width, serializer);
+sse_encode_opt_box_autoadd_u_32(self.height, serializer);
+sse_encode_opt_String(self.description, serializer);
+sse_encode_usize(self.size, serializer);
+ }
+
+@protected void sse_encode_picture_type(PictureType self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case PictureType_Other(): sse_encode_i_32(0, serializer); case PictureType_Icon(): sse_encode_i_32(1, serializer); case PictureType_OtherIcon(): sse_encode_i_32(2, serializer); case PictureType_CoverFront(): sse_encode_i_32(3, serializer); case PictureType_CoverBack(): sse_encode_i_32(4, serializer); case PictureType_Leaflet(): sse_encode_i_32(5, serializer); case PictureType_Media(): sse_encode_i_32(6, serializer); case PictureType_LeadArtist(): sse_encode_i_32(7, serializer); case PictureType_Artist(): sse_encode_i_32(8, serializer); case PictureType_Conductor(): sse_encode_i_32(9, serializer); case PictureType_Band(): sse_encode_i_32(10, serializer); case PictureType_Composer(): sse_encode_i_32(11, serializer); case PictureType_Lyricist(): sse_encode_i_32(12, serializer); case PictureType_RecordingLocation(): sse_encode_i_32(13, serializer); case PictureType_DuringRecording(): sse_encode_i_32(14, serializer); case PictureType_DuringPerformance(): sse_encode_i_32(15, serializer); case PictureType_ScreenCapture(): sse_encode_i_32(16, serializer); case PictureType_BrightFish(): sse_encode_i_32(17, serializer); case PictureType_Illustration(): sse_encode_i_32(18, serializer); case PictureType_BandLogo(): sse_encode_i_32(19, serializer); case PictureType_PublisherLogo(): sse_encode_i_32(20, serializer); case PictureType_Undefined(field0: final field0): sse_encode_i_32(21, serializer); sse_encode_u_8(field0, serializer);
+  } }
+
+@protected void sse_encode_renumber(Renumber self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.start, serializer);
+sse_encode_bool(self.setTotal, serializer);
+ }
+
+@protected void sse_encode_replay_gain(ReplayGain self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_box_autoadd_f_64(self.trackGainDb, serializer);
+sse_encode_opt_box_autoadd_f_64(self.trackPeak, serializer);
+sse_encode_opt_box_autoadd_f_64(self.albumGainDb, serializer);
+sse_encode_opt_box_autoadd_f_64(self.albumPeak, serializer);
+ }
+
+@protected void sse_encode_replay_gain_mode(ReplayGainMode self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case ReplayGainMode_Off(): sse_encode_i_32(0, serializer); case ReplayGainMode_Track(): sse_encode_i_32(1, serializer); case ReplayGainMode_Album(): sse_encode_i_32(2, serializer); case ReplayGainMode_Smart(albumInOrder: final albumInOrder): sse_encode_i_32(3, serializer); sse_encode_bool(albumInOrder, serializer);
+  } }
+
+@protected void sse_encode_scan_event(ScanEvent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case ScanEvent_Progress(field0: final field0): sse_encode_i_32(0, serializer); sse_encode_box_autoadd_scan_progress(field0, serializer);
+case ScanEvent_Skipped(field0: final field0): sse_encode_i_32(1, serializer); sse_encode_box_autoadd_skipped_file(field0, serializer);
+case ScanEvent_Finished(field0: final field0): sse_encode_i_32(2, serializer); sse_encode_box_autoadd_library_diff(field0, serializer);
+case ScanEvent_Cancelled(): sse_encode_i_32(3, serializer);   } }
+
+@protected void sse_encode_scan_phase(ScanPhase self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_scan_progress(ScanProgress self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_scan_phase(self.phase, serializer);
+sse_encode_usize(self.filesSeen, serializer);
+sse_encode_usize(self.filesDone, serializer);
+sse_encode_usize(self.filesTotal, serializer);
+sse_encode_opt_String(self.currentPath, serializer);
+ }
+
+@protected void sse_encode_scan_report(ScanReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_track(self.tracks, serializer);
+sse_encode_list_skipped_file(self.skipped, serializer);
+ }
+
+@protected void sse_encode_skip_reason(SkipReason self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case SkipReason_PermissionDenied(): sse_encode_i_32(0, serializer); case SkipReason_UnsupportedFormat(): sse_encode_i_32(1, serializer); case SkipReason_CorruptHeader(): sse_encode_i_32(2, serializer); case SkipReason_SymlinkLoop(): sse_encode_i_32(3, serializer); case SkipReason_Io(field0: final field0): sse_encode_i_32(4, serializer); sse_encode_String(field0, serializer);
+  } }
+
+@protected void sse_encode_skipped_file(SkippedFile self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_track_path(self.path, serializer);
+sse_encode_skip_reason(self.reason, serializer);
+ }
+
+@protected void sse_encode_swatch(Swatch self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.color, serializer);
+sse_encode_u_32(self.foreground, serializer);
+sse_encode_f_64(self.population, serializer);
+ }
+
+@protected void sse_encode_thumbnail(Thumbnail self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(self.data, serializer);
+sse_encode_String(self.artHash, serializer);
+ }
+
+@protected void sse_encode_track(Track self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_track_path(self.path, serializer);
+sse_encode_opt_box_autoadd_usize(self.id, serializer);
+sse_encode_audio_format(self.format, serializer);
+ }
+
+@protected void sse_encode_track_loudness(TrackLoudness self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_track_path(self.path, serializer);
+sse_encode_f_64(self.integratedLufs, serializer);
+sse_encode_f_64(self.loudnessRangeLu, serializer);
+sse_encode_f_64(self.truePeak, serializer);
+sse_encode_f_64(self.trackGainDb, serializer);
+sse_encode_opt_box_autoadd_f_64(self.albumGainDb, serializer);
+sse_encode_opt_box_autoadd_f_64(self.albumTruePeak, serializer);
+sse_encode_opt_String(self.tagError, serializer);
+ }
+
+@protected void sse_encode_track_move(TrackMove self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_track_path(self.from, serializer);
+sse_encode_library_track(self.track, serializer);
+ }
+
+@protected void sse_encode_track_path(TrackPath self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(self.raw, serializer);
+sse_encode_String(self.display, serializer);
+ }
+
+@protected void sse_encode_u_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint32(self); }
+
+@protected void sse_encode_u_64(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint64(self); }
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self); }
+
+@protected void sse_encode_unit(void self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected void sse_encode_usize(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint64(self); }
+                }
+                
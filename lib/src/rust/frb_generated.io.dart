@@ -3,246 +3,843 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+import 'api/artwork.dart';
+import 'api/batch_edit.dart';
+import 'api/config.dart';
+import 'api/library.dart';
+import 'api/loudness.dart';
+import 'api/lyrics.dart';
 import 'api/metadata.dart';
+import 'api/palette.dart';
+import 'api/path.dart';
+import 'api/scan.dart';
+import 'api/thumbnail.dart';
 import 'api/utils.dart';
+import 'api/watcher.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'dart:ffi' as ffi;
 import 'frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_io.dart';
 
-abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
-  RustLibApiImplPlatform({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_TagPtr => wire
-      ._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockTagPtr;
 
-  @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw);
 
-  @protected
-  Tag dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockTag(
-      dynamic raw);
+                abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
+                  RustLibApiImplPlatform({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
 
-  @protected
-  Tag dco_decode_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockTag(
-      dynamic raw);
+                  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_LyricsTimelinePtr => wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockLyricsTimelinePtr;
 
-  @protected
-  String dco_decode_String(dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ScanCancelTokenPtr => wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelTokenPtr;
 
-  @protected
-  Tag dco_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockTag(
-      dynamic raw);
 
-  @protected
-  int dco_decode_box_autoadd_usize(dynamic raw);
 
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+                  @protected AnyhowException dco_decode_AnyhowException(dynamic raw);
 
-  @protected
-  List<Track> dco_decode_list_track(dynamic raw);
+@protected LyricsTimeline dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockLyricsTimeline(dynamic raw);
 
-  @protected
-  Metadata dco_decode_metadata(dynamic raw);
+@protected ScanCancelToken dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken(dynamic raw);
 
-  @protected
-  String? dco_decode_opt_String(dynamic raw);
+@protected LyricsTimeline dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockLyricsTimeline(dynamic raw);
 
-  @protected
-  Tag?
-      dco_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockTag(
-          dynamic raw);
+@protected ScanCancelToken dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken(dynamic raw);
 
-  @protected
-  int? dco_decode_opt_box_autoadd_usize(dynamic raw);
+@protected LyricsTimeline dco_decode_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockLyricsTimeline(dynamic raw);
 
-  @protected
-  Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw);
+@protected ScanCancelToken dco_decode_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken(dynamic raw);
 
-  @protected
-  Track dco_decode_track(dynamic raw);
+@protected String dco_decode_String(dynamic raw);
 
-  @protected
-  int dco_decode_u_8(dynamic raw);
+@protected Album dco_decode_album(dynamic raw);
 
-  @protected
-  int dco_decode_usize(dynamic raw);
+@protected AlbumLoudness dco_decode_album_loudness(dynamic raw);
 
-  @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+@protected ArtSource dco_decode_art_source(dynamic raw);
 
-  @protected
-  Tag sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockTag(
-      SseDeserializer deserializer);
+@protected AudioFormat dco_decode_audio_format(dynamic raw);
 
-  @protected
-  Tag sse_decode_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockTag(
-      SseDeserializer deserializer);
+@protected AudioProperties dco_decode_audio_properties(dynamic raw);
 
-  @protected
-  String sse_decode_String(SseDeserializer deserializer);
+@protected BatchEdit dco_decode_batch_edit(dynamic raw);
 
-  @protected
-  Tag sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockTag(
-      SseDeserializer deserializer);
+@protected BatchFile dco_decode_batch_file(dynamic raw);
 
-  @protected
-  int sse_decode_box_autoadd_usize(SseDeserializer deserializer);
+@protected BatchFileResult dco_decode_batch_file_result(dynamic raw);
 
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+@protected BatchOutcome dco_decode_batch_outcome(dynamic raw);
 
-  @protected
-  List<Track> sse_decode_list_track(SseDeserializer deserializer);
+@protected bool dco_decode_bool(dynamic raw);
 
-  @protected
-  Metadata sse_decode_metadata(SseDeserializer deserializer);
+@protected AlbumLoudness dco_decode_box_autoadd_album_loudness(dynamic raw);
 
-  @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer);
+@protected ArtSource dco_decode_box_autoadd_art_source(dynamic raw);
 
-  @protected
-  Tag?
-      sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockTag(
-          SseDeserializer deserializer);
+@protected BatchEdit dco_decode_box_autoadd_batch_edit(dynamic raw);
 
-  @protected
-  int? sse_decode_opt_box_autoadd_usize(SseDeserializer deserializer);
+@protected double dco_decode_box_autoadd_f_64(dynamic raw);
 
-  @protected
-  Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer);
+@protected FileStamp dco_decode_box_autoadd_file_stamp(dynamic raw);
 
-  @protected
-  Track sse_decode_track(SseDeserializer deserializer);
+@protected LibraryDiff dco_decode_box_autoadd_library_diff(dynamic raw);
 
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
+@protected LibraryTrack dco_decode_box_autoadd_library_track(dynamic raw);
 
-  @protected
-  int sse_decode_usize(SseDeserializer deserializer);
+@protected MetadataChanges dco_decode_box_autoadd_metadata_changes(dynamic raw);
 
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+@protected MetadataError dco_decode_box_autoadd_metadata_error(dynamic raw);
 
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+@protected Palette dco_decode_box_autoadd_palette(dynamic raw);
 
-  @protected
-  void sse_encode_AnyhowException(
-      AnyhowException self, SseSerializer serializer);
+@protected PictureType dco_decode_box_autoadd_picture_type(dynamic raw);
 
-  @protected
-  void
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockTag(
-          Tag self, SseSerializer serializer);
+@protected Renumber dco_decode_box_autoadd_renumber(dynamic raw);
 
-  @protected
-  void
-      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockTag(
-          Tag self, SseSerializer serializer);
+@protected ReplayGain dco_decode_box_autoadd_replay_gain(dynamic raw);
 
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer);
+@protected ReplayGainMode dco_decode_box_autoadd_replay_gain_mode(dynamic raw);
 
-  @protected
-  void
-      sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockTag(
-          Tag self, SseSerializer serializer);
+@protected ScanProgress dco_decode_box_autoadd_scan_progress(dynamic raw);
 
-  @protected
-  void sse_encode_box_autoadd_usize(int self, SseSerializer serializer);
+@protected SkippedFile dco_decode_box_autoadd_skipped_file(dynamic raw);
 
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-      Uint8List self, SseSerializer serializer);
+@protected Swatch dco_decode_box_autoadd_swatch(dynamic raw);
 
-  @protected
-  void sse_encode_list_track(List<Track> self, SseSerializer serializer);
+@protected Thumbnail dco_decode_box_autoadd_thumbnail(dynamic raw);
 
-  @protected
-  void sse_encode_metadata(Metadata self, SseSerializer serializer);
+@protected TrackLoudness dco_decode_box_autoadd_track_loudness(dynamic raw);
 
-  @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer);
+@protected TrackPath dco_decode_box_autoadd_track_path(dynamic raw);
 
-  @protected
-  void
-      sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockTag(
-          Tag? self, SseSerializer serializer);
+@protected int dco_decode_box_autoadd_u_32(dynamic raw);
 
-  @protected
-  void sse_encode_opt_box_autoadd_usize(int? self, SseSerializer serializer);
+@protected int dco_decode_box_autoadd_u_64(dynamic raw);
 
-  @protected
-  void sse_encode_opt_list_prim_u_8_strict(
-      Uint8List? self, SseSerializer serializer);
+@protected int dco_decode_box_autoadd_u_8(dynamic raw);
 
-  @protected
-  void sse_encode_track(Track self, SseSerializer serializer);
+@protected int dco_decode_box_autoadd_usize(dynamic raw);
 
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer);
+@protected double dco_decode_f_64(dynamic raw);
 
-  @protected
-  void sse_encode_usize(int self, SseSerializer serializer);
+@protected FieldChange dco_decode_field_change(dynamic raw);
 
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
+@protected FileStamp dco_decode_file_stamp(dynamic raw);
+
+@protected int dco_decode_i_32(dynamic raw);
+
+@protected int dco_decode_i_64(dynamic raw);
+
+@protected LibraryDiff dco_decode_library_diff(dynamic raw);
+
+@protected LibraryEvent dco_decode_library_event(dynamic raw);
+
+@protected LibraryRoot dco_decode_library_root(dynamic raw);
+
+@protected LibraryTrack dco_decode_library_track(dynamic raw);
+
+@protected List<String> dco_decode_list_String(dynamic raw);
+
+@protected List<Album> dco_decode_list_album(dynamic raw);
+
+@protected List<BatchFile> dco_decode_list_batch_file(dynamic raw);
+
+@protected List<BatchFileResult> dco_decode_list_batch_file_result(dynamic raw);
+
+@protected List<FieldChange> dco_decode_list_field_change(dynamic raw);
+
+@protected List<LibraryRoot> dco_decode_list_library_root(dynamic raw);
+
+@protected List<LibraryTrack> dco_decode_list_library_track(dynamic raw);
+
+@protected List<LoudnessFailure> dco_decode_list_loudness_failure(dynamic raw);
+
+@protected List<LyricLine> dco_decode_list_lyric_line(dynamic raw);
+
+@protected List<LyricWord> dco_decode_list_lyric_word(dynamic raw);
+
+@protected List<Lyrics> dco_decode_list_lyrics(dynamic raw);
+
+@protected List<MetadataField> dco_decode_list_metadata_field(dynamic raw);
+
+@protected List<Thumbnail?> dco_decode_list_opt_box_autoadd_thumbnail(dynamic raw);
+
+@protected List<PictureInfo> dco_decode_list_picture_info(dynamic raw);
+
+@protected Uint64List dco_decode_list_prim_u_64_strict(dynamic raw);
+
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+
+@protected List<SkippedFile> dco_decode_list_skipped_file(dynamic raw);
+
+@protected List<Track> dco_decode_list_track(dynamic raw);
+
+@protected List<TrackLoudness> dco_decode_list_track_loudness(dynamic raw);
+
+@protected List<TrackMove> dco_decode_list_track_move(dynamic raw);
+
+@protected List<TrackPath> dco_decode_list_track_path(dynamic raw);
+
+@protected LoudnessFailure dco_decode_loudness_failure(dynamic raw);
+
+@protected LoudnessReport dco_decode_loudness_report(dynamic raw);
+
+@protected LyricLine dco_decode_lyric_line(dynamic raw);
+
+@protected LyricWord dco_decode_lyric_word(dynamic raw);
+
+@protected Lyrics dco_decode_lyrics(dynamic raw);
+
+@protected LyricsPosition dco_decode_lyrics_position(dynamic raw);
+
+@protected LyricsSource dco_decode_lyrics_source(dynamic raw);
+
+@protected Metadata dco_decode_metadata(dynamic raw);
+
+@protected MetadataChanges dco_decode_metadata_changes(dynamic raw);
+
+@protected MetadataError dco_decode_metadata_error(dynamic raw);
+
+@protected MetadataField dco_decode_metadata_field(dynamic raw);
+
+@protected String? dco_decode_opt_String(dynamic raw);
+
+@protected AlbumLoudness? dco_decode_opt_box_autoadd_album_loudness(dynamic raw);
+
+@protected ArtSource? dco_decode_opt_box_autoadd_art_source(dynamic raw);
+
+@protected double? dco_decode_opt_box_autoadd_f_64(dynamic raw);
+
+@protected FileStamp? dco_decode_opt_box_autoadd_file_stamp(dynamic raw);
+
+@protected LibraryTrack? dco_decode_opt_box_autoadd_library_track(dynamic raw);
+
+@protected MetadataError? dco_decode_opt_box_autoadd_metadata_error(dynamic raw);
+
+@protected Palette? dco_decode_opt_box_autoadd_palette(dynamic raw);
+
+@protected Renumber? dco_decode_opt_box_autoadd_renumber(dynamic raw);
+
+@protected Swatch? dco_decode_opt_box_autoadd_swatch(dynamic raw);
+
+@protected Thumbnail? dco_decode_opt_box_autoadd_thumbnail(dynamic raw);
+
+@protected TrackLoudness? dco_decode_opt_box_autoadd_track_loudness(dynamic raw);
+
+@protected int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+
+@protected int? dco_decode_opt_box_autoadd_u_64(dynamic raw);
+
+@protected int? dco_decode_opt_box_autoadd_u_8(dynamic raw);
+
+@protected int? dco_decode_opt_box_autoadd_usize(dynamic raw);
+
+@protected Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw);
+
+@protected Palette dco_decode_palette(dynamic raw);
+
+@protected PictureInfo dco_decode_picture_info(dynamic raw);
+
+@protected PictureType dco_decode_picture_type(dynamic raw);
+
+@protected Renumber dco_decode_renumber(dynamic raw);
+
+@protected ReplayGain dco_decode_replay_gain(dynamic raw);
+
+@protected ReplayGainMode dco_decode_replay_gain_mode(dynamic raw);
+
+@protected ScanEvent dco_decode_scan_event(dynamic raw);
+
+@protected ScanPhase dco_decode_scan_phase(dynamic raw);
+
+@protected ScanProgress dco_decode_scan_progress(dynamic raw);
+
+@protected ScanReport dco_decode_scan_report(dynamic raw);
+
+@protected SkipReason dco_decode_skip_reason(dynamic raw);
+
+@protected SkippedFile dco_decode_skipped_file(dynamic raw);
+
+@protected Swatch dco_decode_swatch(dynamic raw);
+
+@protected Thumbnail dco_decode_thumbnail(dynamic raw);
+
+@protected Track dco_decode_track(dynamic raw);
+
+@protected TrackLoudness dco_decode_track_loudness(dynamic raw);
+
+@protected TrackMove dco_decode_track_move(dynamic raw);
+
+@protected TrackPath dco_decode_track_path(dynamic raw);
+
+@protected int dco_decode_u_32(dynamic raw);
+
+@protected int dco_decode_u_64(dynamic raw);
+
+@protected int dco_decode_u_8(dynamic raw);
+
+@protected void dco_decode_unit(dynamic raw);
+
+@protected int dco_decode_usize(dynamic raw);
+
+@protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+
+@protected LyricsTimeline sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockLyricsTimeline(SseDeserializer deserializer);
+
+@protected ScanCancelToken sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken(SseDeserializer deserializer);
+
+@protected LyricsTimeline sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockLyricsTimeline(SseDeserializer deserializer);
+
+@protected ScanCancelToken sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken(SseDeserializer deserializer);
+
+@protected LyricsTimeline sse_decode_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockLyricsTimeline(SseDeserializer deserializer);
+
+@protected ScanCancelToken sse_decode_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken(SseDeserializer deserializer);
+
+@protected String sse_decode_String(SseDeserializer deserializer);
+
+@protected Album sse_decode_album(SseDeserializer deserializer);
+
+@protected AlbumLoudness sse_decode_album_loudness(SseDeserializer deserializer);
+
+@protected ArtSource sse_decode_art_source(SseDeserializer deserializer);
+
+@protected AudioFormat sse_decode_audio_format(SseDeserializer deserializer);
+
+@protected AudioProperties sse_decode_audio_properties(SseDeserializer deserializer);
+
+@protected BatchEdit sse_decode_batch_edit(SseDeserializer deserializer);
+
+@protected BatchFile sse_decode_batch_file(SseDeserializer deserializer);
+
+@protected BatchFileResult sse_decode_batch_file_result(SseDeserializer deserializer);
+
+@protected BatchOutcome sse_decode_batch_outcome(SseDeserializer deserializer);
+
+@protected bool sse_decode_bool(SseDeserializer deserializer);
+
+@protected AlbumLoudness sse_decode_box_autoadd_album_loudness(SseDeserializer deserializer);
+
+@protected ArtSource sse_decode_box_autoadd_art_source(SseDeserializer deserializer);
+
+@protected BatchEdit sse_decode_box_autoadd_batch_edit(SseDeserializer deserializer);
+
+@protected double sse_decode_box_autoadd_f_64(SseDeserializer deserializer);
+
+@protected FileStamp sse_decode_box_autoadd_file_stamp(SseDeserializer deserializer);
+
+@protected LibraryDiff sse_decode_box_autoadd_library_diff(SseDeserializer deserializer);
+
+@protected LibraryTrack sse_decode_box_autoadd_library_track(SseDeserializer deserializer);
+
+@protected MetadataChanges sse_decode_box_autoadd_metadata_changes(SseDeserializer deserializer);
+
+@protected MetadataError sse_decode_box_autoadd_metadata_error(SseDeserializer deserializer);
+
+@protected Palette sse_decode_box_autoadd_palette(SseDeserializer deserializer);
+
+@protected PictureType sse_decode_box_autoadd_picture_type(SseDeserializer deserializer);
+
+@protected Renumber sse_decode_box_autoadd_renumber(SseDeserializer deserializer);
+
+@protected ReplayGain sse_decode_box_autoadd_replay_gain(SseDeserializer deserializer);
+
+@protected ReplayGainMode sse_decode_box_autoadd_replay_gain_mode(SseDeserializer deserializer);
+
+@protected ScanProgress sse_decode_box_autoadd_scan_progress(SseDeserializer deserializer);
+
+@protected SkippedFile sse_decode_box_autoadd_skipped_file(SseDeserializer deserializer);
+
+@protected Swatch sse_decode_box_autoadd_swatch(SseDeserializer deserializer);
+
+@protected Thumbnail sse_decode_box_autoadd_thumbnail(SseDeserializer deserializer);
+
+@protected TrackLoudness sse_decode_box_autoadd_track_loudness(SseDeserializer deserializer);
+
+@protected TrackPath sse_decode_box_autoadd_track_path(SseDeserializer deserializer);
+
+@protected int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
+@protected int sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+
+@protected int sse_decode_box_autoadd_u_8(SseDeserializer deserializer);
+
+@protected int sse_decode_box_autoadd_usize(SseDeserializer deserializer);
+
+@protected double sse_decode_f_64(SseDeserializer deserializer);
+
+@protected FieldChange sse_decode_field_change(SseDeserializer deserializer);
+
+@protected FileStamp sse_decode_file_stamp(SseDeserializer deserializer);
+
+@protected int sse_decode_i_32(SseDeserializer deserializer);
+
+@protected int sse_decode_i_64(SseDeserializer deserializer);
+
+@protected LibraryDiff sse_decode_library_diff(SseDeserializer deserializer);
+
+@protected LibraryEvent sse_decode_library_event(SseDeserializer deserializer);
+
+@protected LibraryRoot sse_decode_library_root(SseDeserializer deserializer);
+
+@protected LibraryTrack sse_decode_library_track(SseDeserializer deserializer);
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+@protected List<Album> sse_decode_list_album(SseDeserializer deserializer);
+
+@protected List<BatchFile> sse_decode_list_batch_file(SseDeserializer deserializer);
+
+@protected List<BatchFileResult> sse_decode_list_batch_file_result(SseDeserializer deserializer);
+
+@protected List<FieldChange> sse_decode_list_field_change(SseDeserializer deserializer);
+
+@protected List<LibraryRoot> sse_decode_list_library_root(SseDeserializer deserializer);
+
+@protected List<LibraryTrack> sse_decode_list_library_track(SseDeserializer deserializer);
+
+@protected List<LoudnessFailure> sse_decode_list_loudness_failure(SseDeserializer deserializer);
+
+@protected List<LyricLine> sse_decode_list_lyric_line(SseDeserializer deserializer);
+
+@protected List<LyricWord> sse_decode_list_lyric_word(SseDeserializer deserializer);
+
+@protected List<Lyrics> sse_decode_list_lyrics(SseDeserializer deserializer);
+
+@protected List<MetadataField> sse_decode_list_metadata_field(SseDeserializer deserializer);
+
+@protected List<Thumbnail?> sse_decode_list_opt_box_autoadd_thumbnail(SseDeserializer deserializer);
+
+@protected List<PictureInfo> sse_decode_list_picture_info(SseDeserializer deserializer);
+
+@protected Uint64List sse_decode_list_prim_u_64_strict(SseDeserializer deserializer);
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+
+@protected List<SkippedFile> sse_decode_list_skipped_file(SseDeserializer deserializer);
+
+@protected List<Track> sse_decode_list_track(SseDeserializer deserializer);
+
+@protected List<TrackLoudness> sse_decode_list_track_loudness(SseDeserializer deserializer);
+
+@protected List<TrackMove> sse_decode_list_track_move(SseDeserializer deserializer);
+
+@protected List<TrackPath> sse_decode_list_track_path(SseDeserializer deserializer);
+
+@protected LoudnessFailure sse_decode_loudness_failure(SseDeserializer deserializer);
+
+@protected LoudnessReport sse_decode_loudness_report(SseDeserializer deserializer);
+
+@protected LyricLine sse_decode_lyric_line(SseDeserializer deserializer);
+
+@protected LyricWord sse_decode_lyric_word(SseDeserializer deserializer);
+
+@protected Lyrics sse_decode_lyrics(SseDeserializer deserializer);
+
+@protected LyricsPosition sse_decode_lyrics_position(SseDeserializer deserializer);
+
+@protected LyricsSource sse_decode_lyrics_source(SseDeserializer deserializer);
+
+@protected Metadata sse_decode_metadata(SseDeserializer deserializer);
+
+@protected MetadataChanges sse_decode_metadata_changes(SseDeserializer deserializer);
+
+@protected MetadataError sse_decode_metadata_error(SseDeserializer deserializer);
+
+@protected MetadataField sse_decode_metadata_field(SseDeserializer deserializer);
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer);
+
+@protected AlbumLoudness? sse_decode_opt_box_autoadd_album_loudness(SseDeserializer deserializer);
+
+@protected ArtSource? sse_decode_opt_box_autoadd_art_source(SseDeserializer deserializer);
+
+@protected double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer);
+
+@protected FileStamp? sse_decode_opt_box_autoadd_file_stamp(SseDeserializer deserializer);
+
+@protected LibraryTrack? sse_decode_opt_box_autoadd_library_track(SseDeserializer deserializer);
+
+@protected MetadataError? sse_decode_opt_box_autoadd_metadata_error(SseDeserializer deserializer);
+
+@protected Palette? sse_decode_opt_box_autoadd_palette(SseDeserializer deserializer);
+
+@protected Renumber? sse_decode_opt_box_autoadd_renumber(SseDeserializer deserializer);
+
+@protected Swatch? sse_decode_opt_box_autoadd_swatch(SseDeserializer deserializer);
+
+@protected Thumbnail? sse_decode_opt_box_autoadd_thumbnail(SseDeserializer deserializer);
+
+@protected TrackLoudness? sse_decode_opt_box_autoadd_track_loudness(SseDeserializer deserializer);
+
+@protected int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+@protected int? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+
+@protected int? sse_decode_opt_box_autoadd_u_8(SseDeserializer deserializer);
+
+@protected int? sse_decode_opt_box_autoadd_usize(SseDeserializer deserializer);
+
+@protected Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer);
+
+@protected Palette sse_decode_palette(SseDeserializer deserializer);
+
+@protected PictureInfo sse_decode_picture_info(SseDeserializer deserializer);
+
+@protected PictureType sse_decode_picture_type(SseDeserializer deserializer);
+
+@protected Renumber sse_decode_renumber(SseDeserializer deserializer);
+
+@protected ReplayGain sse_decode_replay_gain(SseDeserializer deserializer);
+
+@protected ReplayGainMode sse_decode_replay_gain_mode(SseDeserializer deserializer);
+
+@protected ScanEvent sse_decode_scan_event(SseDeserializer deserializer);
+
+@protected ScanPhase sse_decode_scan_phase(SseDeserializer deserializer);
+
+@protected ScanProgress sse_decode_scan_progress(SseDeserializer deserializer);
+
+@protected ScanReport sse_decode_scan_report(SseDeserializer deserializer);
+
+@protected SkipReason sse_decode_skip_reason(SseDeserializer deserializer);
+
+@protected SkippedFile sse_decode_skipped_file(SseDeserializer deserializer);
+
+@protected Swatch sse_decode_swatch(SseDeserializer deserializer);
+
+@protected Thumbnail sse_decode_thumbnail(SseDeserializer deserializer);
+
+@protected Track sse_decode_track(SseDeserializer deserializer);
+
+@protected TrackLoudness sse_decode_track_loudness(SseDeserializer deserializer);
+
+@protected TrackMove sse_decode_track_move(SseDeserializer deserializer);
+
+@protected TrackPath sse_decode_track_path(SseDeserializer deserializer);
+
+@protected int sse_decode_u_32(SseDeserializer deserializer);
+
+@protected int sse_decode_u_64(SseDeserializer deserializer);
+
+@protected int sse_decode_u_8(SseDeserializer deserializer);
+
+@protected void sse_decode_unit(SseDeserializer deserializer);
+
+@protected int sse_decode_usize(SseDeserializer deserializer);
+
+@protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockLyricsTimeline(LyricsTimeline self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken(ScanCancelToken self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockLyricsTimeline(LyricsTimeline self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken(ScanCancelToken self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockLyricsTimeline(LyricsTimeline self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken(ScanCancelToken self, SseSerializer serializer);
+
+@protected void sse_encode_String(String self, SseSerializer serializer);
+
+@protected void sse_encode_album(Album self, SseSerializer serializer);
+
+@protected void sse_encode_album_loudness(AlbumLoudness self, SseSerializer serializer);
+
+@protected void sse_encode_art_source(ArtSource self, SseSerializer serializer);
+
+@protected void sse_encode_audio_format(AudioFormat self, SseSerializer serializer);
+
+@protected void sse_encode_audio_properties(AudioProperties self, SseSerializer serializer);
+
+@protected void sse_encode_batch_edit(BatchEdit self, SseSerializer serializer);
+
+@protected void sse_encode_batch_file(BatchFile self, SseSerializer serializer);
+
+@protected void sse_encode_batch_file_result(BatchFileResult self, SseSerializer serializer);
+
+@protected void sse_encode_batch_outcome(BatchOutcome self, SseSerializer serializer);
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_album_loudness(AlbumLoudness self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_art_source(ArtSource self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_batch_edit(BatchEdit self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_file_stamp(FileStamp self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_library_diff(LibraryDiff self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_library_track(LibraryTrack self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_metadata_changes(MetadataChanges self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_metadata_error(MetadataError self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_palette(Palette self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_picture_type(PictureType self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_renumber(Renumber self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_replay_gain(ReplayGain self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_replay_gain_mode(ReplayGainMode self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_scan_progress(ScanProgress self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_skipped_file(SkippedFile self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_swatch(Swatch self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_thumbnail(Thumbnail self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_track_loudness(TrackLoudness self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_track_path(TrackPath self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_64(int self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_8(int self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_usize(int self, SseSerializer serializer);
+
+@protected void sse_encode_f_64(double self, SseSerializer serializer);
+
+@protected void sse_encode_field_change(FieldChange self, SseSerializer serializer);
+
+@protected void sse_encode_file_stamp(FileStamp self, SseSerializer serializer);
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_i_64(int self, SseSerializer serializer);
+
+@protected void sse_encode_library_diff(LibraryDiff self, SseSerializer serializer);
+
+@protected void sse_encode_library_event(LibraryEvent self, SseSerializer serializer);
+
+@protected void sse_encode_library_root(LibraryRoot self, SseSerializer serializer);
+
+@protected void sse_encode_library_track(LibraryTrack self, SseSerializer serializer);
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer);
+
+@protected void sse_encode_list_album(List<Album> self, SseSerializer serializer);
+
+@protected void sse_encode_list_batch_file(List<BatchFile> self, SseSerializer serializer);
+
+@protected void sse_encode_list_batch_file_result(List<BatchFileResult> self, SseSerializer serializer);
+
+@protected void sse_encode_list_field_change(List<FieldChange> self, SseSerializer serializer);
+
+@protected void sse_encode_list_library_root(List<LibraryRoot> self, SseSerializer serializer);
+
+@protected void sse_encode_list_library_track(List<LibraryTrack> self, SseSerializer serializer);
+
+@protected void sse_encode_list_loudness_failure(List<LoudnessFailure> self, SseSerializer serializer);
+
+@protected void sse_encode_list_lyric_line(List<LyricLine> self, SseSerializer serializer);
+
+@protected void sse_encode_list_lyric_word(List<LyricWord> self, SseSerializer serializer);
+
+@protected void sse_encode_list_lyrics(List<Lyrics> self, SseSerializer serializer);
+
+@protected void sse_encode_list_metadata_field(List<MetadataField> self, SseSerializer serializer);
+
+@protected void sse_encode_list_opt_box_autoadd_thumbnail(List<Thumbnail?> self, SseSerializer serializer);
+
+@protected void sse_encode_list_picture_info(List<PictureInfo> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_64_strict(Uint64List self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer);
+
+@protected void sse_encode_list_skipped_file(List<SkippedFile> self, SseSerializer serializer);
+
+@protected void sse_encode_list_track(List<Track> self, SseSerializer serializer);
+
+@protected void sse_encode_list_track_loudness(List<TrackLoudness> self, SseSerializer serializer);
+
+@protected void sse_encode_list_track_move(List<TrackMove> self, SseSerializer serializer);
+
+@protected void sse_encode_list_track_path(List<TrackPath> self, SseSerializer serializer);
+
+@protected void sse_encode_loudness_failure(LoudnessFailure self, SseSerializer serializer);
+
+@protected void sse_encode_loudness_report(LoudnessReport self, SseSerializer serializer);
+
+@protected void sse_encode_lyric_line(LyricLine self, SseSerializer serializer);
+
+@protected void sse_encode_lyric_word(LyricWord self, SseSerializer serializer);
+
+@protected void sse_encode_lyrics(Lyrics self, SseSerializer serializer);
+
+@protected void sse_encode_lyrics_position(LyricsPosition self, SseSerializer serializer);
+
+@protected void sse_encode_lyrics_source(LyricsSource self, SseSerializer serializer);
+
+@protected void sse_encode_metadata(Metadata self, SseSerializer serializer);
+
+@protected void sse_encode_metadata_changes(MetadataChanges self, SseSerializer serializer);
+
+@protected void sse_encode_metadata_error(MetadataError self, SseSerializer serializer);
+
+@protected void sse_encode_metadata_field(MetadataField self, SseSerializer serializer);
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_album_loudness(AlbumLoudness? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_art_source(ArtSource? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_file_stamp(FileStamp? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_library_track(LibraryTrack? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_metadata_error(MetadataError? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_palette(Palette? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_renumber(Renumber? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_swatch(Swatch? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_thumbnail(Thumbnail? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_track_loudness(TrackLoudness? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_u_64(int? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_u_8(int? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_usize(int? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_list_prim_u_8_strict(Uint8List? self, SseSerializer serializer);
+
+@protected void sse_encode_palette(Palette self, SseSerializer serializer);
+
+@protected void sse_encode_picture_info(PictureInfo self, SseSerializer serializer);
+
+@protected void sse_encode_picture_type(PictureType self, SseSerializer serializer);
+
+@protected void sse_encode_renumber(Renumber self, SseSerializer serializer);
+
+@protected void sse_encode_replay_gain(ReplayGain self, SseSerializer serializer);
+
+@protected void sse_encode_replay_gain_mode(ReplayGainMode self, SseSerializer serializer);
+
+@protected void sse_encode_scan_event(ScanEvent self, SseSerializer serializer);
+
+@protected void sse_encode_scan_phase(ScanPhase self, SseSerializer serializer);
+
+@protected void sse_encode_scan_progress(ScanProgress self, SseSerializer serializer);
+
+@protected void sse_encode_scan_report(ScanReport self, SseSerializer serializer);
+
+@protected void sse_encode_skip_reason(SkipReason self, SseSerializer serializer);
+
+@protected void sse_encode_skipped_file(SkippedFile self, SseSerializer serializer);
+
+@protected void sse_encode_swatch(Swatch self, SseSerializer serializer);
+
+@protected void sse_encode_thumbnail(Thumbnail self, SseSerializer serializer);
+
+@protected void sse_encode_track(Track self, SseSerializer serializer);
+
+@protected void sse_encode_track_loudness(TrackLoudness self, SseSerializer serializer);
+
+@protected void sse_encode_track_move(TrackMove self, SseSerializer serializer);
+
+@protected void sse_encode_track_path(TrackPath self, SseSerializer serializer);
+
+@protected void sse_encode_u_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_u_64(int self, SseSerializer serializer);
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer);
+
+@protected void sse_encode_unit(void self, SseSerializer serializer);
+
+@protected void sse_encode_usize(int self, SseSerializer serializer);
+                }
+                
 
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
-}
 
 // Section: wire_class
 
-class RustLibWire implements BaseWire {
-  factory RustLibWire.fromExternalLibrary(ExternalLibrary lib) =>
-      RustLibWire(lib.ffiDynamicLibrary);
-
-  /// Holds the symbol lookup function.
-  final ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName)
-      _lookup;
-
-  /// The symbols are looked up in [dynamicLibrary].
-  RustLibWire(ffi.DynamicLibrary dynamicLibrary)
-      : _lookup = dynamicLibrary.lookup;
-
-  void
-      rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockTag(
-    ffi.Pointer<ffi.Void> ptr,
-  ) {
-    return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockTag(
-      ptr,
-    );
-  }
-
-  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockTagPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-          'frbgen_amai_music_player_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockTag');
-  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockTag =
-      _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockTagPtr
-          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
-
-  void
-      rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockTag(
-    ffi.Pointer<ffi.Void> ptr,
-  ) {
-    return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockTag(
-      ptr,
-    );
-  }
-
-  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockTagPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-          'frbgen_amai_music_player_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockTag');
-  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockTag =
-      _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockTagPtr
-          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
-}
+
+        class RustLibWire implements BaseWire {
+
+            factory RustLibWire.fromExternalLibrary(ExternalLibrary lib) =>
+              RustLibWire(lib.ffiDynamicLibrary);
+        
+            /// Holds the symbol lookup function.
+            final ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName)
+                _lookup;
+  
+            /// The symbols are looked up in [dynamicLibrary].
+            RustLibWire(ffi.DynamicLibrary dynamicLibrary)
+                : _lookup = dynamicLibrary.lookup;
+
+            
+            void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockLyricsTimeline(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockLyricsTimeline(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockLyricsTimelinePtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_amai_music_player_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockLyricsTimeline');
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockLyricsTimeline = _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockLyricsTimelinePtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockLyricsTimeline(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockLyricsTimeline(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockLyricsTimelinePtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_amai_music_player_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockLyricsTimeline');
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockLyricsTimeline = _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockLyricsTimelinePtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelTokenPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_amai_music_player_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken');
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken = _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelTokenPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelTokenPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_amai_music_player_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken');
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken = _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelTokenPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+        }
+        
\ No newline at end of file
@@ -0,0 +1,307 @@
+use crate::api::metadata::{all_tags, MetadataError};
+use crate::api::path::TrackPath;
+use lofty::{MimeType, Picture, PictureInformation, Tag};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const IMAGE_EXTENSIONS: &[&str] = &["jpg", "jpeg", "png", "webp", "gif", "bmp"];
+
+// well known names for the front cover, best first
+const COVER_NAMES: &[&str] = &["cover", "folder", "front", "albumart", "album", "albumartlarge"];
+
+// words that mark an image as something other than the front cover
+const NON_COVER_WORDS: &[&str] = &[
+    "back", "inlay", "inside", "booklet", "cd", "disc", "disk", "tray", "artist", "scan",
+];
+
+/// What an embedded picture shows, as declared by the tag.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum PictureType {
+    Other,
+    Icon,
+    OtherIcon,
+    CoverFront,
+    CoverBack,
+    Leaflet,
+    Media,
+    LeadArtist,
+    Artist,
+    Conductor,
+    Band,
+    Composer,
+    Lyricist,
+    RecordingLocation,
+    DuringRecording,
+    DuringPerformance,
+    ScreenCapture,
+    BrightFish,
+    Illustration,
+    BandLogo,
+    PublisherLogo,
+    Undefined(u8),
+}
+
+impl PictureType {
+    fn new(picture_type: lofty::PictureType) -> Self {
+        match picture_type {
+            lofty::PictureType::Other => Self::Other,
+            lofty::PictureType::Icon => Self::Icon,
+            lofty::PictureType::OtherIcon => Self::OtherIcon,
+            lofty::PictureType::CoverFront => Self::CoverFront,
+            lofty::PictureType::CoverBack => Self::CoverBack,
+            lofty::PictureType::Leaflet => Self::Leaflet,
+            lofty::PictureType::Media => Self::Media,
+            lofty::PictureType::LeadArtist => Self::LeadArtist,
+            lofty::PictureType::Artist => Self::Artist,
+            lofty::PictureType::Conductor => Self::Conductor,
+            lofty::PictureType::Band => Self::Band,
+            lofty::PictureType::Composer => Self::Composer,
+            lofty::PictureType::Lyricist => Self::Lyricist,
+            lofty::PictureType::RecordingLocation => Self::RecordingLocation,
+            lofty::PictureType::DuringRecording => Self::DuringRecording,
+            lofty::PictureType::DuringPerformance => Self::DuringPerformance,
+            lofty::PictureType::ScreenCapture => Self::ScreenCapture,
+            lofty::PictureType::BrightFish => Self::BrightFish,
+            lofty::PictureType::Illustration => Self::Illustration,
+            lofty::PictureType::BandLogo => Self::BandLogo,
+            lofty::PictureType::PublisherLogo => Self::PublisherLogo,
+            lofty::PictureType::Undefined(value) => Self::Undefined(value),
+            _ => Self::Other,
+        }
+    }
+
+    // lower is a better stand-in for the front cover
+    fn cover_rank(self) -> u8 {
+        match self {
+            Self::CoverFront => 0,
+            // most taggers write untyped pictures, which are nearly always the cover
+            Self::Other | Self::Undefined(_) => 1,
+            Self::Media | Self::Illustration | Self::Leaflet => 2,
+            Self::Icon | Self::OtherIcon => 4,
+            _ => 3,
+        }
+    }
+}
+
+/// Where `Metadata::art` came from.
+#[derive(Clone)]
+pub enum ArtSource {
+    Embedded { picture_type: PictureType },
+    /// An image file next to the track, or next to its disc folder.
+    Folder { path: TrackPath },
+}
+
+pub struct PictureInfo {
+    /// Pass to `get_picture_data` to load this picture.
+    pub index: usize,
+    pub picture_type: PictureType,
+    pub mime_type: Option<String>,
+    /// Only known for PNG and JPEG.
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    pub description: Option<String>,
+    pub size: usize,
+}
+
+/// Picks the picture that best serves as the front cover, earlier tags win ties.
+fn front_cover<'a>(tags: &[&'a Tag]) -> Option<&'a Picture> {
+    tags.iter()
+        .copied()
+        .flat_map(Tag::pictures)
+        .min_by_key(|picture| PictureType::new(picture.pic_type()).cover_rank())
+}
+
+/// Returns the embedded front cover of a track, or the best image file next to it.
+pub(crate) fn find_art(tags: &[&Tag], track: &Path) -> Option<(Vec<u8>, ArtSource)> {
+    if let Some(picture) = front_cover(tags) {
+        let source = ArtSource::Embedded {
+            picture_type: PictureType::new(picture.pic_type()),
+        };
+        return Some((picture.data().to_vec(), source));
+    }
+
+    let cover = folder_cover(track)?;
+    let data = fs::read(&cover).ok()?;
+    let source = ArtSource::Folder {
+        path: TrackPath::new(&cover),
+    };
+    Some((data, source))
+}
+
+/// Looks for cover art stored as an image file next to the track.
+///
+/// Multi-disc albums often keep the cover one level up from `CD1`, `Disc 2`
+/// and the like, so that folder is searched too.
+fn folder_cover(track: &Path) -> Option<PathBuf> {
+    let dir = track.parent()?;
+    let stem = track.file_stem()?.to_string_lossy().to_lowercase();
+
+    let mut candidates = images_in(dir, &stem, 0);
+    if let Some(parent) = dir.parent().filter(|_| is_disc_folder(dir)) {
+        candidates.extend(images_in(parent, &stem, 1));
+    }
+
+    candidates
+        .into_iter()
+        .filter(|(score, _)| *score > 0)
+        // sorting by path as well keeps the choice stable between runs
+        .max_by(|(a, a_path), (b, b_path)| a.cmp(b).then_with(|| b_path.cmp(a_path)))
+        .map(|(_, path)| path)
+}
+
+fn images_in(dir: &Path, track_stem: &str, penalty: i32) -> Vec<(i32, PathBuf)> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+
+    entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.extension()
+                .and_then(|ext| ext.to_str())
+                .is_some_and(|ext| {
+                    IMAGE_EXTENSIONS
+                        .iter()
+                        .any(|image| image.eq_ignore_ascii_case(ext))
+                })
+                && path.is_file()
+        })
+        .map(|path| {
+            let stem = path
+                .file_stem()
+                .unwrap_or_default()
+                .to_string_lossy()
+                .to_lowercase();
+            (cover_score(&stem, track_stem) - penalty * 20, path)
+        })
+        .collect()
+}
+
+fn cover_score(stem: &str, track_stem: &str) -> i32 {
+    // art made for this one track beats the album cover
+    if stem == track_stem {
+        return 100;
+    }
+    if let Some(rank) = COVER_NAMES.iter().position(|name| *name == stem) {
+        return 90 - rank as i32 * 5;
+    }
+
+    let words: Vec<_> = stem
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect();
+    if words
+        .iter()
+        .any(|word| NON_COVER_WORDS.iter().any(|other| word.starts_with(other)))
+    {
+        return -1;
+    }
+    if words
+        .iter()
+        .any(|word| *word == "cover" || *word == "front" || *word == "folder")
+    {
+        return 50;
+    }
+    // any other image could still be the cover, but only if nothing better is around
+    10
+}
+
+fn is_disc_folder(dir: &Path) -> bool {
+    let name = dir
+        .file_name()
+        .unwrap_or_default()
+        .to_string_lossy()
+        .to_lowercase();
+    ["cd", "disc", "disk"].iter().any(|prefix| {
+        name.strip_prefix(prefix).is_some_and(|rest| {
+            let rest = rest.trim_start_matches([' ', '_', '-', '.']);
+            !rest.is_empty() && rest.chars().all(|c| c.is_ascii_digit())
+        })
+    })
+}
+
+/// Lists every picture embedded in the file, without their image data.
+pub fn get_pictures(path: TrackPath) -> Result<Vec<PictureInfo>, MetadataError> {
+    let file = lofty::read_from_path(path.to_path_buf())?;
+    let tags = all_tags(&file);
+
+    Ok(pictures(&tags)
+        .enumerate()
+        .map(|(index, picture)| {
+            let information = PictureInformation::from_picture(picture).ok();
+            PictureInfo {
+                index,
+                picture_type: PictureType::new(picture.pic_type()),
+                mime_type: match picture.mime_type() {
+                    MimeType::None => None,
+                    mime_type => Some(mime_type.as_str().to_string()),
+                },
+                width: information.as_ref().map(|information| information.width),
+                height: information.as_ref().map(|information| information.height),
+                description: picture.description().map(str::to_string),
+                size: picture.data().len(),
+            }
+        })
+        .collect())
+}
+
+/// Returns the image data of the picture at `index` in `get_pictures`.
+pub fn get_picture_data(path: TrackPath, index: usize) -> Result<Option<Vec<u8>>, MetadataError> {
+    let file = lofty::read_from_path(path.to_path_buf())?;
+    let tags = all_tags(&file);
+
+    let data = pictures(&tags).nth(index).map(|picture| picture.data().to_vec());
+    Ok(data)
+}
+
+fn pictures<'a>(tags: &'a [&'a Tag]) -> impl Iterator<Item = &'a Picture> {
+    tags.iter().copied().flat_map(Tag::pictures)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ranks_cover_names() {
+        assert_eq!(cover_score("01 intro", "01 intro"), 100);
+        assert_eq!(cover_score("cover", "01 intro"), 90);
+        assert!(cover_score("folder", "") > cover_score("front", ""));
+        assert!(cover_score("front", "") > cover_score("album cover", ""));
+        assert_eq!(cover_score("album cover", ""), 50);
+        assert_eq!(cover_score("artwork", ""), 10);
+    }
+
+    #[test]
+    fn rejects_other_artwork() {
+        for stem in [
+            "back",
+            "cover-back",
+            "inlay",
+            "cd1",
+            "disc 2",
+            "tray",
+            "scan01",
+            "artist",
+        ] {
+            assert_eq!(cover_score(stem, "01 intro"), -1, "{stem}");
+        }
+    }
+
+    #[test]
+    fn detects_disc_folders() {
+        for name in ["CD1", "cd 2", "Disc 2", "disk_03", "disc-1", "cd.1"] {
+            assert!(
+                is_disc_folder(&Path::new("album").join(name)),
+                "{name}"
+            );
+        }
+        for name in ["CD", "Disc", "Discography", "cd1a", "Disc One", "Bonus"] {
+            assert!(
+                !is_disc_folder(&Path::new("album").join(name)),
+                "{name}"
+            );
+        }
+    }
+}
@@ -0,0 +1,367 @@
+use crate::api::config::{data_dir, save_json};
+use crate::api::library::FileStamp;
+use crate::api::metadata::{edit_tag, stamp, MetadataChanges, MetadataError, MetadataField};
+use crate::api::path::TrackPath;
+use anyhow::{bail, Context, Result};
+use lofty::{Tag, TaggedFileExt};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const JOURNAL_DIR: &str = "journals";
+
+static LAST_JOURNAL_ID: AtomicU64 = AtomicU64::new(0);
+
+/// The same changes for every file in a batch.
+#[derive(Clone)]
+pub struct BatchEdit {
+    pub changes: MetadataChanges,
+    /// Numbers the tracks in the order they were passed in, overriding `changes.track_number`.
+    pub renumber: Option<Renumber>,
+}
+
+#[derive(Clone, Copy)]
+pub struct Renumber {
+    pub start: u32,
+    /// Also sets the track total to the number of files in the batch.
+    pub set_total: bool,
+}
+
+/// A file as it was previewed, so the edit is refused if it changed since.
+pub struct BatchFile {
+    pub path: TrackPath,
+    pub stamp: FileStamp,
+}
+
+pub struct FieldChange {
+    pub field: MetadataField,
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
+pub struct BatchFileResult {
+    pub path: TrackPath,
+    /// Stamp of the file after the preview or edit, `None` if it could not be read.
+    pub stamp: Option<FileStamp>,
+    /// Only the fields that differ, empty when the file already matches.
+    pub changes: Vec<FieldChange>,
+    pub error: Option<MetadataError>,
+}
+
+pub struct BatchOutcome {
+    /// Pass to `undo_batch_edit` to restore the previous values, `None` if nothing was written.
+    pub journal_id: Option<String>,
+    pub results: Vec<BatchFileResult>,
+}
+
+#[derive(Default, Serialize, Deserialize)]
+struct Journal {
+    entries: Vec<JournalEntry>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct JournalEntry {
+    path: Vec<u8>,
+    /// The file right after the edit, undoing is refused once it changed again.
+    stamp: FileStamp,
+    previous: Vec<(MetadataField, Option<String>)>,
+}
+
+impl BatchEdit {
+    fn changes_for(&self, index: usize, count: usize) -> MetadataChanges {
+        let mut changes = self.changes.clone();
+        if let Some(renumber) = self.renumber {
+            changes.track_number = Some(renumber.start + index as u32);
+            if renumber.set_total {
+                changes.track_total = Some(count as u32);
+            }
+        }
+        changes
+    }
+}
+
+/// Shows what `apply_batch_edit` would change without writing anything.
+pub fn preview_batch_edit(paths: Vec<TrackPath>, edit: BatchEdit) -> Vec<BatchFileResult> {
+    let count = paths.len();
+    paths
+        .into_iter()
+        .enumerate()
+        .map(|(index, path)| {
+            match preview(&path, edit.changes_for(index, count)) {
+                Ok((stamp, changes)) => BatchFileResult {
+                    path,
+                    stamp: Some(stamp),
+                    changes,
+                    error: None,
+                },
+                Err(err) => BatchFileResult {
+                    path,
+                    stamp: None,
+                    changes: Vec::new(),
+                    error: Some(err),
+                },
+            }
+        })
+        .collect()
+}
+
+/// Applies `edit` to every file, carrying on past files that fail.
+///
+/// The previous values of every written file are kept in one journal, so the
+/// whole batch can be rolled back with `undo_batch_edit`.
+pub fn apply_batch_edit(files: Vec<BatchFile>, edit: BatchEdit) -> Result<BatchOutcome> {
+    let (journal_id, journal_path) = new_journal()?;
+    let mut journal = Journal::default();
+
+    let count = files.len();
+    let mut results = Vec::with_capacity(count);
+    for (index, file) in files.into_iter().enumerate() {
+        let changes = edit.changes_for(index, count);
+        let path = file.path.to_path_buf();
+
+        let mut previous = Vec::new();
+        let mut diff = Vec::new();
+        let written = edit_tag(&path, file.stamp, |tag| {
+            let before = tag.clone();
+            changes.apply(tag);
+            diff = field_changes(&before, tag);
+            previous = diff
+                .iter()
+                .map(|change| (change.field, change.before.clone()))
+                .collect();
+        });
+
+        // saved after every file, so a crash halfway still leaves a usable journal
+        if let Ok(stamp) = written {
+            journal.entries.push(JournalEntry {
+                path: file.path.raw.clone(),
+                stamp,
+                previous,
+            });
+            save_json(&journal_path, &journal)?;
+        }
+
+        results.push(match written {
+            Ok(stamp) => BatchFileResult {
+                path: file.path,
+                stamp: Some(stamp),
+                changes: diff,
+                error: None,
+            },
+            Err(err) => BatchFileResult {
+                path: file.path,
+                stamp: None,
+                changes: Vec::new(),
+                error: Some(err),
+            },
+        });
+    }
+
+    Ok(BatchOutcome {
+        journal_id: (!journal.entries.is_empty()).then_some(journal_id),
+        results,
+    })
+}
+
+/// Restores the values a batch edit replaced.
+///
+/// Files edited again since are left alone and stay in the journal, the
+/// journal is deleted once every file was restored.
+pub fn undo_batch_edit(journal_id: String) -> Result<Vec<BatchFileResult>> {
+    let journal_path = journal_path(&journal_id)?;
+    let contents = fs::read_to_string(&journal_path).context("Batch edit journal not found!")?;
+    let journal: Journal =
+        serde_json::from_str(&contents).context("Batch edit journal is malformed!")?;
+
+    let mut remaining = Journal::default();
+    let mut results = Vec::new();
+    for entry in journal.entries.into_iter().rev() {
+        let path = TrackPath::from_raw(entry.path.clone());
+        let changes = MetadataChanges::restore(entry.previous.clone());
+
+        let mut diff = Vec::new();
+        let restored = edit_tag(&path.to_path_buf(), entry.stamp, |tag| {
+            let before = tag.clone();
+            changes.apply(tag);
+            diff = field_changes(&before, tag);
+        });
+
+        results.push(match restored {
+            Ok(stamp) => BatchFileResult {
+                path,
+                stamp: Some(stamp),
+                changes: diff,
+                error: None,
+            },
+            Err(err) => {
+                remaining.entries.push(entry);
+                BatchFileResult {
+                    path,
+                    stamp: None,
+                    changes: Vec::new(),
+                    error: Some(err),
+                }
+            }
+        });
+    }
+
+    if remaining.entries.is_empty() {
+        fs::remove_file(&journal_path)?;
+    } else {
+        remaining.entries.reverse();
+        save_json(&journal_path, &remaining)?;
+    }
+
+    Ok(results)
+}
+
+fn preview(
+    path: &TrackPath,
+    changes: MetadataChanges,
+) -> Result<(FileStamp, Vec<FieldChange>), MetadataError> {
+    let path = path.to_path_buf();
+    let stamp = stamp(&path)?;
+    let file = lofty::read_from_path(&path)?;
+
+    let before = file
+        .primary_tag()
+        .cloned()
+        .unwrap_or_else(|| Tag::new(file.primary_tag_type()));
+    let mut after = before.clone();
+    changes.apply(&mut after);
+
+    Ok((stamp, field_changes(&before, &after)))
+}
+
+fn field_changes(before: &Tag, after: &Tag) -> Vec<FieldChange> {
+    MetadataField::ALL
+        .into_iter()
+        .map(|field| FieldChange {
+            field,
+            before: field.read(before),
+            after: field.read(after),
+        })
+        .filter(|change| change.before != change.after)
+        .collect()
+}
+
+// ids are the time in milliseconds, bumped past the last one handed out so two
+// batches within the same millisecond don't share a journal
+fn new_journal() -> Result<(String, PathBuf)> {
+    let mut id = SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis() as u64;
+    loop {
+        let last = LAST_JOURNAL_ID.fetch_max(id, Ordering::Relaxed);
+        if last >= id {
+            id = last + 1;
+            continue;
+        }
+        // journals left over from a run with a different clock are kept too
+        let path = journal_path(&id.to_string())?;
+        if !path.exists() {
+            return Ok((id.to_string(), path));
+        }
+        id += 1;
+    }
+}
+
+fn journal_path(journal_id: &str) -> Result<PathBuf> {
+    // ids come from the Dart side, don't let them point anywhere else
+    if journal_id.is_empty() || !journal_id.bytes().all(|byte| byte.is_ascii_digit()) {
+        bail!("Invalid batch edit journal id!");
+    }
+    Ok(data_dir()?
+        .join(JOURNAL_DIR)
+        .join(format!("{journal_id}.json")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lofty::{Accessor, TagType};
+
+    fn renumbered(renumber: Option<Renumber>) -> Vec<(Option<u32>, Option<u32>)> {
+        let edit = BatchEdit {
+            changes: MetadataChanges {
+                track_number: Some(9),
+                ..Default::default()
+            },
+            renumber,
+        };
+        (0..3)
+            .map(|index| edit.changes_for(index, 3))
+            .map(|changes| (changes.track_number, changes.track_total))
+            .collect()
+    }
+
+    #[test]
+    fn renumbers_tracks_in_order() {
+        assert_eq!(renumbered(None), [(Some(9), None); 3]);
+        let renumber = Renumber {
+            start: 1,
+            set_total: false,
+        };
+        assert_eq!(
+            renumbered(Some(renumber)),
+            [(Some(1), None), (Some(2), None), (Some(3), None)]
+        );
+        let renumber = Renumber {
+            start: 5,
+            set_total: true,
+        };
+        assert_eq!(
+            renumbered(Some(renumber)),
+            [(Some(5), Some(3)), (Some(6), Some(3)), (Some(7), Some(3))]
+        );
+    }
+
+    #[test]
+    fn lists_only_changed_fields() {
+        let mut before = Tag::new(TagType::Id3v2);
+        before.set_title("Title".to_string());
+        before.set_artist("Artist".to_string());
+        before.set_album("Album".to_string());
+
+        let mut after = before.clone();
+        MetadataChanges {
+            title: Some("Title".to_string()),
+            artist: Some("Other".to_string()),
+            year: Some(2001),
+            clear: vec![MetadataField::Album],
+            ..Default::default()
+        }
+        .apply(&mut after);
+
+        let changes = field_changes(&before, &after);
+        let fields: Vec<_> = changes.iter().map(|change| change.field).collect();
+        assert!(
+            fields
+                == [
+                    MetadataField::Artist,
+                    MetadataField::Album,
+                    MetadataField::Year
+                ]
+        );
+        let values: Vec<_> = changes
+            .iter()
+            .map(|change| (change.before.as_deref(), change.after.as_deref()))
+            .collect();
+        assert_eq!(
+            values,
+            [
+                (Some("Artist"), Some("Other")),
+                (Some("Album"), None),
+                (None, Some("2001")),
+            ]
+        );
+        assert!(field_changes(&before, &before).is_empty());
+    }
+
+    #[test]
+    fn hands_out_distinct_journal_ids() {
+        let (first, _) = new_journal().unwrap();
+        let (second, _) = new_journal().unwrap();
+        assert_ne!(first, second);
+    }
+}
@@ -0,0 +1,193 @@
+use crate::api::ignore_rules::build_global;
+use crate::api::path::TrackPath;
+use anyhow::{bail, Context, Result};
+use directories::{ProjectDirs, UserDirs};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const CONFIG_FILE: &str = "config.json";
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct LibraryRoot {
+    #[serde(with = "stored_path")]
+    pub path: TrackPath,
+    pub enabled: bool,
+}
+
+#[derive(Default, Serialize, Deserialize)]
+pub(crate) struct Config {
+    pub(crate) roots: Vec<LibraryRoot>,
+    /// gitignore-style patterns applied to every library folder.
+    #[serde(default)]
+    pub(crate) ignore_patterns: Vec<String>,
+}
+
+impl Config {
+    pub(crate) fn load() -> Result<Self> {
+        let path = config_dir()?.join(CONFIG_FILE);
+        if !path.exists() {
+            return Ok(Self::with_default_roots());
+        }
+
+        let contents = fs::read_to_string(&path)
+            .with_context(|| format!("Could not read config file {}", path.display()))?;
+        serde_json::from_str(&contents).context("Config file is malformed!")
+    }
+
+    pub(crate) fn save(&self) -> Result<()> {
+        save_json(&config_dir()?.join(CONFIG_FILE), self)
+    }
+
+    pub(crate) fn enabled_roots(&self) -> impl Iterator<Item = PathBuf> + '_ {
+        self.roots
+            .iter()
+            .filter(|root| root.enabled)
+            .map(|root| root.path.to_path_buf())
+    }
+
+    // first launch: start out with the platform music folder, if there is one
+    fn with_default_roots() -> Self {
+        let roots = UserDirs::new()
+            .and_then(|dirs| {
+                dirs.audio_dir().map(|dir| LibraryRoot {
+                    path: TrackPath::new(dir),
+                    enabled: true,
+                })
+            })
+            .into_iter()
+            .collect();
+
+        Self {
+            roots,
+            ignore_patterns: Vec::new(),
+        }
+    }
+
+    fn root_mut(&mut self, path: &TrackPath) -> Result<&mut LibraryRoot> {
+        self.roots
+            .iter_mut()
+            .find(|root| root.path == *path)
+            .with_context(|| format!("{} is not a library folder!", path.display))
+    }
+}
+
+// paths that are valid UTF-8 are stored as plain strings, like config files
+// from before raw paths, anything else as its raw bytes
+mod stored_path {
+    use crate::api::path::TrackPath;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(untagged)]
+    enum StoredPath {
+        Text(String),
+        Raw(Vec<u8>),
+    }
+
+    pub(super) fn serialize<S: Serializer>(
+        path: &TrackPath,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match path.to_path_buf().into_os_string().into_string() {
+            Ok(text) => StoredPath::Text(text),
+            Err(_) => StoredPath::Raw(path.raw.clone()),
+        }
+        .serialize(serializer)
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<TrackPath, D::Error> {
+        Ok(match StoredPath::deserialize(deserializer)? {
+            StoredPath::Text(text) => TrackPath::new(text.as_ref()),
+            StoredPath::Raw(raw) => TrackPath::from_raw(raw),
+        })
+    }
+}
+
+pub(crate) fn project_dirs() -> Result<ProjectDirs> {
+    ProjectDirs::from("", "", "amai_music_player").context("Could not get project directories!")
+}
+
+pub(crate) fn data_dir() -> Result<PathBuf> {
+    Ok(project_dirs()?.data_dir().to_path_buf())
+}
+
+pub(crate) fn save_json<T: Serialize>(path: &Path, value: &T) -> Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    // write next to the real file first so a crash never leaves half a file behind
+    let tmp = path.with_extension("tmp");
+    fs::write(&tmp, serde_json::to_vec_pretty(value)?)?;
+    fs::rename(tmp, path)?;
+    Ok(())
+}
+
+fn config_dir() -> Result<PathBuf> {
+    Ok(project_dirs()?.config_dir().to_path_buf())
+}
+
+pub fn list_library_roots() -> Result<Vec<LibraryRoot>> {
+    Ok(Config::load()?.roots)
+}
+
+pub fn add_library_root(path: TrackPath) -> Result<LibraryRoot> {
+    let dir = fs::canonicalize(path.to_path_buf())
+        .with_context(|| format!("Could not open {}", path.display))?;
+    if !dir.is_dir() {
+        bail!("{} is not a directory!", path.display);
+    }
+    let path = TrackPath::new(&dir);
+
+    let mut config = Config::load()?;
+    if config.roots.iter().any(|root| root.path == path) {
+        bail!("{} is already a library folder!", path.display);
+    }
+
+    let root = LibraryRoot {
+        path,
+        enabled: true,
+    };
+    config.roots.push(root.clone());
+    config.save()?;
+
+    Ok(root)
+}
+
+pub fn remove_library_root(path: TrackPath) -> Result<()> {
+    let mut config = Config::load()?;
+    let count = config.roots.len();
+    config.roots.retain(|root| root.path != path);
+    if config.roots.len() == count {
+        bail!("{} is not a library folder!", path.display);
+    }
+
+    config.save()
+}
+
+pub fn set_library_root_enabled(path: TrackPath, enabled: bool) -> Result<LibraryRoot> {
+    let mut config = Config::load()?;
+    let root = config.root_mut(&path)?;
+    root.enabled = enabled;
+    let root = root.clone();
+    config.save()?;
+
+    Ok(root)
+}
+
+pub fn get_ignore_patterns() -> Result<Vec<String>> {
+    Ok(Config::load()?.ignore_patterns)
+}
+
+/// Replaces the global ignore patterns, which use the same syntax as `.amaiignore` files.
+pub fn set_ignore_patterns(patterns: Vec<String>) -> Result<()> {
+    // reject typos up front instead of silently ignoring nothing
+    build_global(Path::new(""), &patterns).context("Invalid ignore pattern!")?;
+
+    let mut config = Config::load()?;
+    config.ignore_patterns = patterns;
+    config.save()
+}
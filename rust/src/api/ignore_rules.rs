@@ -0,0 +1,166 @@
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use ignore::Match;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+pub(crate) const IGNORE_FILE: &str = ".amaiignore";
+
+/// gitignore-style rules for one library folder.
+///
+/// Like git, the `.amaiignore` closest to a file wins, and the global patterns
+/// from the config only apply when no `.amaiignore` has a say.
+pub(crate) struct IgnoreRules {
+    root: PathBuf,
+    global: Gitignore,
+    // `None` for folders without an `.amaiignore`, so each folder is only checked once
+    folders: HashMap<PathBuf, Option<Gitignore>>,
+}
+
+impl IgnoreRules {
+    pub(crate) fn new(root: &Path, patterns: &[String]) -> Self {
+        Self {
+            root: root.to_path_buf(),
+            global: build_global(root, patterns).unwrap_or_else(|_| Gitignore::empty()),
+            folders: HashMap::new(),
+        }
+    }
+
+    pub(crate) fn is_ignored(&mut self, path: &Path, is_dir: bool) -> bool {
+        if !path.starts_with(&self.root) || path == self.root {
+            return false;
+        }
+
+        for dir in path.ancestors().skip(1) {
+            if let Some(rules) = self.folder(dir) {
+                match rules.matched_path_or_any_parents(path, is_dir) {
+                    Match::Ignore(_) => return true,
+                    Match::Whitelist(_) => return false,
+                    Match::None => {}
+                }
+            }
+            if dir == self.root {
+                break;
+            }
+        }
+
+        self.global
+            .matched_path_or_any_parents(path, is_dir)
+            .is_ignore()
+    }
+
+    fn folder(&mut self, dir: &Path) -> Option<&Gitignore> {
+        self.folders
+            .entry(dir.to_path_buf())
+            .or_insert_with(|| {
+                let file = dir.join(IGNORE_FILE);
+                if !file.is_file() {
+                    return None;
+                }
+
+                // a broken line shouldn't throw away the rest of the file
+                let mut builder = GitignoreBuilder::new(dir);
+                builder.add(file);
+                builder.build().ok()
+            })
+            .as_ref()
+    }
+}
+
+pub(crate) fn build_global(root: &Path, patterns: &[String]) -> Result<Gitignore, ignore::Error> {
+    let mut builder = GitignoreBuilder::new(root);
+    for pattern in patterns {
+        builder.add_line(None, pattern)?;
+    }
+    builder.build()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    // a throwaway library folder, removed again when the test ends
+    struct Library(PathBuf);
+
+    impl Library {
+        fn new(name: &str) -> Self {
+            let root =
+                std::env::temp_dir().join(format!("amai-ignore-{}-{name}", std::process::id()));
+            let _ = fs::remove_dir_all(&root);
+            fs::create_dir_all(&root).unwrap();
+            Self(root)
+        }
+
+        fn ignore_file(&self, dir: &str, contents: &str) -> &Self {
+            let dir = self.0.join(dir);
+            fs::create_dir_all(&dir).unwrap();
+            fs::write(dir.join(IGNORE_FILE), contents).unwrap();
+            self
+        }
+
+        fn rules(&self, patterns: &[&str]) -> IgnoreRules {
+            let patterns: Vec<_> = patterns.iter().map(|pattern| pattern.to_string()).collect();
+            IgnoreRules::new(&self.0, &patterns)
+        }
+
+        fn ignored(&self, rules: &mut IgnoreRules, path: &str) -> bool {
+            rules.is_ignored(&self.0.join(path), false)
+        }
+    }
+
+    impl Drop for Library {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn nearest_ignore_file_wins() {
+        let library = Library::new("nearest");
+        library
+            .ignore_file("", "*.wav\n")
+            .ignore_file("a", "!*.wav\n")
+            .ignore_file("a/b", "*.wav\n");
+        let mut rules = library.rules(&[]);
+
+        assert!(library.ignored(&mut rules, "song.wav"));
+        assert!(!library.ignored(&mut rules, "a/song.wav"));
+        assert!(library.ignored(&mut rules, "a/b/song.wav"));
+        assert!(!library.ignored(&mut rules, "song.flac"));
+    }
+
+    #[test]
+    fn falls_through_files_without_a_say() {
+        let library = Library::new("fall-through");
+        library
+            .ignore_file("", "*.wav\n")
+            .ignore_file("a", "!keep.wav\n");
+        let mut rules = library.rules(&[]);
+
+        assert!(!library.ignored(&mut rules, "a/keep.wav"));
+        assert!(library.ignored(&mut rules, "a/other.wav"));
+    }
+
+    #[test]
+    fn global_patterns_apply_last() {
+        let library = Library::new("global");
+        library.ignore_file("a", "!*.flac\n");
+        let mut rules = library.rules(&["*.flac", "live/"]);
+
+        assert!(library.ignored(&mut rules, "song.flac"));
+        assert!(!library.ignored(&mut rules, "a/song.flac"));
+        assert!(!library.ignored(&mut rules, "song.mp3"));
+        assert!(rules.is_ignored(&library.0.join("live"), true));
+        assert!(library.ignored(&mut rules, "live/song.mp3"));
+    }
+
+    #[test]
+    fn never_ignores_the_root_or_outside_it() {
+        let library = Library::new("outside");
+        let mut rules = library.rules(&["*"]);
+
+        assert!(!rules.is_ignored(&library.0, true));
+        assert!(!rules.is_ignored(Path::new("/elsewhere/song.mp3"), false));
+        assert!(library.ignored(&mut rules, "song.mp3"));
+    }
+}
@@ -0,0 +1,559 @@
+use crate::api::config::data_dir;
+use crate::api::path::{to_raw, TrackPath};
+use crate::api::scan::{scan_lock, ScanContext, ScanPhase, SkipReason};
+use crate::api::track_ids;
+use crate::api::utils::{scan_music_files, AudioFormat, Track};
+use anyhow::{Context, Result};
+use lofty::{Accessor, AudioFile, ItemKey, TaggedFileExt};
+use rayon::prelude::*;
+use rusqlite::{params, Connection, OptionalExtension, Row, Transaction};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf, MAIN_SEPARATOR_STR};
+use std::time::UNIX_EPOCH;
+
+const DATABASE_FILE: &str = "library.sqlite";
+
+// each entry moves the schema up by one `user_version`, never edit one that has shipped
+pub(crate) const MIGRATIONS: &[&str] = &["
+    CREATE TABLE tracks (
+        id INTEGER PRIMARY KEY,
+        path TEXT NOT NULL UNIQUE,
+        name TEXT NOT NULL,
+        format TEXT NOT NULL,
+        size INTEGER NOT NULL,
+        mtime INTEGER NOT NULL,
+        title TEXT,
+        artist TEXT,
+        album TEXT,
+        album_artist TEXT,
+        genre TEXT,
+        year INTEGER,
+        track_number INTEGER,
+        disc_number INTEGER
+    );
+    CREATE INDEX tracks_album ON tracks (album);
+    CREATE INDEX tracks_artist ON tracks (artist);
+", "
+    CREATE TABLE tracks_raw_path (
+        id INTEGER PRIMARY KEY,
+        path BLOB NOT NULL UNIQUE,
+        name TEXT NOT NULL,
+        format TEXT NOT NULL,
+        size INTEGER NOT NULL,
+        mtime INTEGER NOT NULL,
+        title TEXT,
+        artist TEXT,
+        album TEXT,
+        album_artist TEXT,
+        genre TEXT,
+        year INTEGER,
+        track_number INTEGER,
+        disc_number INTEGER
+    );
+    INSERT INTO tracks_raw_path
+        SELECT id, CAST(path AS BLOB), name, format, size, mtime,
+            title, artist, album, album_artist, genre, year, track_number, disc_number
+        FROM tracks;
+    DROP TABLE tracks;
+    ALTER TABLE tracks_raw_path RENAME TO tracks;
+    CREATE INDEX tracks_album ON tracks (album);
+    CREATE INDEX tracks_artist ON tracks (artist);
+
+    CREATE TABLE track_ids (
+        id INTEGER PRIMARY KEY,
+        path BLOB NOT NULL UNIQUE,
+        dev INTEGER,
+        ino INTEGER
+    );
+    CREATE TABLE meta (
+        key TEXT PRIMARY KEY,
+        value INTEGER NOT NULL
+    );
+", "
+    ALTER TABLE tracks ADD COLUMN duration_ms INTEGER;
+    -- forces the next scan to read every file again, which fills in the durations
+    UPDATE tracks SET mtime = 0;
+", "
+    CREATE TABLE loudness (
+        path BLOB PRIMARY KEY,
+        size INTEGER NOT NULL,
+        mtime INTEGER NOT NULL,
+        integrated_lufs REAL NOT NULL,
+        loudness_range REAL NOT NULL,
+        true_peak REAL NOT NULL,
+        album_lufs REAL,
+        album_true_peak REAL
+    );
+"];
+
+// the migration that moved track ids out of `track_ids.json`
+const TRACK_IDS_MIGRATION: usize = 1;
+
+const TRACK_COLUMNS: &str = "id, name, path, format, title, artist, album, album_artist, genre, year, track_number, disc_number, duration_ms";
+
+pub struct LibraryTrack {
+    pub track: Track,
+    pub title: Option<String>,
+    pub artist: Option<String>,
+    pub album: Option<String>,
+    pub album_artist: Option<String>,
+    pub genre: Option<String>,
+    pub year: Option<u32>,
+    pub track_number: Option<u32>,
+    pub disc_number: Option<u32>,
+    pub duration_ms: Option<u64>,
+}
+
+pub struct TrackMove {
+    pub from: TrackPath,
+    pub track: LibraryTrack,
+}
+
+/// What changed in the library since the previous scan.
+pub struct LibraryDiff {
+    pub added: Vec<LibraryTrack>,
+    /// Ids of tracks that are gone.
+    pub removed: Vec<u64>,
+    pub modified: Vec<LibraryTrack>,
+    pub moved: Vec<TrackMove>,
+}
+
+pub struct Album {
+    pub name: String,
+    pub artist: Option<String>,
+    pub track_count: usize,
+    /// Sum of the durations of its tracks.
+    pub duration_ms: u64,
+}
+
+/// Size and modification time of a file, used to tell whether it changed since it was read.
+#[derive(Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileStamp {
+    pub size: i64,
+    /// Milliseconds since the unix epoch.
+    pub mtime: i64,
+}
+
+impl FileStamp {
+    pub(crate) fn of(path: &Path) -> Result<Self> {
+        let metadata = fs::metadata(path)?;
+        let mtime = metadata.modified()?.duration_since(UNIX_EPOCH)?.as_millis();
+
+        Ok(Self {
+            size: metadata.len() as i64,
+            mtime: mtime as i64,
+        })
+    }
+}
+
+impl LibraryTrack {
+    fn new(track: Track, tags: TrackTags) -> Self {
+        Self {
+            track,
+            title: tags.title,
+            artist: tags.artist,
+            album: tags.album,
+            album_artist: tags.album_artist,
+            genre: tags.genre,
+            year: tags.year,
+            track_number: tags.track_number,
+            disc_number: tags.disc_number,
+            duration_ms: tags.duration_ms,
+        }
+    }
+}
+
+#[derive(Default)]
+struct TrackTags {
+    title: Option<String>,
+    artist: Option<String>,
+    album: Option<String>,
+    album_artist: Option<String>,
+    genre: Option<String>,
+    year: Option<u32>,
+    track_number: Option<u32>,
+    disc_number: Option<u32>,
+    duration_ms: Option<u64>,
+}
+
+impl TrackTags {
+    // a file without tags is still a track, it just shows up by its file name
+    fn read(path: &Path) -> Result<Self, SkipReason> {
+        let file = lofty::read_from_path(path).map_err(|err| SkipReason::from_lofty(&err))?;
+        let duration_ms = Some(file.properties().duration().as_millis() as u64);
+        let Some(tag) = file.primary_tag().or_else(|| file.first_tag()) else {
+            return Ok(Self {
+                duration_ms,
+                ..Self::default()
+            });
+        };
+
+        Ok(Self {
+            title: tag.title().map(|title| title.to_string()),
+            artist: tag.artist().map(|artist| artist.to_string()),
+            album: tag.album().map(|album| album.to_string()),
+            album_artist: tag.get_string(&ItemKey::AlbumArtist).map(str::to_string),
+            genre: tag.genre().map(|genre| genre.to_string()),
+            year: tag.year(),
+            track_number: tag.track(),
+            disc_number: tag.disk(),
+            duration_ms,
+        })
+    }
+}
+
+pub(crate) fn open() -> Result<Connection> {
+    let dir = data_dir()?;
+    fs::create_dir_all(&dir)?;
+
+    let mut conn = Connection::open(dir.join(DATABASE_FILE))
+        .context("Could not open the library database!")?;
+    conn.pragma_update_and_check(None, "journal_mode", "WAL", |_| Ok(()))?;
+    migrate(&mut conn)?;
+
+    Ok(conn)
+}
+
+fn migrate(conn: &mut Connection) -> Result<()> {
+    let version: i64 = conn.pragma_query_value(None, "user_version", |row| row.get(0))?;
+
+    let tx = conn.transaction()?;
+    for (version, migration) in MIGRATIONS.iter().enumerate().skip(version as usize) {
+        tx.execute_batch(migration)?;
+        if version == TRACK_IDS_MIGRATION {
+            track_ids::import_legacy(&tx)?;
+        }
+        tx.pragma_update(None, "user_version", version as i64 + 1)?;
+    }
+    tx.commit()?;
+
+    if version as usize <= TRACK_IDS_MIGRATION {
+        track_ids::remove_legacy()?;
+    }
+    Ok(())
+}
+
+// raw path and stamp of every stored track by id
+type StoredTracks = HashMap<usize, (Vec<u8>, FileStamp)>;
+
+fn stored_track(row: &Row) -> rusqlite::Result<(usize, (Vec<u8>, FileStamp))> {
+    let id: i64 = row.get(0)?;
+    let stamp = FileStamp {
+        size: row.get(2)?,
+        mtime: row.get(3)?,
+    };
+    Ok((id as usize, (row.get(1)?, stamp)))
+}
+
+/// Brings the database in line with a fresh scan.
+///
+/// Tags are only read again for files whose size or modification time changed
+/// since they were stored, and tracks that were not found anymore are dropped.
+pub(crate) fn sync(tracks: Vec<Track>, context: &ScanContext) -> Result<LibraryDiff> {
+    let mut conn = open()?;
+
+    let stored: StoredTracks = conn
+        .prepare("SELECT id, path, size, mtime FROM tracks")?
+        .query_map([], stored_track)?
+        .collect::<rusqlite::Result<_>>()?;
+
+    let scanned: HashSet<_> = tracks.iter().filter_map(|track| track.id).collect();
+    let removed: Vec<_> = stored
+        .keys()
+        .filter(|id| !scanned.contains(id))
+        .copied()
+        .collect();
+
+    // read before the transaction starts, so the database isn't locked for the slow part
+    let changes = read_changes(tracks, &stored, context)?;
+
+    let tx = conn.transaction()?;
+    for id in &removed {
+        tx.execute("DELETE FROM tracks WHERE id = ?1", [*id as i64])?;
+    }
+    let mut diff = write_changes(&tx, changes, &stored, context)?;
+    diff.removed.extend(removed.into_iter().map(|id| id as u64));
+    tx.commit()?;
+
+    Ok(diff)
+}
+
+/// Applies a partial scan, e.g. from the file watcher.
+///
+/// `gone` holds files or folders that no longer exist; tracks still stored
+/// under them afterwards are dropped, the rest have moved somewhere in `tracks`.
+pub(crate) fn sync_paths(
+    tracks: Vec<Track>,
+    gone: &[PathBuf],
+    context: &ScanContext,
+) -> Result<LibraryDiff> {
+    let mut conn = open()?;
+
+    let stored: StoredTracks = {
+        let mut query = conn.prepare("SELECT id, path, size, mtime FROM tracks WHERE id = ?1")?;
+        let mut stored = HashMap::new();
+        for id in tracks.iter().filter_map(|track| track.id) {
+            if let Some((id, entry)) = query.query_row([id as i64], stored_track).optional()? {
+                stored.insert(id, entry);
+            }
+        }
+        stored
+    };
+
+    let changes = read_changes(tracks, &stored, context)?;
+
+    let tx = conn.transaction()?;
+    let mut diff = write_changes(&tx, changes, &stored, context)?;
+    {
+        let mut query = tx.prepare("SELECT id FROM tracks WHERE path = ?1 OR instr(path, ?2) = 1")?;
+        for path in gone {
+            let mut children = path.clone().into_os_string();
+            children.push(MAIN_SEPARATOR_STR);
+            let (path, children) = (to_raw(path.clone().into_os_string()), to_raw(children));
+            let ids: Vec<i64> = query
+                .query_map(params![path, children], |row| row.get(0))?
+                .collect::<rusqlite::Result<_>>()?;
+            for id in ids {
+                tx.execute("DELETE FROM tracks WHERE id = ?1", [id])?;
+                diff.removed.push(id as u64);
+            }
+        }
+    }
+    tx.commit()?;
+
+    Ok(diff)
+}
+
+struct Change {
+    track: Track,
+    stamp: FileStamp,
+    /// `None` when the file could not be parsed, it gets dropped from the library.
+    tags: Option<TrackTags>,
+}
+
+fn read_changes(
+    tracks: Vec<Track>,
+    stored: &StoredTracks,
+    context: &ScanContext,
+) -> Result<Vec<Change>> {
+    let pending: Vec<_> = tracks
+        .into_iter()
+        .filter_map(|track| {
+            let stamp = FileStamp::of(&track.path.to_path_buf()).ok()?;
+            let unchanged = stored
+                .get(&track.id?)
+                .is_some_and(|(path, stored)| *path == track.path.raw && *stored == stamp);
+            (!unchanged).then_some((track, stamp))
+        })
+        .collect();
+
+    context.phase(ScanPhase::ReadingTags, pending.len());
+    let changes = pending
+        .into_par_iter()
+        .filter_map(|(track, stamp)| {
+            if context.is_cancelled() {
+                return None;
+            }
+            let path = track.path.to_path_buf();
+            let tags = match TrackTags::read(&path) {
+                Ok(tags) => Some(tags),
+                Err(reason) => {
+                    context.skip(&path, reason);
+                    None
+                }
+            };
+            context.done(&path);
+            Some(Change { track, stamp, tags })
+        })
+        .collect();
+    context.check()?;
+
+    Ok(changes)
+}
+
+fn write_changes(
+    tx: &Transaction,
+    changes: Vec<Change>,
+    stored: &StoredTracks,
+    context: &ScanContext,
+) -> Result<LibraryDiff> {
+    let mut diff = LibraryDiff {
+        added: Vec::new(),
+        removed: Vec::new(),
+        modified: Vec::new(),
+        moved: Vec::new(),
+    };
+
+    context.phase(ScanPhase::Saving, changes.len());
+    // REPLACE also evicts a row still holding this path, e.g. after two files swapped names
+    let mut upsert = tx.prepare(
+        "INSERT OR REPLACE INTO tracks (
+            id, path, name, format, size, mtime,
+            title, artist, album, album_artist, genre, year, track_number, disc_number,
+            duration_ms
+        ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15)",
+    )?;
+
+    for Change { track, stamp, tags } in changes {
+        let Some(id) = track.id else {
+            continue;
+        };
+        let Some(tags) = tags else {
+            if stored.contains_key(&id) {
+                tx.execute("DELETE FROM tracks WHERE id = ?1", [id as i64])?;
+                diff.removed.push(id as u64);
+            }
+            continue;
+        };
+
+        upsert.execute(params![
+            id as i64,
+            track.path.raw,
+            track.name,
+            track.format.name(),
+            stamp.size,
+            stamp.mtime,
+            tags.title,
+            tags.artist,
+            tags.album,
+            tags.album_artist,
+            tags.genre,
+            tags.year,
+            tags.track_number,
+            tags.disc_number,
+            tags.duration_ms.map(|duration| duration as i64),
+        ])?;
+        context.done(&track.path.to_path_buf());
+
+        let entry = LibraryTrack::new(track, tags);
+        match stored.get(&id) {
+            None => diff.added.push(entry),
+            Some((path, _)) if *path != entry.track.path.raw => diff.moved.push(TrackMove {
+                from: TrackPath::from_raw(path.clone()),
+                track: entry,
+            }),
+            Some(_) => diff.modified.push(entry),
+        }
+    }
+
+    Ok(diff)
+}
+
+fn escape_like(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
+
+/// Rescans the library folders and reports what changed, so callers can update in place.
+pub fn rescan_library(max_depth: Option<usize>) -> Result<LibraryDiff> {
+    let _lock = scan_lock();
+    let context = ScanContext::silent();
+    sync(scan_music_files(max_depth, &context)?, &context)
+}
+
+fn library_track(row: &Row) -> rusqlite::Result<LibraryTrack> {
+    let format: String = row.get("format")?;
+    let id: i64 = row.get("id")?;
+
+    Ok(LibraryTrack {
+        track: Track {
+            name: row.get("name")?,
+            path: TrackPath::from_raw(row.get("path")?),
+            id: Some(id as usize),
+            // only ever written from `AudioFormat::name`
+            format: AudioFormat::from_name(&format).unwrap_or(AudioFormat::Mpeg),
+        },
+        title: row.get("title")?,
+        artist: row.get("artist")?,
+        album: row.get("album")?,
+        album_artist: row.get("album_artist")?,
+        genre: row.get("genre")?,
+        year: row.get("year")?,
+        track_number: row.get("track_number")?,
+        disc_number: row.get("disc_number")?,
+        duration_ms: row
+            .get::<_, Option<i64>>("duration_ms")?
+            .map(|duration| duration as u64),
+    })
+}
+
+/// Returns the library as of the last scan without touching the disk, so it is cheap to call at startup.
+pub fn get_library_tracks() -> Result<Vec<LibraryTrack>> {
+    let conn = open()?;
+    let mut query = conn.prepare(&format!("SELECT {TRACK_COLUMNS} FROM tracks ORDER BY path"))?;
+    let tracks = query.query_map([], library_track)?.collect::<rusqlite::Result<_>>()?;
+
+    Ok(tracks)
+}
+
+pub fn get_library_track(id: usize) -> Result<Option<LibraryTrack>> {
+    let conn = open()?;
+    let track = conn
+        .query_row(
+            &format!("SELECT {TRACK_COLUMNS} FROM tracks WHERE id = ?1"),
+            [id as i64],
+            library_track,
+        )
+        .optional()?;
+
+    Ok(track)
+}
+
+pub fn search_library(query: String) -> Result<Vec<LibraryTrack>> {
+    let pattern = format!("%{}%", escape_like(&query));
+
+    let conn = open()?;
+    let mut query = conn.prepare(&format!(
+        "SELECT {TRACK_COLUMNS} FROM tracks
+        WHERE name LIKE ?1 ESCAPE '\\'
+            OR title LIKE ?1 ESCAPE '\\'
+            OR artist LIKE ?1 ESCAPE '\\'
+            OR album LIKE ?1 ESCAPE '\\'
+        ORDER BY path"
+    ))?;
+    let tracks = query
+        .query_map([pattern], library_track)?
+        .collect::<rusqlite::Result<_>>()?;
+
+    Ok(tracks)
+}
+
+pub fn get_albums() -> Result<Vec<Album>> {
+    let conn = open()?;
+    let mut query = conn.prepare(
+        "SELECT album, COALESCE(album_artist, artist), COUNT(*), TOTAL(duration_ms) FROM tracks
+        WHERE album IS NOT NULL
+        GROUP BY album, COALESCE(album_artist, artist)
+        ORDER BY album COLLATE NOCASE",
+    )?;
+    let albums = query
+        .query_map([], |row| {
+            Ok(Album {
+                name: row.get(0)?,
+                artist: row.get(1)?,
+                track_count: row.get::<_, i64>(2)? as usize,
+                duration_ms: row.get::<_, f64>(3)? as u64,
+            })
+        })?
+        .collect::<rusqlite::Result<_>>()?;
+
+    Ok(albums)
+}
+
+pub fn get_album_tracks(album: String, artist: Option<String>) -> Result<Vec<LibraryTrack>> {
+    let conn = open()?;
+    let mut query = conn.prepare(&format!(
+        "SELECT {TRACK_COLUMNS} FROM tracks
+        WHERE album = ?1 AND COALESCE(album_artist, artist) IS ?2
+        ORDER BY disc_number, track_number, path"
+    ))?;
+    let tracks = query
+        .query_map(params![album, artist], library_track)?
+        .collect::<rusqlite::Result<_>>()?;
+
+    Ok(tracks)
+}
@@ -0,0 +1,578 @@
+use crate::api::library::{self, FileStamp};
+use crate::api::metadata::{edit_tag, stamp, ReplayGain};
+use crate::api::path::TrackPath;
+use anyhow::{bail, Context, Result};
+use ebur128::{EbuR128, Mode};
+use flutter_rust_bridge::frb;
+use lofty::{FileType, ItemKey, ItemValue, Probe, Tag, TagItem};
+use rayon::prelude::*;
+use rusqlite::{params, OptionalExtension};
+use std::ffi::OsStr;
+use std::fs::File;
+use std::io;
+use std::path::Path;
+use symphonia::core::audio::SampleBuffer;
+use symphonia::core::codecs::DecoderOptions;
+use symphonia::core::errors::Error as DecodeError;
+use symphonia::core::formats::FormatOptions;
+use symphonia::core::io::MediaSourceStream;
+use symphonia::core::meta::MetadataOptions;
+use symphonia::core::probe::Hint;
+
+// ReplayGain 2.0 aims for -18 LUFS, Opus' R128 gains for the EBU's -23 LUFS
+const REPLAYGAIN_REFERENCE_LUFS: f64 = -18.0;
+const R128_REFERENCE_LUFS: f64 = -23.0;
+
+pub struct TrackLoudness {
+    pub path: TrackPath,
+    pub integrated_lufs: f64,
+    pub loudness_range_lu: f64,
+    /// Linear, 1.0 is full scale.
+    pub true_peak: f64,
+    /// ReplayGain 2.0 gain that brings the track to -18 LUFS.
+    pub track_gain_db: f64,
+    pub album_gain_db: Option<f64>,
+    pub album_true_peak: Option<f64>,
+    /// Set when the analysis worked, but writing the tags did not.
+    pub tag_error: Option<String>,
+}
+
+pub struct AlbumLoudness {
+    pub integrated_lufs: f64,
+    pub loudness_range_lu: f64,
+    pub true_peak: f64,
+    pub gain_db: f64,
+}
+
+pub struct LoudnessFailure {
+    pub path: TrackPath,
+    pub error: String,
+}
+
+pub struct LoudnessReport {
+    pub tracks: Vec<TrackLoudness>,
+    /// Only for analyses run with `album` set.
+    pub album: Option<AlbumLoudness>,
+    pub failed: Vec<LoudnessFailure>,
+}
+
+#[derive(Clone, Copy)]
+pub enum ReplayGainMode {
+    Off,
+    Track,
+    Album,
+    /// Album gain while an album plays in order, track gain otherwise, e.g. on shuffle.
+    Smart { album_in_order: bool },
+}
+
+struct Analysis {
+    path: TrackPath,
+    stamp: FileStamp,
+    meter: EbuR128,
+}
+
+/// Measures EBU R128 loudness of every track on all cores and stores the
+/// results in the library.
+///
+/// With `album` set the tracks are treated as one album and also get album
+/// values. With `write_tags` set the results are written as `REPLAYGAIN_*`
+/// tags, or as `R128_*` gains for Opus files.
+pub fn analyze_loudness(
+    paths: Vec<TrackPath>,
+    album: bool,
+    write_tags: bool,
+) -> Result<LoudnessReport> {
+    let results: Vec<_> = paths
+        .into_par_iter()
+        .map(|path| (analyze(&path), path))
+        .collect();
+    let mut analyses = Vec::new();
+    let mut failed = Vec::new();
+    for (result, path) in results {
+        match result {
+            Ok(analysis) => analyses.push(analysis),
+            Err(err) => failed.push(LoudnessFailure {
+                path,
+                error: err.to_string(),
+            }),
+        }
+    }
+
+    let album = if album && !analyses.is_empty() {
+        let meters = analyses.iter().map(|analysis| &analysis.meter);
+        let integrated_lufs = EbuR128::loudness_global_multiple(meters.clone())?;
+        Some(AlbumLoudness {
+            integrated_lufs,
+            loudness_range_lu: EbuR128::loudness_range_multiple(meters)?,
+            true_peak: analyses
+                .iter()
+                .map(|analysis| true_peak(&analysis.meter))
+                .collect::<Result<Vec<_>>>()?
+                .into_iter()
+                .fold(0.0, f64::max),
+            gain_db: REPLAYGAIN_REFERENCE_LUFS - integrated_lufs,
+        })
+    } else {
+        None
+    };
+
+    let conn = library::open()?;
+    let mut tracks = Vec::new();
+    for analysis in analyses {
+        let integrated_lufs = analysis.meter.loudness_global()?;
+        // gated loudness of digital silence, there is no sensible gain for it
+        if !integrated_lufs.is_finite() {
+            failed.push(LoudnessFailure {
+                path: analysis.path,
+                error: "Track is silent!".to_string(),
+            });
+            continue;
+        }
+
+        let mut track = TrackLoudness {
+            path: analysis.path,
+            integrated_lufs,
+            loudness_range_lu: analysis.meter.loudness_range()?,
+            true_peak: true_peak(&analysis.meter)?,
+            track_gain_db: REPLAYGAIN_REFERENCE_LUFS - integrated_lufs,
+            album_gain_db: album.as_ref().map(|album| album.gain_db),
+            album_true_peak: album.as_ref().map(|album| album.true_peak),
+            tag_error: None,
+        };
+
+        let mut stamp = Some(analysis.stamp);
+        if write_tags {
+            match write_gain_tags(&track, analysis.stamp) {
+                Ok(written) => stamp = Some(written),
+                Err(err) => {
+                    track.tag_error = Some(err.to_string());
+                    // most likely changed since the analysis, which makes it stale
+                    stamp = None;
+                }
+            }
+        }
+        if let Some(stamp) = stamp {
+            conn.execute(
+                "INSERT OR REPLACE INTO loudness (
+                    path, size, mtime, integrated_lufs, loudness_range, true_peak,
+                    album_lufs, album_true_peak
+                ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+                params![
+                    track.path.raw,
+                    stamp.size,
+                    stamp.mtime,
+                    track.integrated_lufs,
+                    track.loudness_range_lu,
+                    track.true_peak,
+                    album.as_ref().map(|album| album.integrated_lufs),
+                    track.album_true_peak,
+                ],
+            )?;
+        }
+        tracks.push(track);
+    }
+
+    Ok(LoudnessReport {
+        tracks,
+        album,
+        failed,
+    })
+}
+
+/// Returns the stored analysis of a track, unless the file changed since.
+pub fn get_track_loudness(path: TrackPath) -> Result<Option<TrackLoudness>> {
+    let current = stamp(&path.to_path_buf())?;
+    let conn = library::open()?;
+    let stored = conn
+        .query_row(
+            "SELECT size, mtime, integrated_lufs, loudness_range, true_peak,
+                album_lufs, album_true_peak
+            FROM loudness WHERE path = ?1",
+            [&path.raw],
+            |row| {
+                let stamp = FileStamp {
+                    size: row.get(0)?,
+                    mtime: row.get(1)?,
+                };
+                let integrated_lufs: f64 = row.get(2)?;
+                let album_lufs: Option<f64> = row.get(5)?;
+                Ok((
+                    stamp,
+                    TrackLoudness {
+                        path: path.clone(),
+                        integrated_lufs,
+                        loudness_range_lu: row.get(3)?,
+                        true_peak: row.get(4)?,
+                        track_gain_db: REPLAYGAIN_REFERENCE_LUFS - integrated_lufs,
+                        album_gain_db: album_lufs.map(|lufs| REPLAYGAIN_REFERENCE_LUFS - lufs),
+                        album_true_peak: row.get(6)?,
+                        tag_error: None,
+                    },
+                ))
+            },
+        )
+        .optional()?;
+
+    Ok(stored.and_then(|(stamp, track)| (stamp == current).then_some(track)))
+}
+
+/// Linear volume factor to play a track at, from `Metadata::replay_gain`.
+///
+/// Falls back to the other gain when the preferred one is missing, and to 1.0
+/// for untagged tracks. With `prevent_clipping` the factor is capped so the
+/// peak stays at full scale. Factors above 1.0 need headroom from the player
+/// volume, multiply them in before clamping.
+#[frb(sync)]
+pub fn replay_gain_volume(
+    gain: ReplayGain,
+    mode: ReplayGainMode,
+    preamp_db: f64,
+    prevent_clipping: bool,
+) -> f64 {
+    let album = match mode {
+        ReplayGainMode::Off => return 1.0,
+        ReplayGainMode::Track => false,
+        ReplayGainMode::Album => true,
+        ReplayGainMode::Smart { album_in_order } => album_in_order,
+    };
+
+    let track_values = (gain.track_gain_db, gain.track_peak);
+    let album_values = (gain.album_gain_db, gain.album_peak);
+    let (preferred, fallback) = if album {
+        (album_values, track_values)
+    } else {
+        (track_values, album_values)
+    };
+    let Some((gain_db, peak)) = preferred
+        .0
+        .map(|gain_db| (gain_db, preferred.1))
+        .or_else(|| fallback.0.map(|gain_db| (gain_db, fallback.1)))
+    else {
+        return 1.0;
+    };
+
+    let volume = 10f64.powf((gain_db + preamp_db) / 20.0);
+    match peak.filter(|peak| prevent_clipping && *peak > 0.0) {
+        Some(peak) => volume.min(1.0 / peak),
+        None => volume,
+    }
+}
+
+fn analyze(path: &TrackPath) -> Result<Analysis> {
+    let path_buf = path.to_path_buf();
+    // taken first, so tags aren't written over a file that changed during the analysis
+    let stamp = stamp(&path_buf)?;
+
+    let mut hint = Hint::new();
+    if let Some(extension) = path_buf.extension().and_then(OsStr::to_str) {
+        hint.with_extension(extension);
+    }
+    let source = MediaSourceStream::new(Box::new(File::open(&path_buf)?), Default::default());
+    let mut format = symphonia::default::get_probe()
+        .format(
+            &hint,
+            source,
+            &FormatOptions::default(),
+            &MetadataOptions::default(),
+        )?
+        .format;
+    let track = format.default_track().context("File has no audio track!")?;
+    let track_id = track.id;
+    let mut decoder =
+        symphonia::default::get_codecs().make(&track.codec_params, &DecoderOptions::default())?;
+
+    let mut meter = None;
+    let mut buffer: Option<SampleBuffer<f32>> = None;
+    loop {
+        let packet = match format.next_packet() {
+            Ok(packet) => packet,
+            Err(DecodeError::IoError(err)) if err.kind() == io::ErrorKind::UnexpectedEof => break,
+            Err(err) => return Err(err.into()),
+        };
+        if packet.track_id() != track_id {
+            continue;
+        }
+        let decoded = match decoder.decode(&packet) {
+            Ok(decoded) => decoded,
+            // a damaged frame only costs a few milliseconds of the measurement
+            Err(DecodeError::DecodeError(_)) => continue,
+            Err(err) => return Err(err.into()),
+        };
+
+        let spec = *decoded.spec();
+        let channels = spec.channels.count();
+        if meter.is_none() {
+            let mode = Mode::I | Mode::LRA | Mode::TRUE_PEAK;
+            meter = Some(EbuR128::new(channels as u32, spec.rate, mode)?);
+        }
+        if buffer
+            .as_ref()
+            .is_none_or(|buffer| buffer.capacity() < decoded.capacity() * channels)
+        {
+            buffer = Some(SampleBuffer::new(decoded.capacity() as u64, spec));
+        }
+        let (Some(meter), Some(buffer)) = (meter.as_mut(), buffer.as_mut()) else {
+            continue;
+        };
+        buffer.copy_interleaved_ref(decoded);
+        meter.add_frames_f32(buffer.samples())?;
+    }
+
+    let Some(meter) = meter else {
+        bail!("File has no audio!");
+    };
+    Ok(Analysis {
+        path: path.clone(),
+        stamp,
+        meter,
+    })
+}
+
+fn true_peak(meter: &EbuR128) -> Result<f64> {
+    (0..meter.channels()).try_fold(0.0, |peak: f64, channel| {
+        Ok(peak.max(meter.true_peak(channel)?))
+    })
+}
+
+fn write_gain_tags(track: &TrackLoudness, expected: FileStamp) -> Result<FileStamp> {
+    let path = track.path.to_path_buf();
+    let opus = is_opus(&path);
+
+    let mut stored = true;
+    let stamp = edit_tag(&path, expected, |tag| {
+        if opus {
+            // gains are relative to the output gain in the header, which is left at 0
+            set_unknown(tag, "R128_TRACK_GAIN", r128_gain(track.integrated_lufs));
+            if let Some(album_gain) = track.album_gain_db {
+                let album_lufs = REPLAYGAIN_REFERENCE_LUFS - album_gain;
+                set_unknown(tag, "R128_ALBUM_GAIN", r128_gain(album_lufs));
+            }
+            return;
+        }
+
+        let mut set = |key, value| stored &= tag.insert_text(key, value);
+        set(ItemKey::ReplayGainTrackGain, format!("{:.2} dB", track.track_gain_db));
+        set(ItemKey::ReplayGainTrackPeak, format!("{:.6}", track.true_peak));
+        if let (Some(gain), Some(peak)) = (track.album_gain_db, track.album_true_peak) {
+            set(ItemKey::ReplayGainAlbumGain, format!("{gain:.2} dB"));
+            set(ItemKey::ReplayGainAlbumPeak, format!("{peak:.6}"));
+        }
+    })?;
+
+    if !stored {
+        bail!("The tags of this file can't hold ReplayGain values!");
+    }
+    Ok(stamp)
+}
+
+// `insert_text` only takes keys the tag type knows, and Vorbis comments have
+// no R128 keys of their own
+fn set_unknown(tag: &mut Tag, key: &str, value: String) {
+    tag.insert_unchecked(TagItem::new(
+        ItemKey::Unknown(key.to_string()),
+        ItemValue::Text(value),
+    ));
+}
+
+// Q7.8 fixed point dB, as the Opus spec wants it
+fn r128_gain(integrated_lufs: f64) -> String {
+    let gain = ((R128_REFERENCE_LUFS - integrated_lufs) * 256.0).round();
+    (gain.clamp(i16::MIN as f64, i16::MAX as f64) as i16).to_string()
+}
+
+fn is_opus(path: &Path) -> bool {
+    Probe::open(path)
+        .ok()
+        .and_then(|probe| probe.guess_file_type().ok())
+        .and_then(|probe| probe.file_type())
+        == Some(FileType::Opus)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tagged(track: Option<(f64, f64)>, album: Option<(f64, f64)>) -> ReplayGain {
+        ReplayGain {
+            track_gain_db: track.map(|(gain, _)| gain),
+            track_peak: track.map(|(_, peak)| peak),
+            album_gain_db: album.map(|(gain, _)| gain),
+            album_peak: album.map(|(_, peak)| peak),
+        }
+    }
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!((actual - expected).abs() < 1e-9, "{actual} != {expected}");
+    }
+
+    #[test]
+    fn picks_the_gain_for_the_mode() {
+        let gain = tagged(Some((-20.0, 0.0)), Some((20.0, 0.0)));
+        assert_eq!(
+            replay_gain_volume(gain, ReplayGainMode::Off, 6.0, true),
+            1.0
+        );
+        assert_close(
+            replay_gain_volume(gain, ReplayGainMode::Track, 0.0, false),
+            0.1,
+        );
+        assert_close(
+            replay_gain_volume(gain, ReplayGainMode::Album, 0.0, false),
+            10.0,
+        );
+
+        let smart = |album_in_order| ReplayGainMode::Smart { album_in_order };
+        assert_close(replay_gain_volume(gain, smart(false), 0.0, false), 0.1);
+        assert_close(replay_gain_volume(gain, smart(true), 0.0, false), 10.0);
+    }
+
+    #[test]
+    fn falls_back_to_the_other_gain() {
+        let track_only = tagged(Some((-20.0, 0.5)), None);
+        assert_close(
+            replay_gain_volume(track_only, ReplayGainMode::Album, 0.0, false),
+            0.1,
+        );
+
+        let album_only = tagged(None, Some((-20.0, 0.5)));
+        assert_close(
+            replay_gain_volume(album_only, ReplayGainMode::Track, 0.0, false),
+            0.1,
+        );
+
+        let untagged = tagged(None, None);
+        assert_eq!(
+            replay_gain_volume(untagged, ReplayGainMode::Track, 6.0, true),
+            1.0
+        );
+    }
+
+    #[test]
+    fn adds_the_preamp() {
+        let gain = tagged(Some((-26.0, 0.0)), None);
+        assert_close(
+            replay_gain_volume(gain, ReplayGainMode::Track, 6.0, false),
+            0.1,
+        );
+    }
+
+    #[test]
+    fn keeps_the_peak_below_full_scale() {
+        let gain = tagged(Some((20.0, 0.8)), None);
+        assert_close(
+            replay_gain_volume(gain, ReplayGainMode::Track, 0.0, true),
+            1.25,
+        );
+        assert_close(
+            replay_gain_volume(gain, ReplayGainMode::Track, 0.0, false),
+            10.0,
+        );
+
+        // the fallback brings its own peak along
+        assert_close(
+            replay_gain_volume(gain, ReplayGainMode::Album, 0.0, true),
+            1.25,
+        );
+
+        // quiet tracks are left alone, and a missing peak can't clip
+        let quiet = tagged(Some((-20.0, 0.8)), None);
+        assert_close(
+            replay_gain_volume(quiet, ReplayGainMode::Track, 0.0, true),
+            0.1,
+        );
+        let unknown_peak = tagged(Some((20.0, 0.0)), None);
+        assert_close(
+            replay_gain_volume(unknown_peak, ReplayGainMode::Track, 0.0, true),
+            10.0,
+        );
+    }
+
+    #[test]
+    fn converts_loudness_to_r128_gain() {
+        assert_eq!(r128_gain(-23.0), "0");
+        assert_eq!(r128_gain(-18.0), "-1280");
+        assert_eq!(r128_gain(-33.5), "2688");
+        assert_eq!(r128_gain(-300.0), i16::MAX.to_string());
+        assert_eq!(r128_gain(300.0), i16::MIN.to_string());
+    }
+
+    // an Ogg page holding whole packets, `kind` is 2 for the first page and 4 for the last
+    fn ogg_page(kind: u8, granule: u64, sequence: u32, packets: &[&[u8]]) -> Vec<u8> {
+        let mut lacing = Vec::new();
+        for packet in packets {
+            lacing.extend(std::iter::repeat_n(255, packet.len() / 255));
+            lacing.push((packet.len() % 255) as u8);
+        }
+        let mut page = b"OggS\0".to_vec();
+        page.push(kind);
+        page.extend_from_slice(&granule.to_le_bytes());
+        page.extend_from_slice(&1u32.to_le_bytes());
+        page.extend_from_slice(&sequence.to_le_bytes());
+        page.extend_from_slice(&[0; 4]);
+        page.push(lacing.len() as u8);
+        page.extend_from_slice(&lacing);
+        packets
+            .iter()
+            .for_each(|packet| page.extend_from_slice(packet));
+
+        let crc = page.iter().fold(0u32, |mut crc, byte| {
+            crc ^= (*byte as u32) << 24;
+            for _ in 0..8 {
+                crc = if crc & 0x8000_0000 != 0 {
+                    (crc << 1) ^ 0x04C1_1DB7
+                } else {
+                    crc << 1
+                };
+            }
+            crc
+        });
+        page[22..26].copy_from_slice(&crc.to_le_bytes());
+        page
+    }
+
+    // one silent 20 ms frame
+    fn write_opus(path: &Path) {
+        let mut head = b"OpusHead\x01\x01".to_vec();
+        head.extend_from_slice(&312u16.to_le_bytes());
+        head.extend_from_slice(&48000u32.to_le_bytes());
+        head.extend_from_slice(&[0, 0, 0]);
+        let mut tags = b"OpusTags".to_vec();
+        tags.extend_from_slice(&4u32.to_le_bytes());
+        tags.extend_from_slice(b"amai");
+        tags.extend_from_slice(&0u32.to_le_bytes());
+
+        let mut opus = ogg_page(2, 0, 0, &[&head]);
+        opus.extend(ogg_page(0, 0, 1, &[&tags]));
+        opus.extend(ogg_page(4, 312 + 960, 2, &[&[0xF8]]));
+        std::fs::write(path, opus).unwrap();
+    }
+
+    #[test]
+    fn writes_r128_gains_to_opus() {
+        use lofty::ogg::OpusFile;
+        use lofty::{AudioFile, ParseOptions};
+
+        let path = std::env::temp_dir().join(format!("amai-r128-{}.opus", std::process::id()));
+        write_opus(&path);
+
+        let track = TrackLoudness {
+            path: TrackPath::new(&path),
+            integrated_lufs: -18.0,
+            loudness_range_lu: 0.0,
+            true_peak: 0.5,
+            track_gain_db: 0.0,
+            album_gain_db: Some(-2.0),
+            album_true_peak: Some(0.5),
+            tag_error: None,
+        };
+        let written = write_gain_tags(&track, stamp(&path).unwrap());
+
+        let file = OpusFile::read_from(&mut File::open(&path).unwrap(), ParseOptions::new());
+        let _ = std::fs::remove_file(&path);
+        written.unwrap();
+        let file = file.unwrap();
+        let comments = file.vorbis_comments();
+        assert_eq!(comments.get("R128_TRACK_GAIN"), Some("-1280"));
+        assert_eq!(comments.get("R128_ALBUM_GAIN"), Some("-1792"));
+    }
+}
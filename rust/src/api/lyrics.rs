@@ -0,0 +1,829 @@
+use crate::api::metadata::{all_tags, id3v2_tag, temp_path, MetadataError};
+use crate::api::path::TrackPath;
+use flutter_rust_bridge::frb;
+use lofty::id3::v2::{FrameValue, Id3v2Tag, SyncTextContentType, SynchronizedText, TimestampFormat};
+use lofty::{ItemKey, TagType};
+use anyhow::{bail, Result};
+use std::ffi::OsStr;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// languages a tag can't name, ID3 uses `XXX` and some taggers write `und`
+const UNKNOWN_LANGUAGES: &[&str] = &["xxx", "und", "\0\0\0"];
+
+pub enum LyricsSource {
+    Tag,
+    /// An ID3 `SYLT` frame, which is always synced.
+    SyncedTag,
+    Sidecar { path: TrackPath },
+}
+
+pub struct LyricLine {
+    /// `None` for unsynced lyrics.
+    pub start_ms: Option<u64>,
+    pub text: String,
+    /// Word timings from enhanced LRC or `SYLT`, empty if only the line is synced.
+    pub words: Vec<LyricWord>,
+}
+
+#[derive(Clone)]
+pub struct LyricWord {
+    pub start_ms: u64,
+    /// Only known when the source marks it, otherwise the word lasts until the next one.
+    pub end_ms: Option<u64>,
+    /// Includes the whitespace around it, so the words joined give the line.
+    pub text: String,
+}
+
+pub struct Lyrics {
+    pub source: LyricsSource,
+    /// ISO 639 code, e.g. `eng` from a tag or `en` from `song.en.lrc`.
+    pub language: Option<String>,
+    /// Whether the lines carry timestamps.
+    pub synced: bool,
+    /// Already applied to the timestamps, positive values show lines earlier.
+    pub offset_ms: i64,
+    pub lines: Vec<LyricLine>,
+    pub timeline: LyricsTimeline,
+}
+
+/// Finds the line and word to highlight without sending the lyrics back and forth.
+#[frb(opaque)]
+pub struct LyricsTimeline {
+    starts: Vec<u64>,
+    lines: Vec<TimedLine>,
+}
+
+struct TimedLine {
+    words: Vec<u64>,
+    // where the last word ends, the next line's start if the source doesn't say
+    end: Option<u64>,
+}
+
+pub struct LyricsPosition {
+    /// Index into `Lyrics::lines`, `None` before the first line and for unsynced lyrics.
+    pub line: Option<usize>,
+    /// Index into the line's `words`, `None` for lines without word timings.
+    pub word: Option<usize>,
+    /// How far into the current word playback is, from 0 to 1, for a smooth wipe.
+    pub word_progress: f64,
+}
+
+impl LyricsTimeline {
+    /// Index into `Lyrics::lines` of the line being sung at `position_ms`,
+    /// `None` before the first line and for unsynced lyrics.
+    #[frb(sync)]
+    pub fn line_index_at(&self, position_ms: u64) -> Option<usize> {
+        self.starts
+            .partition_point(|start| *start <= position_ms)
+            .checked_sub(1)
+    }
+
+    /// Line and word being sung at `position_ms`.
+    ///
+    /// Both are binary searches, cheap enough to call on every frame.
+    #[frb(sync)]
+    pub fn lyrics_at(&self, position_ms: u64) -> LyricsPosition {
+        let line = self.line_index_at(position_ms);
+        let Some(timed) = line.and_then(|line| self.lines.get(line)) else {
+            return LyricsPosition {
+                line,
+                word: None,
+                word_progress: 0.0,
+            };
+        };
+
+        let word = timed
+            .words
+            .partition_point(|start| *start <= position_ms)
+            .checked_sub(1);
+        let word_progress = word.map_or(0.0, |word| {
+            let start = timed.words[word];
+            let end = timed
+                .words
+                .get(word + 1)
+                .copied()
+                .or(timed.end)
+                .or_else(|| line.and_then(|line| self.starts.get(line + 1).copied()));
+            match end {
+                Some(end) if end > start => {
+                    ((position_ms - start) as f64 / (end - start) as f64).min(1.0)
+                }
+                _ => 1.0,
+            }
+        });
+
+        LyricsPosition {
+            line,
+            word,
+            word_progress,
+        }
+    }
+}
+
+/// Builds an LRC file from plain lyrics and the times a user tapped along to them.
+///
+/// Every non-empty line needs exactly one tap, in order; blank lines between
+/// verses are dropped. Save the result with `save_lrc_sidecar`, or into the
+/// tag by passing it as `MetadataChanges::lyrics` to `write_metadata`.
+pub fn build_lrc(text: String, taps_ms: Vec<u64>) -> Result<String> {
+    let lines: Vec<_> = text
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect();
+    if lines.len() != taps_ms.len() {
+        bail!("Got {} taps for {} lines!", taps_ms.len(), lines.len());
+    }
+    if taps_ms.windows(2).any(|pair| pair[0] > pair[1]) {
+        bail!("Taps are out of order!");
+    }
+
+    Ok(lines
+        .iter()
+        .zip(taps_ms)
+        .map(|(line, tap)| format!("[{}]{line}\n", format_timestamp(tap)))
+        .collect())
+}
+
+/// Moves timestamps in an LRC file by `delta_ms`, positive values show lyrics later.
+///
+/// With `from_line` set, only that line of the file and the ones after it are
+/// moved, for when the sync drifts partway through. Word stamps move along
+/// with their lines, everything else is kept as is.
+pub fn nudge_lrc(lrc: String, delta_ms: i64, from_line: Option<usize>) -> String {
+    let from_line = from_line.unwrap_or(0);
+    lrc.split_inclusive('\n')
+        .enumerate()
+        .map(|(index, line)| {
+            if index < from_line {
+                line.to_string()
+            } else {
+                shift_stamps(line, delta_ms)
+            }
+        })
+        .collect()
+}
+
+/// Writes `lrc` next to the track as `song.lrc`, or `song.<language>.lrc`,
+/// replacing any existing file of that name.
+pub fn save_lrc_sidecar(path: TrackPath, lrc: String, language: Option<String>) -> Result<TrackPath> {
+    let path = path.to_path_buf();
+    let Some(stem) = path.file_stem() else {
+        bail!("Track has no file name!");
+    };
+
+    let mut name = stem.to_os_string();
+    if let Some(language) = language {
+        if !is_sidecar_language(&language) {
+            bail!("Invalid lyrics language {language}!");
+        }
+        name.push(".");
+        name.push(language);
+    }
+    name.push(".lrc");
+    let sidecar = path.with_file_name(name);
+
+    let temp = temp_path(&sidecar);
+    fs::write(&temp, lrc)?;
+    fs::rename(&temp, &sidecar)?;
+
+    Ok(TrackPath::new(&sidecar))
+}
+
+/// What an LRC file holds, with the offset already applied to the lines.
+pub(crate) struct ParsedLrc {
+    pub(crate) language: Option<String>,
+    pub(crate) offset_ms: i64,
+    pub(crate) lines: Vec<LyricLine>,
+}
+
+/// Returns all lyrics of a track, `.lrc` files next to it first and then
+/// the ones in its tags.
+///
+/// Sidecars are matched by name, `song.lrc` and per language `song.en.lrc`.
+pub fn get_lyrics(path: TrackPath) -> Result<Vec<Lyrics>, MetadataError> {
+    let path = path.to_path_buf();
+    let mut lyrics: Vec<_> = sidecars(&path)
+        .into_iter()
+        .filter_map(|(sidecar, language)| {
+            let text = read_text(&sidecar)?;
+            let parsed = parse_lrc(&text);
+            Some(Lyrics::new(
+                LyricsSource::Sidecar {
+                    path: TrackPath::new(&sidecar),
+                },
+                parsed.language.clone().or(language),
+                parsed,
+            ))
+        })
+        .collect();
+
+    // lyrics frames only live in ID3v2, which lofty folds away when it merges tags
+    let id3v2 = id3v2_tag(&path).ok().flatten();
+    for sylt in id3v2.iter().flat_map(synced_frames) {
+        let language = language(&sylt.language);
+        lyrics.push(Lyrics::new(
+            LyricsSource::SyncedTag,
+            language,
+            ParsedLrc {
+                language: None,
+                offset_ms: 0,
+                lines: sylt_lines(sylt.content),
+            },
+        ));
+    }
+
+    let uslt = id3v2
+        .iter()
+        .flat_map(Id3v2Tag::unsync_text)
+        .map(|frame| (frame.content.as_str(), language(&frame.language)));
+    let file = lofty::read_from_path(&path)?;
+    let tags = all_tags(&file);
+    // Vorbis comments and MP4 atoms have no language
+    let other = tags
+        .iter()
+        .filter(|tag| id3v2.is_none() || tag.tag_type() != TagType::Id3v2)
+        .flat_map(|tag| tag.get_strings(&ItemKey::Lyrics))
+        .map(|text| (text, None));
+
+    for (text, language) in uslt.chain(other) {
+        // plenty of taggers store a whole LRC file as plain lyrics
+        let parsed = parse_lrc(text);
+        lyrics.push(Lyrics::new(
+            LyricsSource::Tag,
+            parsed.language.clone().or(language),
+            parsed,
+        ));
+    }
+
+    Ok(lyrics)
+}
+
+impl Lyrics {
+    pub(crate) fn new(source: LyricsSource, language: Option<String>, parsed: ParsedLrc) -> Self {
+        let synced = parsed.lines.iter().any(|line| line.start_ms.is_some());
+        let timed: Vec<_> = parsed
+            .lines
+            .iter()
+            .filter_map(|line| Some((line.start_ms?, line)))
+            .collect();
+        let timeline = LyricsTimeline {
+            starts: timed.iter().map(|(start, _)| *start).collect(),
+            lines: timed
+                .iter()
+                .map(|(_, line)| TimedLine {
+                    words: line.words.iter().map(|word| word.start_ms).collect(),
+                    end: line.words.last().and_then(|word| word.end_ms),
+                })
+                .collect(),
+        };
+
+        Self {
+            source,
+            language,
+            synced,
+            offset_ms: parsed.offset_ms,
+            lines: parsed.lines,
+            timeline,
+        }
+    }
+}
+
+/// Parses LRC, falling back to plain lines for text without any timestamps.
+///
+/// Lines with several timestamps (`[00:12.00][01:30.50]chorus`) are repeated
+/// at each of them, untimed lines in between synced ones are dropped. Inline
+/// `<mm:ss.xx>` stamps from enhanced LRC become word timings.
+pub(crate) fn parse_lrc(text: &str) -> ParsedLrc {
+    let mut language = None;
+    let mut offset_ms = 0;
+    let mut timed = Vec::new();
+    let mut plain = Vec::new();
+
+    for line in text.trim_start_matches('\u{feff}').lines() {
+        let line = line.trim();
+        let mut rest = line;
+        let mut starts = Vec::new();
+
+        while let Some((tag, after)) = rest.strip_prefix('[').and_then(|tag| tag.split_once(']')) {
+            if let Some(time) = parse_timestamp(tag) {
+                starts.push(time);
+            } else {
+                // anything else in brackets, like `[Verse 1: Artist]`, is part of the lyrics
+                let Some((key, value)) = tag.split_once(':') else {
+                    break;
+                };
+                match key.trim().to_lowercase().as_str() {
+                    "offset" => offset_ms = value.trim().parse().unwrap_or(0),
+                    "la" | "lang" => language = Some(value.trim().to_lowercase()),
+                    "ar" | "ti" | "al" | "au" | "by" | "length" | "re" | "ve" => {}
+                    _ => break,
+                }
+            }
+            rest = after;
+        }
+
+        if starts.is_empty() {
+            // metadata lines have been consumed entirely
+            if rest.len() == line.len() {
+                plain.push(line.to_string());
+            }
+            continue;
+        }
+        timed.extend(starts.into_iter().map(|start| (start, rest.trim().to_string())));
+    }
+
+    if timed.is_empty() {
+        // keep blank lines between verses, but not around the lyrics
+        let start = plain.iter().position(|line| !line.is_empty()).unwrap_or(plain.len());
+        let end = plain.iter().rposition(|line| !line.is_empty()).map_or(start, |end| end + 1);
+        return ParsedLrc {
+            language,
+            offset_ms,
+            lines: plain[start..end]
+                .iter()
+                .map(|text| LyricLine {
+                    start_ms: None,
+                    text: text.clone(),
+                    words: Vec::new(),
+                })
+                .collect(),
+        };
+    }
+
+    // stable, so lines sharing a timestamp stay in file order
+    timed.sort_by_key(|(start, _)| *start);
+    let shift = |time: u64| time.saturating_add_signed(offset_ms.saturating_neg());
+    ParsedLrc {
+        language,
+        offset_ms,
+        lines: timed
+            .into_iter()
+            .map(|(start, text)| {
+                let (text, words) = parse_words(&text);
+                LyricLine {
+                    start_ms: Some(shift(start)),
+                    text,
+                    words: words
+                        .into_iter()
+                        .map(|word| LyricWord {
+                            start_ms: shift(word.start_ms),
+                            end_ms: word.end_ms.map(shift),
+                            text: word.text,
+                        })
+                        .collect(),
+                }
+            })
+            .collect(),
+    }
+}
+
+// splits `<00:12.00>Some <00:12.40>words<00:13.10>` into the plain line and
+// its words, a stamp without text after it marks where the previous word ends
+fn parse_words(line: &str) -> (String, Vec<LyricWord>) {
+    let mut text = String::new();
+    let mut words: Vec<LyricWord> = Vec::new();
+    let mut rest = line;
+
+    loop {
+        let stamp = rest
+            .find('<')
+            .and_then(|open| Some((open, open + rest[open..].find('>')?)))
+            .and_then(|(open, close)| Some((open, close, parse_timestamp(&rest[open + 1..close])?)));
+        let Some((open, close, time)) = stamp else {
+            text.push_str(rest);
+            if let Some(word) = words.last_mut() {
+                word.text.push_str(rest);
+            }
+            break;
+        };
+
+        let before = &rest[..open];
+        text.push_str(before);
+        if let Some(word) = words.last_mut() {
+            word.text.push_str(before);
+        }
+
+        rest = &rest[close + 1..];
+        let word_text = &rest[..rest.find('<').unwrap_or(rest.len())];
+        if word_text.trim().is_empty() {
+            if let Some(word) = words.last_mut() {
+                word.end_ms = Some(time);
+            }
+        } else {
+            words.push(LyricWord {
+                start_ms: time,
+                end_ms: None,
+                text: String::new(),
+            });
+        }
+    }
+
+    (text.trim().to_string(), words)
+}
+
+// `SYLT` entries are whole lines or single syllables, a leading line break
+// starts a new line in the latter
+fn sylt_lines(content: Vec<(u32, String)>) -> Vec<LyricLine> {
+    let by_word = content
+        .iter()
+        .skip(1)
+        .any(|(_, text)| text.starts_with(['\n', '\r']));
+    if !by_word {
+        return content
+            .into_iter()
+            .map(|(start, text)| LyricLine {
+                start_ms: Some(start as u64),
+                text: text.trim().to_string(),
+                words: Vec::new(),
+            })
+            .collect();
+    }
+
+    let mut lines: Vec<LyricLine> = Vec::new();
+    for (start, text) in content {
+        let word = LyricWord {
+            start_ms: start as u64,
+            end_ms: None,
+            text: text.trim_start_matches(['\n', '\r']).to_string(),
+        };
+        match lines.last_mut() {
+            Some(line) if !text.starts_with(['\n', '\r']) => {
+                line.text.push_str(&word.text);
+                line.words.push(word);
+            }
+            _ => lines.push(LyricLine {
+                start_ms: Some(word.start_ms),
+                text: word.text.clone(),
+                words: vec![word],
+            }),
+        }
+    }
+    for line in &mut lines {
+        line.text = line.text.trim().to_string();
+    }
+    lines
+}
+
+fn synced_frames(tag: &Id3v2Tag) -> Vec<SynchronizedText> {
+    tag.into_iter()
+        .filter(|frame| frame.id_str() == "SYLT")
+        .filter_map(|frame| match frame.content() {
+            FrameValue::Binary(data) => SynchronizedText::parse(data).ok(),
+            _ => None,
+        })
+        // MPEG frame stamps would need the frame rate, no tagger in use writes them
+        .filter(|sylt| {
+            sylt.timestamp_format == TimestampFormat::MS
+                && sylt.content_type == SyncTextContentType::Lyrics
+        })
+        .collect()
+}
+
+fn language(code: &[u8]) -> Option<String> {
+    let language = String::from_utf8_lossy(code).to_lowercase();
+    (!UNKNOWN_LANGUAGES.contains(&language.as_str())).then_some(language)
+}
+
+pub(crate) fn format_timestamp(ms: u64) -> String {
+    let centis = (ms + 5) / 10;
+    format!("{:02}:{:02}.{:02}", centis / 6000, centis / 100 % 60, centis % 100)
+}
+
+// rewrites every `[mm:ss.xx]` and `<mm:ss.xx>` stamp, leaving tags like `[ar:...]` alone
+fn shift_stamps(line: &str, delta_ms: i64) -> String {
+    let mut shifted = String::with_capacity(line.len());
+    let mut rest = line;
+
+    while let Some(open) = rest.find(['[', '<']) {
+        let close = if rest[open..].starts_with('[') { ']' } else { '>' };
+        let Some(end) = rest[open..].find(close).map(|end| open + end) else {
+            break;
+        };
+
+        shifted.push_str(&rest[..=open]);
+        let stamp = &rest[open + 1..end];
+        match parse_timestamp(stamp) {
+            Some(time) => shifted.push_str(&format_timestamp(time.saturating_add_signed(delta_ms))),
+            None => shifted.push_str(stamp),
+        }
+        shifted.push(close);
+        rest = &rest[end + 1..];
+    }
+
+    shifted.push_str(rest);
+    shifted
+}
+
+/// Parses `mm:ss`, `mm:ss.xx` or `mm:ss:xx` into milliseconds.
+pub(crate) fn parse_timestamp(stamp: &str) -> Option<u64> {
+    let (minutes, rest) = stamp.trim().split_once(':')?;
+    let (seconds, fraction) = match rest.split_once(['.', ':']) {
+        Some((seconds, fraction)) => (seconds, fraction),
+        None => (rest, "0"),
+    };
+
+    let minutes: u64 = minutes.parse().ok()?;
+    let seconds: u64 = seconds.parse().ok()?;
+    if seconds >= 60 || fraction.is_empty() || !fraction.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    // `.5` is half a second, `.05` and `.050` are 50 milliseconds
+    let digits = &fraction[..fraction.len().min(3)];
+    let millis = digits.parse::<u64>().ok()? * 10u64.pow(3 - digits.len() as u32);
+
+    Some((minutes * 60 + seconds) * 1000 + millis)
+}
+
+// `song.lrc` and `song.<language>.lrc`, in name order so the result is stable
+fn sidecars(track: &Path) -> Vec<(PathBuf, Option<String>)> {
+    let (Some(dir), Some(stem)) = (track.parent(), track.file_stem()) else {
+        return Vec::new();
+    };
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+
+    let mut sidecars: Vec<_> = entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter_map(|path| {
+            let is_lrc = path
+                .extension()
+                .and_then(OsStr::to_str)
+                .is_some_and(|ext| ext.eq_ignore_ascii_case("lrc"));
+            if !is_lrc {
+                return None;
+            }
+
+            let name = Path::new(path.file_stem()?);
+            if name == Path::new(stem) {
+                return Some((path, None));
+            }
+            // `song.en` has the stem `song` and the language `en`
+            let language = name.extension()?.to_str()?.to_lowercase();
+            (name.file_stem()? == stem && is_sidecar_language(&language))
+                .then_some((path, Some(language)))
+        })
+        .collect();
+    sidecars.sort_by(|(a, _), (b, _)| a.cmp(b));
+    sidecars
+}
+
+// `en`, `eng` or `pt-br`, anything else like `song.live.lrc` is a different song
+fn is_sidecar_language(language: &str) -> bool {
+    let mut subtags = language.split('-');
+    let primary = subtags.next().unwrap_or_default();
+    language.len() <= 8
+        && (2..=3).contains(&primary.len())
+        && primary.bytes().all(|b| b.is_ascii_alphabetic())
+        && subtags.all(|subtag| !subtag.is_empty() && subtag.bytes().all(|b| b.is_ascii_alphanumeric()))
+}
+
+fn read_text(path: &Path) -> Option<String> {
+    let bytes = fs::read(path).ok()?;
+    // most LRC files are UTF-8, anything else is at least mostly readable
+    Some(match String::from_utf8(bytes) {
+        Ok(text) => text,
+        Err(err) => String::from_utf8_lossy(err.as_bytes()).to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texts(lines: &[LyricLine]) -> Vec<&str> {
+        lines.iter().map(|line| line.text.as_str()).collect()
+    }
+
+    fn starts(lines: &[LyricLine]) -> Vec<Option<u64>> {
+        lines.iter().map(|line| line.start_ms).collect()
+    }
+
+    #[test]
+    fn parses_timestamps() {
+        assert_eq!(parse_timestamp("01:02"), Some(62_000));
+        assert_eq!(parse_timestamp("01:02.5"), Some(62_500));
+        assert_eq!(parse_timestamp("01:02.05"), Some(62_050));
+        assert_eq!(parse_timestamp("01:02.050"), Some(62_050));
+        assert_eq!(parse_timestamp("01:02:34"), Some(62_340));
+        assert_eq!(parse_timestamp(" 10:00.00 "), Some(600_000));
+        assert_eq!(parse_timestamp("01:60.00"), None);
+        assert_eq!(parse_timestamp("01:02."), None);
+        assert_eq!(parse_timestamp("ar:Artist"), None);
+        assert_eq!(parse_timestamp("Verse 1: Artist"), None);
+    }
+
+    #[test]
+    fn parses_synced_lines_in_order() {
+        let parsed = parse_lrc("[00:05.00]second\n[00:01.00]first\n[00:09.00][00:03.00]chorus\n");
+        assert_eq!(
+            texts(&parsed.lines),
+            ["first", "chorus", "second", "chorus"]
+        );
+        assert_eq!(
+            starts(&parsed.lines),
+            [Some(1_000), Some(3_000), Some(5_000), Some(9_000)]
+        );
+    }
+
+    #[test]
+    fn applies_offset_and_language() {
+        let parsed = parse_lrc("\u{feff}[la:EN]\n[offset:500]\n[ar:Artist]\n[00:01.00]line\n");
+        assert_eq!(parsed.language.as_deref(), Some("en"));
+        assert_eq!(parsed.offset_ms, 500);
+        // a positive offset shows lines earlier
+        assert_eq!(starts(&parsed.lines), [Some(500)]);
+    }
+
+    #[test]
+    fn keeps_unknown_brackets_as_lyrics() {
+        let parsed =
+            parse_lrc("[ti:Song]\n[Verse 1: Artist]\nfirst line\n\n[Chorus]\nsecond line\n");
+        assert_eq!(
+            texts(&parsed.lines),
+            [
+                "[Verse 1: Artist]",
+                "first line",
+                "",
+                "[Chorus]",
+                "second line"
+            ]
+        );
+        assert!(parsed.lines.iter().all(|line| line.start_ms.is_none()));
+
+        let parsed = parse_lrc("[00:01.00][Verse 1: Artist]\n");
+        assert_eq!(texts(&parsed.lines), ["[Verse 1: Artist]"]);
+    }
+
+    #[test]
+    fn trims_blank_lines_around_plain_lyrics() {
+        let parsed = parse_lrc("\n\nfirst\n\nsecond\n\n");
+        assert_eq!(texts(&parsed.lines), ["first", "", "second"]);
+    }
+
+    #[test]
+    fn parses_enhanced_lrc_words() {
+        let (text, words) = parse_words("<00:01.00>Some <00:01.50>words<00:02.00>");
+        assert_eq!(text, "Some words");
+        let words: Vec<_> = words
+            .iter()
+            .map(|word| (word.start_ms, word.end_ms, word.text.as_str()))
+            .collect();
+        assert_eq!(
+            words,
+            [(1_000, None, "Some "), (1_500, Some(2_000), "words")]
+        );
+
+        let (text, words) = parse_words("no stamps <here>");
+        assert_eq!(text, "no stamps <here>");
+        assert!(words.is_empty());
+    }
+
+    #[test]
+    fn applies_offset_to_words() {
+        let parsed = parse_lrc("[offset:-1000]\n[00:01.00]<00:01.00>a <00:01.50>b\n");
+        let words: Vec<_> = parsed.lines[0]
+            .words
+            .iter()
+            .map(|word| word.start_ms)
+            .collect();
+        assert_eq!(parsed.lines[0].start_ms, Some(2_000));
+        assert_eq!(words, [2_000, 2_500]);
+    }
+
+    #[test]
+    fn reads_sylt_lines() {
+        let lines = sylt_lines(vec![(1_000, "first".into()), (2_000, " second ".into())]);
+        assert_eq!(texts(&lines), ["first", "second"]);
+        assert_eq!(starts(&lines), [Some(1_000), Some(2_000)]);
+        assert!(lines.iter().all(|line| line.words.is_empty()));
+    }
+
+    #[test]
+    fn reads_sylt_syllables() {
+        let lines = sylt_lines(vec![
+            (1_000, "Hel".into()),
+            (1_200, "lo ".into()),
+            (1_500, "world".into()),
+            (3_000, "\nNext".into()),
+        ]);
+        assert_eq!(texts(&lines), ["Hello world", "Next"]);
+        assert_eq!(starts(&lines), [Some(1_000), Some(3_000)]);
+        let words: Vec<_> = lines[0].words.iter().map(|word| word.start_ms).collect();
+        assert_eq!(words, [1_000, 1_200, 1_500]);
+        assert_eq!(lines[1].words[0].text, "Next");
+    }
+
+    fn lyrics(lrc: &str) -> Lyrics {
+        Lyrics::new(LyricsSource::Tag, None, parse_lrc(lrc))
+    }
+
+    #[test]
+    fn finds_line_at_position() {
+        let lyrics = lyrics("[00:01.00]first\n[00:03.00]second\n");
+        let timeline = &lyrics.timeline;
+        assert_eq!(timeline.line_index_at(0), None);
+        assert_eq!(timeline.line_index_at(1_000), Some(0));
+        assert_eq!(timeline.line_index_at(2_999), Some(0));
+        assert_eq!(timeline.line_index_at(3_000), Some(1));
+        assert_eq!(timeline.line_index_at(60_000), Some(1));
+
+        let position = timeline.lyrics_at(2_000);
+        assert_eq!((position.line, position.word), (Some(0), None));
+    }
+
+    #[test]
+    fn finds_word_and_progress_at_position() {
+        let lyrics = lyrics(
+            "[00:01.00]<00:01.00>a <00:02.00>b<00:04.00>\n[00:05.00]<00:05.00>c\n[00:09.00]d\n",
+        );
+        let timeline = &lyrics.timeline;
+
+        let position = timeline.lyrics_at(1_500);
+        assert_eq!((position.line, position.word), (Some(0), Some(0)));
+        assert_eq!(position.word_progress, 0.5);
+
+        // the end stamp closes the last word of the line
+        let position = timeline.lyrics_at(3_000);
+        assert_eq!((position.line, position.word), (Some(0), Some(1)));
+        assert_eq!(position.word_progress, 0.5);
+        assert_eq!(timeline.lyrics_at(4_500).word_progress, 1.0);
+
+        // without an end stamp, the word lasts until the next line
+        let position = timeline.lyrics_at(6_000);
+        assert_eq!((position.line, position.word), (Some(1), Some(0)));
+        assert_eq!(position.word_progress, 0.25);
+    }
+
+    #[test]
+    fn unsynced_lyrics_have_no_position() {
+        let lyrics = lyrics("just\nplain\n");
+        assert!(!lyrics.synced);
+        let position = lyrics.timeline.lyrics_at(1_000);
+        assert_eq!((position.line, position.word), (None, None));
+    }
+
+    #[test]
+    fn checks_sidecar_languages() {
+        for language in ["en", "eng", "pt-br", "zh-hant"] {
+            assert!(is_sidecar_language(language), "{language}");
+        }
+        for language in [
+            "",
+            "e",
+            "live",
+            "remix",
+            "en-",
+            "-en",
+            "en_us",
+            "pt-brazil1",
+        ] {
+            assert!(!is_sidecar_language(language), "{language}");
+        }
+    }
+
+    #[test]
+    fn formats_timestamps_to_the_nearest_centisecond() {
+        assert_eq!(format_timestamp(0), "00:00.00");
+        assert_eq!(format_timestamp(62_344), "01:02.34");
+        assert_eq!(format_timestamp(62_345), "01:02.35");
+        assert_eq!(format_timestamp(59_995), "01:00.00");
+        assert_eq!(format_timestamp(6_000_000), "100:00.00");
+    }
+
+    #[test]
+    fn builds_lrc_from_taps() {
+        let lrc = build_lrc("first\n\n second \n".into(), vec![1_000, 2_504]).unwrap();
+        assert_eq!(lrc, "[00:01.00]first\n[00:02.50]second\n");
+        assert_eq!(texts(&parse_lrc(&lrc).lines), ["first", "second"]);
+
+        assert!(build_lrc("first\nsecond".into(), vec![1_000]).is_err());
+        assert!(build_lrc("first\nsecond".into(), vec![2_000, 1_000]).is_err());
+    }
+
+    #[test]
+    fn nudges_line_and_word_stamps() {
+        let lrc = "[ar:Artist]\n[00:01.00]a\n[00:02.00]<00:02.00>b <00:02.50>c\n";
+        assert_eq!(
+            nudge_lrc(lrc.into(), 250, None),
+            "[ar:Artist]\n[00:01.25]a\n[00:02.25]<00:02.25>b <00:02.75>c\n"
+        );
+        assert_eq!(
+            nudge_lrc(lrc.into(), -500, Some(2)),
+            "[ar:Artist]\n[00:01.00]a\n[00:01.50]<00:01.50>b <00:02.00>c\n"
+        );
+    }
+
+    #[test]
+    fn shifts_only_timestamps() {
+        assert_eq!(shift_stamps("[00:01.00]a", -5_000), "[00:00.00]a");
+        assert_eq!(
+            shift_stamps("[Verse 1: Artist] <b> [00:01.00", 1_000),
+            "[Verse 1: Artist] <b> [00:01.00"
+        );
+        assert_eq!(shift_stamps("[00:01:50]a", 0), "[00:01.50]a");
+    }
+}
@@ -1,22 +1,691 @@
-use anyhow::Result;
-pub use lofty::{Accessor, Tag, TaggedFileExt};
+use crate::api::artwork::{find_art, ArtSource};
+use crate::api::library::FileStamp;
+use crate::api::path::TrackPath;
+use lofty::error::{ErrorKind, LoftyError};
+use lofty::id3::v2::{Frame, FrameValue, Id3v2Tag};
+use lofty::iff::aiff::AiffFile;
+use lofty::iff::wav::WavFile;
+use lofty::mpeg::MpegFile;
+use lofty::{
+    Accessor, AudioFile, FileProperties, FileType, ItemKey, MergeTag, ParseOptions, Probe,
+    SplitTag, Tag, TagExt, TaggedFile, TaggedFileExt,
+};
+use serde::{Deserialize, Serialize};
+use std::ffi::OsString;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::{fmt, fs, io};
 
 pub struct Metadata {
-    pub tag: Option<Tag>,
+    /// The front cover, or the picture closest to one.
     pub art: Option<Vec<u8>>,
+    pub art_source: Option<ArtSource>,
     pub title: Option<String>,
+    pub artist: Option<String>,
+    pub album: Option<String>,
+    pub album_artist: Option<String>,
+    pub track_number: Option<u32>,
+    pub track_total: Option<u32>,
+    pub disc_number: Option<u32>,
+    pub disc_total: Option<u32>,
+    pub year: Option<u32>,
+    pub genre: Option<String>,
+    pub composer: Option<String>,
+    pub comment: Option<String>,
+    pub title_sort: Option<String>,
+    pub artist_sort: Option<String>,
+    pub album_sort: Option<String>,
+    pub album_artist_sort: Option<String>,
+    pub properties: AudioProperties,
+    pub replay_gain: ReplayGain,
+    /// The file as it was read, hand this to `write_metadata` to detect concurrent changes.
+    pub stamp: FileStamp,
 }
 
-pub fn get_metadata(path: String) -> Result<Metadata> {
-    let tags = lofty::read_from_path(path)?;
-    let primary_tag = tags.primary_tag();
+/// Gains from `REPLAYGAIN_*` or Opus `R128_*` tags, all relative to the
+/// ReplayGain reference level of -18 LUFS.
+#[derive(Clone, Copy, Default)]
+pub struct ReplayGain {
+    pub track_gain_db: Option<f64>,
+    /// Linear, 1.0 is full scale.
+    pub track_peak: Option<f64>,
+    pub album_gain_db: Option<f64>,
+    pub album_peak: Option<f64>,
+}
+
+/// Stream properties read from the file headers, not the tags.
+pub struct AudioProperties {
+    pub duration_ms: u64,
+    /// Bitrate of the whole file in kbps, including tags and container overhead.
+    pub overall_bitrate: Option<u32>,
+    /// Bitrate of the audio stream alone in kbps.
+    pub audio_bitrate: Option<u32>,
+    pub sample_rate: Option<u32>,
+    /// Only known for lossless formats.
+    pub bit_depth: Option<u8>,
+    pub channels: Option<u8>,
+}
+
+impl AudioProperties {
+    fn new(properties: &FileProperties) -> Self {
+        Self {
+            duration_ms: properties.duration().as_millis() as u64,
+            overall_bitrate: properties.overall_bitrate(),
+            audio_bitrate: properties.audio_bitrate(),
+            sample_rate: properties.sample_rate(),
+            bit_depth: properties.bit_depth(),
+            channels: properties.channels(),
+        }
+    }
+}
+
+/// A partial tag update, fields left as `None` keep their current value.
+#[derive(Clone, Default)]
+pub struct MetadataChanges {
+    pub title: Option<String>,
+    pub artist: Option<String>,
+    pub album: Option<String>,
+    pub album_artist: Option<String>,
+    pub track_number: Option<u32>,
+    pub track_total: Option<u32>,
+    pub disc_number: Option<u32>,
+    pub disc_total: Option<u32>,
+    pub year: Option<u32>,
+    pub genre: Option<String>,
+    pub composer: Option<String>,
+    pub comment: Option<String>,
+    /// Unsynced lyrics, or a whole LRC file for players that read synced lyrics from tags.
+    pub lyrics: Option<String>,
+    /// Fields to remove from the tag, applied before the values above.
+    pub clear: Vec<MetadataField>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MetadataField {
+    Title,
+    Artist,
+    Album,
+    AlbumArtist,
+    TrackNumber,
+    TrackTotal,
+    DiscNumber,
+    DiscTotal,
+    Year,
+    Genre,
+    Composer,
+    Comment,
+    Lyrics,
+}
+
+impl MetadataField {
+    pub(crate) const ALL: [Self; 13] = [
+        Self::Title,
+        Self::Artist,
+        Self::Album,
+        Self::AlbumArtist,
+        Self::TrackNumber,
+        Self::TrackTotal,
+        Self::DiscNumber,
+        Self::DiscTotal,
+        Self::Year,
+        Self::Genre,
+        Self::Composer,
+        Self::Comment,
+        Self::Lyrics,
+    ];
+
+    /// The value as it is stored in `tag`, numbers are formatted as text.
+    pub(crate) fn read(self, tag: &Tag) -> Option<String> {
+        match self {
+            Self::Title => tag.title().map(|title| title.to_string()),
+            Self::Artist => tag.artist().map(|artist| artist.to_string()),
+            Self::Album => tag.album().map(|album| album.to_string()),
+            Self::AlbumArtist => tag.get_string(&ItemKey::AlbumArtist).map(str::to_string),
+            Self::TrackNumber => tag.track().map(|number| number.to_string()),
+            Self::TrackTotal => tag.track_total().map(|total| total.to_string()),
+            Self::DiscNumber => tag.disk().map(|number| number.to_string()),
+            Self::DiscTotal => tag.disk_total().map(|total| total.to_string()),
+            Self::Year => tag.year().map(|year| year.to_string()),
+            Self::Genre => tag.genre().map(|genre| genre.to_string()),
+            Self::Composer => tag.get_string(&ItemKey::Composer).map(str::to_string),
+            Self::Comment => tag.comment().map(|comment| comment.to_string()),
+            Self::Lyrics => tag.get_string(&ItemKey::Lyrics).map(str::to_string),
+        }
+    }
+}
+
+impl MetadataChanges {
+    /// Turns values captured with `MetadataField::read` back into changes,
+    /// missing values are cleared.
+    pub(crate) fn restore(values: Vec<(MetadataField, Option<String>)>) -> Self {
+        let mut changes = Self::default();
+        for (field, value) in values {
+            let Some(value) = value else {
+                changes.clear.push(field);
+                continue;
+            };
+            match field {
+                MetadataField::Title => changes.title = Some(value),
+                MetadataField::Artist => changes.artist = Some(value),
+                MetadataField::Album => changes.album = Some(value),
+                MetadataField::AlbumArtist => changes.album_artist = Some(value),
+                MetadataField::TrackNumber => changes.track_number = value.parse().ok(),
+                MetadataField::TrackTotal => changes.track_total = value.parse().ok(),
+                MetadataField::DiscNumber => changes.disc_number = value.parse().ok(),
+                MetadataField::DiscTotal => changes.disc_total = value.parse().ok(),
+                MetadataField::Year => changes.year = value.parse().ok(),
+                MetadataField::Genre => changes.genre = Some(value),
+                MetadataField::Composer => changes.composer = Some(value),
+                MetadataField::Comment => changes.comment = Some(value),
+                MetadataField::Lyrics => changes.lyrics = Some(value),
+            }
+        }
+        changes
+    }
+
+    pub(crate) fn apply(self, tag: &mut Tag) {
+        for field in self.clear {
+            match field {
+                MetadataField::Title => tag.remove_title(),
+                MetadataField::Artist => tag.remove_artist(),
+                MetadataField::Album => tag.remove_album(),
+                MetadataField::AlbumArtist => tag.remove_key(&ItemKey::AlbumArtist),
+                MetadataField::TrackNumber => tag.remove_track(),
+                MetadataField::TrackTotal => tag.remove_track_total(),
+                MetadataField::DiscNumber => tag.remove_disk(),
+                MetadataField::DiscTotal => tag.remove_disk_total(),
+                MetadataField::Year => tag.remove_year(),
+                MetadataField::Genre => tag.remove_genre(),
+                MetadataField::Composer => tag.remove_key(&ItemKey::Composer),
+                MetadataField::Comment => tag.remove_comment(),
+                MetadataField::Lyrics => tag.remove_key(&ItemKey::Lyrics),
+            }
+        }
+
+        if let Some(title) = self.title {
+            tag.set_title(title);
+        }
+        if let Some(artist) = self.artist {
+            tag.set_artist(artist);
+        }
+        if let Some(album) = self.album {
+            tag.set_album(album);
+        }
+        if let Some(album_artist) = self.album_artist {
+            tag.insert_text(ItemKey::AlbumArtist, album_artist);
+        }
+        if let Some(track_number) = self.track_number {
+            tag.set_track(track_number);
+        }
+        if let Some(track_total) = self.track_total {
+            tag.set_track_total(track_total);
+        }
+        if let Some(disc_number) = self.disc_number {
+            tag.set_disk(disc_number);
+        }
+        if let Some(disc_total) = self.disc_total {
+            tag.set_disk_total(disc_total);
+        }
+        if let Some(year) = self.year {
+            tag.set_year(year);
+        }
+        if let Some(genre) = self.genre {
+            tag.set_genre(genre);
+        }
+        if let Some(composer) = self.composer {
+            tag.insert_text(ItemKey::Composer, composer);
+        }
+        if let Some(comment) = self.comment {
+            tag.set_comment(comment);
+        }
+        if let Some(lyrics) = self.lyrics {
+            tag.insert_text(ItemKey::Lyrics, lyrics);
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum MetadataError {
+    NotFound,
+    UnsupportedFormat,
+    /// The format was recognized, but its tags or headers could not be parsed.
+    CorruptTag(String),
+    /// The file changed on disk since it was read, read it again before writing.
+    Modified,
+    Io(String),
+}
+
+impl fmt::Display for MetadataError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::NotFound => write!(f, "File not found!"),
+            Self::UnsupportedFormat => write!(f, "Unsupported audio format!"),
+            Self::CorruptTag(message) => write!(f, "Corrupt tag: {message}"),
+            Self::Modified => write!(f, "File was changed by another program!"),
+            Self::Io(message) => write!(f, "Could not read file: {message}"),
+        }
+    }
+}
 
-    let art = primary_tag.map(|tag| tag.pictures()[0].data().to_vec());
-    let title = primary_tag.map(|tag| tag.title().unwrap().to_string());
+impl std::error::Error for MetadataError {}
+
+impl From<LoftyError> for MetadataError {
+    fn from(err: LoftyError) -> Self {
+        match err.kind() {
+            ErrorKind::Io(err) if err.kind() == io::ErrorKind::NotFound => Self::NotFound,
+            ErrorKind::Io(err) => Self::Io(err.to_string()),
+            ErrorKind::UnknownFormat => Self::UnsupportedFormat,
+            _ => Self::CorruptTag(err.to_string()),
+        }
+    }
+}
+
+impl From<io::Error> for MetadataError {
+    fn from(err: io::Error) -> Self {
+        match err.kind() {
+            io::ErrorKind::NotFound => Self::NotFound,
+            _ => Self::Io(err.to_string()),
+        }
+    }
+}
+
+pub fn get_metadata(path: TrackPath) -> Result<Metadata, MetadataError> {
+    let path = path.to_path_buf();
+    // taken before reading, so a write racing the read is caught as a change
+    let stamp = stamp(&path)?;
+    let file = lofty::read_from_path(&path)?;
+
+    let tags = all_tags(&file);
+    let item = |key: ItemKey| first(&tags, |tag| tag.get_string(&key).map(str::to_string));
+
+    let (art, art_source) = find_art(&tags, &path).unzip();
 
     Ok(Metadata {
-        tag: primary_tag.cloned(),
         art,
-        title,
+        art_source,
+        title: first(&tags, |tag| tag.title().map(|title| title.to_string())),
+        artist: first(&tags, |tag| tag.artist().map(|artist| artist.to_string())),
+        album: first(&tags, |tag| tag.album().map(|album| album.to_string())),
+        album_artist: item(ItemKey::AlbumArtist),
+        track_number: first(&tags, |tag| tag.track()),
+        track_total: first(&tags, |tag| tag.track_total()),
+        disc_number: first(&tags, |tag| tag.disk()),
+        disc_total: first(&tags, |tag| tag.disk_total()),
+        year: first(&tags, year),
+        genre: first(&tags, |tag| tag.genre().map(|genre| genre.to_string())),
+        composer: item(ItemKey::Composer),
+        comment: first(&tags, |tag| tag.comment().map(|comment| comment.to_string())),
+        title_sort: item(ItemKey::TrackTitleSortOrder),
+        artist_sort: item(ItemKey::TrackArtistSortOrder),
+        album_sort: item(ItemKey::AlbumTitleSortOrder),
+        album_artist_sort: item(ItemKey::AlbumArtistSortOrder),
+        properties: AudioProperties::new(file.properties()),
+        replay_gain: replay_gain(&tags),
+        stamp,
     })
 }
+
+/// Applies `changes` to the primary tag of the file, creating one if there is none.
+///
+/// Fails with `MetadataError::Modified` unless the file still matches `expected`
+/// from `get_metadata`. Returns the stamp of the written file for follow-up edits.
+pub fn write_metadata(
+    path: TrackPath,
+    changes: MetadataChanges,
+    expected: FileStamp,
+) -> Result<FileStamp, MetadataError> {
+    edit_tag(&path.to_path_buf(), expected, |tag| changes.apply(tag))
+}
+
+pub(crate) fn edit_tag(
+    path: &Path,
+    expected: FileStamp,
+    edit: impl FnOnce(&mut Tag),
+) -> Result<FileStamp, MetadataError> {
+    check_unchanged(path, expected)?;
+
+    // the generic tag has no place for frames like PRIV or a described COMM,
+    // they only survive when the rest of the ID3v2 tag is merged back in
+    if let Some(id3v2) = id3v2_tag(path)? {
+        let (remainder, mut tag) = id3v2.split_tag();
+        edit(&mut tag);
+        save_atomically(path, &writable(remainder.merge_tag(tag)), expected)?;
+        return stamp(path);
+    }
+
+    let mut file = lofty::read_from_path(path)?;
+    if file.primary_tag().is_none() {
+        file.insert_tag(Tag::new(file.primary_tag_type()));
+    }
+    let tag = file
+        .primary_tag_mut()
+        .ok_or(MetadataError::UnsupportedFormat)?;
+    edit(tag);
+
+    save_atomically(path, tag, expected)?;
+    stamp(path)
+}
+
+// lofty edits files in place, a crash halfway through would leave a broken file,
+// so the tag is written into a copy that then replaces the original
+pub(crate) fn save_atomically(
+    path: &Path,
+    tag: &impl TagExt<Err = LoftyError>,
+    expected: FileStamp,
+) -> Result<(), MetadataError> {
+    let temp = temp_path(path);
+    let result = fs::copy(path, &temp)
+        .map_err(MetadataError::from)
+        .and_then(|_| Ok(tag.save_to_path(&temp)?))
+        // checked again right before the swap, copying a large file takes a while
+        .and_then(|_| check_unchanged(path, expected))
+        .and_then(|_| Ok(fs::rename(&temp, path)?));
+
+    if result.is_err() {
+        let _ = fs::remove_file(&temp);
+    }
+    result
+}
+
+/// The ID3v2 tag of an MPEG, AIFF or WAV file with every frame, including
+/// the ones lofty leaves out of the generic `Tag`.
+pub(crate) fn id3v2_tag(path: &Path) -> Result<Option<Id3v2Tag>, MetadataError> {
+    let file_type = Probe::open(path)?.guess_file_type()?.file_type();
+    let options = ParseOptions::new().read_properties(false);
+    let mut reader = File::open(path)?;
+
+    Ok(match file_type {
+        Some(FileType::Mpeg) => MpegFile::read_from(&mut reader, options)?.remove_id3v2(),
+        Some(FileType::Aiff) => AiffFile::read_from(&mut reader, options)?.remove_id3v2(),
+        Some(FileType::Wav) => WavFile::read_from(&mut reader, options)?.remove_id3v2(),
+        _ => None,
+    })
+}
+
+// lofty reads these frames but refuses to write them back, as raw bytes
+// they go through unchanged
+fn writable(mut tag: Id3v2Tag) -> Id3v2Tag {
+    fn binary(frame: &Frame) -> Option<Frame<'static>> {
+        let data = match frame.content() {
+            FrameValue::Private(private) => private.as_bytes(),
+            FrameValue::RelativeVolumeAdjustment(volume) => volume.as_bytes(),
+            FrameValue::EventTimingCodes(timing) => timing.as_bytes(),
+            FrameValue::Ownership(ownership) => ownership.as_bytes().ok()?,
+            _ => return None,
+        };
+        let id = frame.id_str().to_string();
+        Frame::new(id, FrameValue::Binary(data), *frame.flags()).ok()
+    }
+
+    let converted: Vec<_> = (&tag).into_iter().filter_map(binary).collect();
+    tag.retain(|frame| binary(frame).is_none());
+    for frame in converted {
+        tag.insert(frame);
+    }
+    tag
+}
+
+// hidden, so scans and the watcher ignore it
+pub(crate) fn temp_path(path: &Path) -> PathBuf {
+    let mut name = OsString::from(".");
+    name.push(path.file_name().unwrap_or_default());
+    name.push(".tmp");
+    path.with_file_name(name)
+}
+
+pub(crate) fn stamp(path: &Path) -> Result<FileStamp, MetadataError> {
+    FileStamp::of(path).map_err(|err| match err.downcast::<io::Error>() {
+        Ok(err) => err.into(),
+        Err(err) => MetadataError::Io(err.to_string()),
+    })
+}
+
+pub(crate) fn check_unchanged(path: &Path, expected: FileStamp) -> Result<(), MetadataError> {
+    if stamp(path)? != expected {
+        return Err(MetadataError::Modified);
+    }
+    Ok(())
+}
+
+/// Reads only the stream properties, skipping the artwork and tags.
+pub fn get_audio_properties(path: TrackPath) -> Result<AudioProperties, MetadataError> {
+    let file = lofty::read_from_path(path.to_path_buf())?;
+    Ok(AudioProperties::new(file.properties()))
+}
+
+// files can carry several tags (e.g. ID3v2 and APE), the primary one gets
+// the first say and the others fill in whatever it lacks
+pub(crate) fn all_tags(file: &TaggedFile) -> Vec<&Tag> {
+    let primary = file.primary_tag();
+    primary
+        .into_iter()
+        .chain(
+            file.tags()
+                .iter()
+                .filter(|tag| Some(tag.tag_type()) != primary.map(Tag::tag_type)),
+        )
+        .collect()
+}
+
+fn first<T>(tags: &[&Tag], read: impl Fn(&Tag) -> Option<T>) -> Option<T> {
+    tags.iter().find_map(|tag| read(tag))
+}
+
+// lofty maps the usual spellings to its own keys, anything else (lowercase
+// TXXX descriptions, MP4 freeform atoms) ends up as an unknown key
+fn replay_gain(tags: &[&Tag]) -> ReplayGain {
+    let find = |names: &[&str], key: Option<ItemKey>| {
+        first(tags, |tag| {
+            if let Some(value) = key.as_ref().and_then(|key| tag.get_string(key)) {
+                return Some(value.to_string());
+            }
+            tag.items().find_map(|item| {
+                let ItemKey::Unknown(name) = item.key() else {
+                    return None;
+                };
+                // `----:com.apple.iTunes:replaygain_track_gain` for MP4
+                let name = name.rsplit(':').next().unwrap_or_default().to_lowercase();
+                names
+                    .contains(&name.as_str())
+                    .then(|| item.value().text().map(str::to_string))
+                    .flatten()
+            })
+        })
+    };
+    let gain = |names: &[&str], key| find(names, key).as_deref().and_then(parse_gain);
+    let peak = |names: &[&str], key| find(names, key).and_then(|value| value.trim().parse().ok());
+    // Q7.8 fixed point relative to -23 LUFS, 5 dB below the ReplayGain reference
+    let r128 = |names: &[&str]| {
+        find(names, None)
+            .and_then(|value| value.trim().parse::<i16>().ok())
+            .map(|gain| gain as f64 / 256.0 + 5.0)
+    };
+
+    ReplayGain {
+        track_gain_db: gain(&["replaygain_track_gain"], Some(ItemKey::ReplayGainTrackGain))
+            .or_else(|| r128(&["r128_track_gain"])),
+        track_peak: peak(&["replaygain_track_peak"], Some(ItemKey::ReplayGainTrackPeak)),
+        album_gain_db: gain(&["replaygain_album_gain"], Some(ItemKey::ReplayGainAlbumGain))
+            .or_else(|| r128(&["r128_album_gain"])),
+        album_peak: peak(&["replaygain_album_peak"], Some(ItemKey::ReplayGainAlbumPeak)),
+    }
+}
+
+// `-3.21 dB`, `+1.5dB` and bare numbers all show up in the wild
+fn parse_gain(value: &str) -> Option<f64> {
+    let value = value.trim().replace('\u{2212}', "-");
+    let number = value
+        .strip_suffix("dB")
+        .or_else(|| value.strip_suffix("db"))
+        .or_else(|| value.strip_suffix("DB"))
+        .unwrap_or(&value);
+    number.trim().parse().ok().filter(|gain: &f64| gain.is_finite())
+}
+
+// ID3v2.4 and most Vorbis comments only carry a full recording date
+fn year(tag: &Tag) -> Option<u32> {
+    tag.year().or_else(|| {
+        tag.get_string(&ItemKey::RecordingDate)?
+            .get(..4)?
+            .parse()
+            .ok()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lofty::{ItemValue, TagItem, TagType};
+
+    fn tag(tag_type: TagType, items: &[(ItemKey, &str)]) -> Tag {
+        let mut tag = Tag::new(tag_type);
+        for (key, value) in items {
+            tag.insert_unchecked(TagItem::new(
+                key.clone(),
+                ItemValue::Text(value.to_string()),
+            ));
+        }
+        tag
+    }
+
+    fn unknown(name: &str) -> ItemKey {
+        ItemKey::Unknown(name.to_string())
+    }
+
+    // a tenth of a second of silence
+    fn write_wav(path: &Path) {
+        let data = vec![0u8; 1600];
+        let mut wav = Vec::new();
+        wav.extend_from_slice(b"RIFF");
+        wav.extend_from_slice(&(36 + data.len() as u32).to_le_bytes());
+        wav.extend_from_slice(b"WAVEfmt ");
+        wav.extend_from_slice(&16u32.to_le_bytes());
+        wav.extend_from_slice(&1u16.to_le_bytes());
+        wav.extend_from_slice(&1u16.to_le_bytes());
+        wav.extend_from_slice(&8000u32.to_le_bytes());
+        wav.extend_from_slice(&16000u32.to_le_bytes());
+        wav.extend_from_slice(&2u16.to_le_bytes());
+        wav.extend_from_slice(&16u16.to_le_bytes());
+        wav.extend_from_slice(b"data");
+        wav.extend_from_slice(&(data.len() as u32).to_le_bytes());
+        wav.extend_from_slice(&data);
+        fs::write(path, wav).unwrap();
+    }
+
+    #[test]
+    fn parses_gains() {
+        assert_eq!(parse_gain("-3.21 dB"), Some(-3.21));
+        assert_eq!(parse_gain("+1.5dB"), Some(1.5));
+        assert_eq!(parse_gain(" 2 DB "), Some(2.0));
+        assert_eq!(parse_gain("0.5"), Some(0.5));
+        assert_eq!(parse_gain("\u{2212}4 dB"), Some(-4.0));
+        assert_eq!(parse_gain("loud"), None);
+        assert_eq!(parse_gain("inf dB"), None);
+        assert_eq!(parse_gain("NaN"), None);
+    }
+
+    #[test]
+    fn reads_replay_gain() {
+        let tag = tag(
+            TagType::VorbisComments,
+            &[
+                (ItemKey::ReplayGainTrackGain, "-6.5 dB"),
+                (ItemKey::ReplayGainTrackPeak, "0.98"),
+                (ItemKey::ReplayGainAlbumGain, "-7 dB"),
+            ],
+        );
+        let gain = replay_gain(&[&tag]);
+        assert_eq!(gain.track_gain_db, Some(-6.5));
+        assert_eq!(gain.track_peak, Some(0.98));
+        assert_eq!(gain.album_gain_db, Some(-7.0));
+        assert_eq!(gain.album_peak, None);
+    }
+
+    #[test]
+    fn reads_replay_gain_from_freeform_atoms() {
+        let tag = tag(
+            TagType::Mp4Ilst,
+            &[(
+                unknown("----:com.apple.iTunes:REPLAYGAIN_TRACK_GAIN"),
+                "-2.00 dB",
+            )],
+        );
+        assert_eq!(replay_gain(&[&tag]).track_gain_db, Some(-2.0));
+    }
+
+    #[test]
+    fn converts_r128_gain_to_the_replay_gain_reference() {
+        // Q7.8 relative to -23 LUFS, ReplayGain aims 5 dB louder
+        let r128 = tag(
+            TagType::VorbisComments,
+            &[
+                (unknown("R128_TRACK_GAIN"), "-1280"),
+                (unknown("R128_ALBUM_GAIN"), "384"),
+            ],
+        );
+        let gain = replay_gain(&[&r128]);
+        assert_eq!(gain.track_gain_db, Some(0.0));
+        assert_eq!(gain.album_gain_db, Some(6.5));
+    }
+
+    #[test]
+    fn prefers_replay_gain_over_r128() {
+        let both = tag(
+            TagType::VorbisComments,
+            &[
+                (unknown("R128_TRACK_GAIN"), "256"),
+                (ItemKey::ReplayGainTrackGain, "-3 dB"),
+            ],
+        );
+        assert_eq!(replay_gain(&[&both]).track_gain_db, Some(-3.0));
+    }
+
+    #[test]
+    fn keeps_id3v2_frames_without_a_generic_item() {
+        use lofty::id3::v2::{CommentFrame, FrameFlags, PrivateFrame};
+        use lofty::TextEncoding;
+
+        let path = std::env::temp_dir().join(format!("amai-edit-{}.wav", std::process::id()));
+        write_wav(&path);
+
+        let mut id3v2 = Id3v2Tag::new();
+        id3v2.set_title("Old".to_string());
+        let private = PrivateFrame {
+            owner: "player".to_string(),
+            private_data: vec![1, 2, 3],
+        };
+        let comment = CommentFrame {
+            encoding: TextEncoding::UTF8,
+            language: *b"eng",
+            description: "iTunNORM".to_string(),
+            content: "00000200".to_string(),
+        };
+        let flags = FrameFlags::default();
+        id3v2.insert(Frame::new("PRIV", FrameValue::Private(private), flags).unwrap());
+        id3v2.insert(Frame::new("COMM", FrameValue::Comment(comment), flags).unwrap());
+        // lofty can't write a PRIV frame on its own either
+        writable(id3v2).save_to_path(&path).unwrap();
+
+        let changes = MetadataChanges {
+            title: Some("New".to_string()),
+            artist: Some("Artist".to_string()),
+            ..Default::default()
+        };
+        let expected = stamp(&path).unwrap();
+        edit_tag(&path, expected, |tag| changes.apply(tag)).unwrap();
+
+        let id3v2 = id3v2_tag(&path).unwrap().unwrap();
+        let _ = fs::remove_file(&path);
+        assert_eq!(id3v2.title().as_deref(), Some("New"));
+        assert_eq!(id3v2.artist().as_deref(), Some("Artist"));
+        let frames: Vec<_> = id3v2.into_iter().collect();
+        assert!(frames.iter().any(|frame| matches!(
+            frame.content(),
+            FrameValue::Private(frame) if frame.private_data == [1, 2, 3]
+        )));
+        assert!(frames.iter().any(|frame| matches!(
+            frame.content(),
+            FrameValue::Comment(frame) if frame.description == "iTunNORM"
+        )));
+    }
+}
@@ -4,3 +4,16 @@
 
 pub mod utils;
 pub mod metadata;
+pub mod config;
+pub mod library;
+pub mod path;
+pub mod scan;
+pub mod watcher;
+pub mod batch_edit;
+pub mod artwork;
+pub mod thumbnail;
+pub mod palette;
+pub mod lyrics;
+pub mod loudness;
+mod ignore_rules;
+mod track_ids;
@@ -0,0 +1,249 @@
+use crate::api::config::project_dirs;
+use crate::api::path::TrackPath;
+use crate::api::thumbnail::{art_hash, read_art, thumbnail, write_cached};
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+const PALETTE_DIR: &str = "palettes";
+// plenty of pixels for picking colors, and cheap to quantize
+const SAMPLE_SIZE: u32 = 64;
+const MAX_BOXES: usize = 16;
+
+#[derive(Clone, Copy, Serialize, Deserialize)]
+pub struct Swatch {
+    /// `0xAARRGGBB`, ready for Dart's `Color`.
+    pub color: u32,
+    /// Black or white, whichever is easier to read on `color`.
+    pub foreground: u32,
+    /// Share of the artwork's pixels close to this color, from 0 to 1.
+    pub population: f64,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Palette {
+    /// The most common color.
+    pub dominant: Swatch,
+    /// A saturated color of medium lightness, `None` for grayscale artwork.
+    pub vibrant: Option<Swatch>,
+    /// A desaturated color of medium lightness.
+    pub muted: Option<Swatch>,
+}
+
+/// Picks colors from the artwork of a track for tinting the player, or
+/// `None` if the track has no artwork.
+///
+/// Palettes are cached by artwork, so all tracks of an album share one.
+pub fn get_palette(path: TrackPath) -> Result<Option<Palette>> {
+    let Some(art) = read_art(&path.to_path_buf())? else {
+        return Ok(None);
+    };
+
+    let cached = cache_dir()?.join(format!("{}.json", art_hash(&art)));
+    if let Some(palette) = fs::read_to_string(&cached)
+        .ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+    {
+        return Ok(Some(palette));
+    }
+
+    let sample = thumbnail(&art, SAMPLE_SIZE)?;
+    let pixels: Vec<[u8; 3]> = image::load_from_memory(&sample.data)
+        .context("Could not decode artwork!")?
+        .to_rgb8()
+        .pixels()
+        .map(|pixel| pixel.0)
+        .collect();
+    let Some(palette) = palette(pixels) else {
+        return Ok(None);
+    };
+
+    write_cached(&cached, &serde_json::to_vec(&palette)?)?;
+    Ok(Some(palette))
+}
+
+fn palette(pixels: Vec<[u8; 3]>) -> Option<Palette> {
+    let total = pixels.len() as f64;
+    let swatches: Vec<_> = median_cut(pixels)
+        .into_iter()
+        .map(|pixels| swatch(&pixels, total))
+        .collect();
+
+    let dominant = *swatches
+        .iter()
+        .max_by(|a, b| a.population.total_cmp(&b.population))?;
+    let best = |score: fn(f64, f64) -> f64| {
+        swatches
+            .iter()
+            .map(|swatch| {
+                let (saturation, lightness) = saturation_lightness(swatch.color);
+                (score(saturation, lightness) * swatch.population.sqrt(), swatch)
+            })
+            .filter(|(score, _)| *score > 0.0)
+            .max_by(|(a, _), (b, _)| a.total_cmp(b))
+            .map(|(_, swatch)| *swatch)
+    };
+
+    Some(Palette {
+        dominant,
+        vibrant: best(|saturation, lightness| {
+            if saturation < 0.35 {
+                return 0.0;
+            }
+            saturation * (1.0 - (lightness - 0.5).abs() * 2.0)
+        }),
+        muted: best(|saturation, lightness| {
+            if saturation >= 0.35 || !(0.2..=0.8).contains(&lightness) {
+                return 0.0;
+            }
+            1.0 - (lightness - 0.5).abs() * 2.0
+        }),
+    })
+}
+
+// splits the pixels into boxes of similar colors, always halving the box with
+// the widest spread at its median, which is deterministic for the same artwork
+fn median_cut(pixels: Vec<[u8; 3]>) -> Vec<Vec<[u8; 3]>> {
+    let mut boxes = vec![pixels];
+    while boxes.len() < MAX_BOXES {
+        let Some((index, channel)) = boxes
+            .iter()
+            .enumerate()
+            .filter(|(_, pixels)| pixels.len() > 1)
+            .map(|(index, pixels)| {
+                let (channel, range) = widest_channel(pixels);
+                (index, channel, range)
+            })
+            .filter(|(_, _, range)| *range > 0)
+            .max_by_key(|(index, _, range)| (*range, usize::MAX - index))
+            .map(|(index, channel, _)| (index, channel))
+        else {
+            break;
+        };
+
+        let mut pixels = boxes.swap_remove(index);
+        pixels.sort_by_key(|pixel| pixel[channel]);
+        let upper = pixels.split_off(pixels.len() / 2);
+        boxes.push(pixels);
+        boxes.push(upper);
+    }
+    boxes.retain(|pixels| !pixels.is_empty());
+    boxes
+}
+
+fn widest_channel(pixels: &[[u8; 3]]) -> (usize, u8) {
+    (0..3)
+        .map(|channel| {
+            let values = pixels.iter().map(|pixel| pixel[channel]);
+            let range = values.clone().max().unwrap_or(0) - values.min().unwrap_or(0);
+            (channel, range)
+        })
+        .max_by_key(|(channel, range)| (*range, 2 - channel))
+        .unwrap_or((0, 0))
+}
+
+fn swatch(pixels: &[[u8; 3]], total: f64) -> Swatch {
+    let mut sum = [0u64; 3];
+    for pixel in pixels {
+        for (sum, value) in sum.iter_mut().zip(pixel) {
+            *sum += *value as u64;
+        }
+    }
+    let count = pixels.len().max(1) as u64;
+    let [r, g, b] = sum.map(|sum| (sum / count) as u32);
+    let color = 0xFF00_0000 | (r << 16) | (g << 8) | b;
+
+    Swatch {
+        color,
+        foreground: foreground(color),
+        population: pixels.len() as f64 / total,
+    }
+}
+
+// picks the text color with the higher WCAG contrast ratio
+fn foreground(color: u32) -> u32 {
+    let luminance = relative_luminance(color);
+    let on_white = 1.05 / (luminance + 0.05);
+    let on_black = (luminance + 0.05) / 0.05;
+    if on_white >= on_black {
+        0xFFFF_FFFF
+    } else {
+        0xFF00_0000
+    }
+}
+
+fn relative_luminance(color: u32) -> f64 {
+    let [r, g, b] = channels(color).map(|channel| {
+        if channel <= 0.03928 {
+            channel / 12.92
+        } else {
+            ((channel + 0.055) / 1.055).powf(2.4)
+        }
+    });
+    0.2126 * r + 0.7152 * g + 0.0722 * b
+}
+
+fn saturation_lightness(color: u32) -> (f64, f64) {
+    let [r, g, b] = channels(color);
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let lightness = (max + min) / 2.0;
+    let saturation = if max == min {
+        0.0
+    } else {
+        (max - min) / (1.0 - (2.0 * lightness - 1.0).abs())
+    };
+    (saturation, lightness)
+}
+
+fn channels(color: u32) -> [f64; 3] {
+    [16, 8, 0].map(|shift| ((color >> shift) & 0xFF) as f64 / 255.0)
+}
+
+fn cache_dir() -> Result<PathBuf> {
+    Ok(project_dirs()?.cache_dir().join(PALETTE_DIR))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_colors_into_boxes() {
+        let pixels = [[255, 0, 0], [250, 5, 0], [0, 0, 255], [0, 10, 250]].repeat(8);
+        let boxes = median_cut(pixels.clone());
+        // stops once every box holds a single color
+        assert_eq!(boxes.len(), 4);
+        assert!(boxes
+            .iter()
+            .all(|pixels| pixels.iter().all(|pixel| *pixel == pixels[0])));
+        assert_eq!(boxes.iter().map(Vec::len).sum::<usize>(), pixels.len());
+        assert_eq!(median_cut(pixels), boxes);
+
+        let gradient: Vec<_> = (0..=255).map(|value| [value, 0, 0]).collect();
+        assert_eq!(median_cut(gradient).len(), MAX_BOXES);
+
+        // a flat image has nothing to split
+        assert_eq!(median_cut(vec![[7, 7, 7]; 10]), [vec![[7, 7, 7]; 10]]);
+        assert!(median_cut(Vec::new()).is_empty());
+    }
+
+    #[test]
+    fn picks_readable_foreground() {
+        assert_eq!(foreground(0xFF00_0000), 0xFFFF_FFFF);
+        assert_eq!(foreground(0xFFFF_FFFF), 0xFF00_0000);
+        assert_eq!(foreground(0xFF00_0080), 0xFFFF_FFFF);
+        assert_eq!(foreground(0xFFFF_FF00), 0xFF00_0000);
+    }
+
+    #[test]
+    fn computes_saturation_and_lightness() {
+        let close =
+            |(a, b): (f64, f64), (c, d): (f64, f64)| (a - c).abs() < 1e-3 && (b - d).abs() < 1e-3;
+        assert!(close(saturation_lightness(0xFFFF_0000), (1.0, 0.5)));
+        assert!(close(saturation_lightness(0xFF80_8080), (0.0, 0.502)));
+        assert!(close(saturation_lightness(0xFFFF_FFFF), (0.0, 1.0)));
+        assert!(close(saturation_lightness(0xFF40_2020), (0.333, 0.188)));
+    }
+}
@@ -0,0 +1,115 @@
+use crate::api::config::project_dirs;
+use anyhow::{Context, Result};
+use std::collections::hash_map::DefaultHasher;
+use std::ffi::OsString;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+
+/// A file path exactly as the OS stores it, so names that aren't valid UTF-8
+/// survive the trip to Dart and back.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct TrackPath {
+    /// The raw OS path, hand this back to Rust as is.
+    pub raw: Vec<u8>,
+    /// Lossy UTF-8 version of the path, only meant for showing to the user.
+    pub display: String,
+}
+
+impl TrackPath {
+    pub(crate) fn new(path: &Path) -> Self {
+        Self {
+            raw: to_raw(path.as_os_str().to_owned()),
+            display: path.to_string_lossy().to_string(),
+        }
+    }
+
+    pub(crate) fn from_raw(raw: Vec<u8>) -> Self {
+        Self::new(&PathBuf::from(from_raw(raw)))
+    }
+
+    pub(crate) fn to_path_buf(&self) -> PathBuf {
+        PathBuf::from(from_raw(self.raw.clone()))
+    }
+}
+
+#[cfg(unix)]
+pub(crate) fn to_raw(path: OsString) -> Vec<u8> {
+    use std::os::unix::ffi::OsStringExt;
+    path.into_vec()
+}
+
+#[cfg(unix)]
+pub(crate) fn from_raw(raw: Vec<u8>) -> OsString {
+    use std::os::unix::ffi::OsStringExt;
+    OsString::from_vec(raw)
+}
+
+// windows paths are UTF-16 that may contain unpaired surrogates, keep the code units as they are
+#[cfg(windows)]
+pub(crate) fn to_raw(path: OsString) -> Vec<u8> {
+    use std::os::windows::ffi::OsStrExt;
+    path.encode_wide().flat_map(u16::to_le_bytes).collect()
+}
+
+#[cfg(windows)]
+pub(crate) fn from_raw(raw: Vec<u8>) -> OsString {
+    use std::os::windows::ffi::OsStringExt;
+    let wide: Vec<u16> = raw
+        .chunks_exact(2)
+        .map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+        .collect();
+    OsString::from_wide(&wide)
+}
+
+#[cfg(not(any(unix, windows)))]
+pub(crate) fn to_raw(path: OsString) -> Vec<u8> {
+    path.to_string_lossy().as_bytes().to_vec()
+}
+
+#[cfg(not(any(unix, windows)))]
+pub(crate) fn from_raw(raw: Vec<u8>) -> OsString {
+    String::from_utf8_lossy(&raw).to_string().into()
+}
+
+/// Returns a path the Dart side can open for playback.
+///
+/// Dart strings can't hold paths that aren't valid UTF-8, those get a link
+/// with a UTF-8 name in the cache folder instead.
+pub fn playback_path(path: TrackPath) -> Result<String> {
+    let path = path.to_path_buf();
+    if let Some(path) = path.to_str() {
+        return Ok(path.to_string());
+    }
+
+    let mut hasher = DefaultHasher::new();
+    path.hash(&mut hasher);
+    let extension = path
+        .extension()
+        .map(|ext| ext.to_string_lossy().to_string())
+        .unwrap_or_default();
+
+    let dir = project_dirs()?.cache_dir().join("playback");
+    fs::create_dir_all(&dir)?;
+    let link = dir.join(format!("{:016x}.{extension}", hasher.finish()));
+    if fs::read_link(&link).is_ok_and(|target| target == path) {
+        return link_to_string(link);
+    }
+
+    // stale or colliding link from an earlier run
+    let _ = fs::remove_file(&link);
+    #[cfg(unix)]
+    std::os::unix::fs::symlink(&path, &link)?;
+    // symlinks need extra privileges on windows, hard links don't
+    #[cfg(not(unix))]
+    fs::hard_link(&path, &link)?;
+
+    link_to_string(link)
+}
+
+fn link_to_string(link: PathBuf) -> Result<String> {
+    link.into_os_string()
+        .into_string()
+        .ok()
+        .context("Cache folder path is not valid UTF-8!")
+}
@@ -0,0 +1,283 @@
+use crate::api::library::{self, LibraryDiff};
+use crate::api::path::TrackPath;
+use crate::api::utils::{scan_music_files, Track};
+use crate::frb_generated::StreamSink;
+use anyhow::Result;
+use flutter_rust_bridge::frb;
+use lofty::error::{ErrorKind, LoftyError};
+use std::collections::HashSet;
+use std::fmt;
+use std::io;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, Instant};
+
+// often enough for a smooth progress bar without flooding the isolate
+const REPORT_INTERVAL: Duration = Duration::from_millis(100);
+
+static SCAN_LOCK: Mutex<()> = Mutex::new(());
+
+#[frb(opaque)]
+#[derive(Default)]
+pub struct ScanCancelToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl ScanCancelToken {
+    #[frb(sync)]
+    pub fn new() -> ScanCancelToken {
+        Self::default()
+    }
+
+    #[frb(sync)]
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+}
+
+#[derive(Clone, Copy)]
+pub enum ScanPhase {
+    /// Walking the library folders, only `files_seen` moves.
+    Discovering,
+    /// Sniffing which of the files are audio.
+    Detecting,
+    /// Reading tags of new and changed files.
+    ReadingTags,
+    /// Writing the results to the library database.
+    Saving,
+}
+
+pub struct ScanProgress {
+    pub phase: ScanPhase,
+    pub files_seen: usize,
+    /// How much of the current phase is done, out of `files_total`.
+    pub files_done: usize,
+    pub files_total: usize,
+    pub current_path: Option<String>,
+}
+
+/// Why a file in a library folder did not make it into the library.
+#[derive(Clone)]
+pub enum SkipReason {
+    PermissionDenied,
+    /// Looks like audio, but isn't a format we can read.
+    UnsupportedFormat,
+    /// The format was recognized, but the file could not be parsed.
+    CorruptHeader,
+    /// A symlink pointing back to one of its own parent folders.
+    SymlinkLoop,
+    Io(String),
+}
+
+impl SkipReason {
+    pub(crate) fn from_io(err: &io::Error) -> Self {
+        match err.kind() {
+            io::ErrorKind::PermissionDenied => Self::PermissionDenied,
+            _ => Self::Io(err.to_string()),
+        }
+    }
+
+    pub(crate) fn from_lofty(err: &LoftyError) -> Self {
+        match err.kind() {
+            ErrorKind::Io(err) => Self::from_io(err),
+            ErrorKind::UnknownFormat => Self::UnsupportedFormat,
+            _ => Self::CorruptHeader,
+        }
+    }
+
+    pub(crate) fn from_walkdir(err: &walkdir::Error) -> Self {
+        if err.loop_ancestor().is_some() {
+            return Self::SymlinkLoop;
+        }
+        match err.io_error() {
+            Some(err) => Self::from_io(err),
+            None => Self::Io(err.to_string()),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct SkippedFile {
+    pub path: TrackPath,
+    pub reason: SkipReason,
+}
+
+pub struct ScanReport {
+    pub tracks: Vec<Track>,
+    pub skipped: Vec<SkippedFile>,
+}
+
+pub enum ScanEvent {
+    Progress(ScanProgress),
+    Skipped(SkippedFile),
+    Finished(LibraryDiff),
+    Cancelled,
+}
+
+/// Scans and stores the library like `get_music_files`, but also reports every
+/// file that was left out and why.
+pub fn scan_library(max_depth: Option<usize>) -> Result<ScanReport> {
+    let _lock = scan_lock();
+    let context = ScanContext::silent();
+    let mut tracks = scan_music_files(max_depth, &context)?;
+    library::sync(tracks.clone(), &context)?;
+
+    let skipped = context.take_skipped();
+    let skipped_paths: HashSet<_> = skipped.iter().map(|file| &file.path).collect();
+    tracks.retain(|track| !skipped_paths.contains(&track.path));
+
+    Ok(ScanReport { tracks, skipped })
+}
+
+/// Scans the library like `rescan_library`, reading files on all cores and
+/// streaming progress along the way. Stops early once `token` is cancelled.
+pub fn scan_library_with_progress(
+    sink: StreamSink<ScanEvent>,
+    token: &ScanCancelToken,
+    max_depth: Option<usize>,
+) -> Result<()> {
+    let _lock = scan_lock();
+    let context = ScanContext::reporting(sink, token.cancelled.clone());
+
+    let event = match scan_music_files(max_depth, &context)
+        .and_then(|tracks| library::sync(tracks, &context))
+    {
+        Ok(diff) => ScanEvent::Finished(diff),
+        Err(err) if err.is::<Cancelled>() => ScanEvent::Cancelled,
+        Err(err) => return Err(err),
+    };
+    context.send(event);
+
+    Ok(())
+}
+
+/// Held across scanning and storing the result, by every scan including the
+/// watcher's.
+///
+/// Each scan rewrites the track ids as a whole, two at once would hand out
+/// the same id twice.
+pub(crate) fn scan_lock() -> MutexGuard<'static, ()> {
+    // a scan that panicked left nothing half written, its transactions rolled back
+    SCAN_LOCK.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+#[derive(Debug)]
+pub(crate) struct Cancelled;
+
+impl fmt::Display for Cancelled {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Scan was cancelled!")
+    }
+}
+
+impl std::error::Error for Cancelled {}
+
+/// Progress and cancellation shared by every step of a scan.
+///
+/// Scans that nobody watches use `ScanContext::silent`, which never reports
+/// and can't be cancelled.
+pub(crate) struct ScanContext {
+    sink: Option<Mutex<StreamSink<ScanEvent>>>,
+    cancelled: Arc<AtomicBool>,
+    phase: Mutex<ScanPhase>,
+    seen: AtomicUsize,
+    done: AtomicUsize,
+    total: AtomicUsize,
+    skipped: Mutex<Vec<SkippedFile>>,
+    last_report: Mutex<Instant>,
+}
+
+impl ScanContext {
+    pub(crate) fn silent() -> Self {
+        Self::new(None, Arc::new(AtomicBool::new(false)))
+    }
+
+    fn reporting(sink: StreamSink<ScanEvent>, cancelled: Arc<AtomicBool>) -> Self {
+        Self::new(Some(Mutex::new(sink)), cancelled)
+    }
+
+    fn new(sink: Option<Mutex<StreamSink<ScanEvent>>>, cancelled: Arc<AtomicBool>) -> Self {
+        Self {
+            sink,
+            cancelled,
+            phase: Mutex::new(ScanPhase::Discovering),
+            seen: AtomicUsize::new(0),
+            done: AtomicUsize::new(0),
+            total: AtomicUsize::new(0),
+            skipped: Mutex::new(Vec::new()),
+            last_report: Mutex::new(Instant::now()),
+        }
+    }
+
+    pub(crate) fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn check(&self) -> Result<()> {
+        if self.is_cancelled() {
+            return Err(Cancelled.into());
+        }
+        Ok(())
+    }
+
+    pub(crate) fn phase(&self, phase: ScanPhase, total: usize) {
+        *self.phase.lock().unwrap() = phase;
+        self.done.store(0, Ordering::Relaxed);
+        self.total.store(total, Ordering::Relaxed);
+        self.report(None, true);
+    }
+
+    pub(crate) fn seen(&self, path: &Path) {
+        self.seen.fetch_add(1, Ordering::Relaxed);
+        self.report(Some(path), false);
+    }
+
+    pub(crate) fn done(&self, path: &Path) {
+        self.done.fetch_add(1, Ordering::Relaxed);
+        self.report(Some(path), false);
+    }
+
+    pub(crate) fn skip(&self, path: &Path, reason: SkipReason) {
+        let file = SkippedFile {
+            path: TrackPath::new(path),
+            reason,
+        };
+        self.skipped.lock().unwrap().push(file.clone());
+        self.send(ScanEvent::Skipped(file));
+    }
+
+    pub(crate) fn take_skipped(&self) -> Vec<SkippedFile> {
+        std::mem::take(&mut *self.skipped.lock().unwrap())
+    }
+
+    fn report(&self, path: Option<&Path>, force: bool) {
+        if self.sink.is_none() {
+            return;
+        }
+
+        {
+            let mut last_report = self.last_report.lock().unwrap();
+            if !force && last_report.elapsed() < REPORT_INTERVAL {
+                return;
+            }
+            *last_report = Instant::now();
+        }
+
+        self.send(ScanEvent::Progress(ScanProgress {
+            phase: *self.phase.lock().unwrap(),
+            files_seen: self.seen.load(Ordering::Relaxed),
+            files_done: self.done.load(Ordering::Relaxed),
+            files_total: self.total.load(Ordering::Relaxed),
+            current_path: path.map(|path| path.to_string_lossy().to_string()),
+        }));
+    }
+
+    fn send(&self, event: ScanEvent) {
+        if let Some(sink) = &self.sink {
+            // the scan still has to finish so the database stays consistent,
+            // even if the listener went away
+            let _ = sink.lock().unwrap().add(event);
+        }
+    }
+}
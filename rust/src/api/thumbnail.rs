@@ -0,0 +1,122 @@
+use crate::api::artwork::find_art;
+use crate::api::config::project_dirs;
+use crate::api::metadata::{all_tags, MetadataError};
+use crate::api::path::TrackPath;
+use anyhow::{Context, Result};
+use image::codecs::jpeg::JpegEncoder;
+use image::imageops::FilterType;
+use rayon::prelude::*;
+use std::collections::hash_map::DefaultHasher;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+const THUMBNAIL_DIR: &str = "thumbnails";
+const JPEG_QUALITY: u8 = 85;
+const MIN_SIZE: u32 = 16;
+const MAX_SIZE: u32 = 2048;
+
+// tracks of one album share a cover and are often thumbnailed in parallel
+static NEXT_TEMP: AtomicUsize = AtomicUsize::new(0);
+
+pub struct Thumbnail {
+    /// JPEG encoded image that fits into the requested size.
+    pub data: Vec<u8>,
+    /// Hash of the original artwork, tracks that share a cover share it.
+    pub art_hash: String,
+}
+
+/// Returns the artwork of a track scaled down to fit into `size` pixels,
+/// or `None` if the track has no artwork.
+///
+/// Results are cached on disk by artwork and size, so every album cover is
+/// only decoded once per size.
+pub fn get_thumbnail(path: TrackPath, size: u32) -> Result<Option<Thumbnail>> {
+    let path = path.to_path_buf();
+    let Some(art) = read_art(&path)? else {
+        return Ok(None);
+    };
+    thumbnail(&art, size).map(Some)
+}
+
+/// `get_thumbnail` for many tracks at once, e.g. a screen of the track list.
+///
+/// Tracks whose artwork could not be read get `None` rather than failing the rest.
+pub fn get_thumbnails(paths: Vec<TrackPath>, size: u32) -> Vec<Option<Thumbnail>> {
+    paths
+        .into_par_iter()
+        .map(|path| get_thumbnail(path, size).ok().flatten())
+        .collect()
+}
+
+pub fn clear_thumbnail_cache() -> Result<()> {
+    let dir = cache_dir()?;
+    if dir.exists() {
+        fs::remove_dir_all(dir)?;
+    }
+    Ok(())
+}
+
+pub(crate) fn read_art(path: &Path) -> Result<Option<Vec<u8>>, MetadataError> {
+    let file = lofty::read_from_path(path)?;
+    Ok(find_art(&all_tags(&file), path).map(|(data, _)| data))
+}
+
+pub(crate) fn art_hash(art: &[u8]) -> String {
+    let mut hasher = DefaultHasher::new();
+    art.hash(&mut hasher);
+    format!("{:016x}", hasher.finish())
+}
+
+pub(crate) fn thumbnail(art: &[u8], size: u32) -> Result<Thumbnail> {
+    let size = size.clamp(MIN_SIZE, MAX_SIZE);
+    let art_hash = art_hash(art);
+    let cached = cache_dir()?.join(format!("{art_hash}-{size}.jpg"));
+
+    if let Ok(data) = fs::read(&cached) {
+        return Ok(Thumbnail { data, art_hash });
+    }
+
+    let data = encode(art, size)?;
+    write_cached(&cached, &data)?;
+
+    Ok(Thumbnail { data, art_hash })
+}
+
+/// Writes a cache file through a temp file of its own, so a reader never sees
+/// half a file and parallel writers of the same entry don't clash.
+pub(crate) fn write_cached(path: &Path, data: &[u8]) -> Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let temp = path.with_extension(format!(
+        "{}-{}.tmp",
+        process::id(),
+        NEXT_TEMP.fetch_add(1, Ordering::Relaxed)
+    ));
+    fs::write(&temp, data)?;
+    fs::rename(&temp, path)?;
+    Ok(())
+}
+
+fn encode(art: &[u8], size: u32) -> Result<Vec<u8>> {
+    let mut image = image::load_from_memory(art).context("Could not decode artwork!")?;
+    // small covers are kept as they are, scaling up only makes them blurry
+    if image.width() > size || image.height() > size {
+        image = image.resize(size, size, FilterType::Triangle);
+    }
+
+    let mut data = Vec::new();
+    // JPEG has no alpha channel, so transparent covers end up on black
+    JpegEncoder::new_with_quality(&mut data, JPEG_QUALITY)
+        .encode_image(&image.to_rgb8())
+        .context("Could not encode thumbnail!")?;
+
+    Ok(data)
+}
+
+fn cache_dir() -> Result<PathBuf> {
+    Ok(project_dirs()?.cache_dir().join(THUMBNAIL_DIR))
+}
@@ -0,0 +1,375 @@
+use crate::api::config::data_dir;
+use crate::api::library;
+use crate::api::path::{from_raw, to_raw};
+use anyhow::Result;
+use rusqlite::{params, Connection, OptionalExtension, Transaction};
+use serde::Deserialize;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// where ids were kept before they moved into the library database
+const LEGACY_FILE: &str = "track_ids.json";
+
+// identifies a file independently of its name, so renames and moves within
+// the same filesystem keep their id
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+struct FileKey {
+    dev: u64,
+    ino: u64,
+}
+
+impl FileKey {
+    #[cfg(unix)]
+    fn of(path: &Path) -> Option<Self> {
+        use std::os::unix::fs::MetadataExt;
+
+        let metadata = fs::metadata(path).ok()?;
+        Some(Self {
+            dev: metadata.dev(),
+            ino: metadata.ino(),
+        })
+    }
+
+    // there is no stable file index on other platforms, so only paths are used there
+    #[cfg(not(unix))]
+    fn of(_path: &Path) -> Option<Self> {
+        None
+    }
+}
+
+struct Entry {
+    id: usize,
+    path: PathBuf,
+    key: Option<FileKey>,
+}
+
+#[derive(Deserialize)]
+struct LegacyStore {
+    next_id: usize,
+    entries: Vec<LegacyEntry>,
+}
+
+#[derive(Deserialize)]
+struct LegacyEntry {
+    id: usize,
+    path: String,
+    key: Option<FileKey>,
+}
+
+/// Hands out track ids that stay the same across rescans.
+///
+/// A file is matched by inode first, which survives renames, and by path
+/// second, which survives editors that rewrite files into a new inode.
+pub(crate) struct TrackIds {
+    conn: Connection,
+    next_id: usize,
+    entries: HashMap<usize, Entry>,
+    by_key: HashMap<FileKey, usize>,
+    by_path: HashMap<PathBuf, usize>,
+    seen: HashSet<usize>,
+}
+
+impl TrackIds {
+    pub(crate) fn load() -> Result<Self> {
+        Self::read(library::open()?)
+    }
+
+    fn read(conn: Connection) -> Result<Self> {
+        let next_id: Option<i64> = conn
+            .query_row("SELECT value FROM meta WHERE key = 'next_track_id'", [], |row| {
+                row.get(0)
+            })
+            .optional()?;
+
+        let entries: Vec<_> = conn
+            .prepare("SELECT id, path, dev, ino FROM track_ids")?
+            .query_map([], |row| {
+                let id: i64 = row.get(0)?;
+                let dev: Option<i64> = row.get(2)?;
+                let ino: Option<i64> = row.get(3)?;
+                Ok(Entry {
+                    id: id as usize,
+                    path: PathBuf::from(from_raw(row.get(1)?)),
+                    key: dev.zip(ino).map(|(dev, ino)| FileKey {
+                        dev: dev as u64,
+                        ino: ino as u64,
+                    }),
+                })
+            })?
+            .collect::<rusqlite::Result<_>>()?;
+
+        let mut ids = Self {
+            conn,
+            next_id: next_id.unwrap_or_default() as usize,
+            entries: HashMap::new(),
+            by_key: HashMap::new(),
+            by_path: HashMap::new(),
+            seen: HashSet::new(),
+        };
+        entries.into_iter().for_each(|entry| ids.insert(entry));
+
+        Ok(ids)
+    }
+
+    pub(crate) fn id_for(&mut self, path: &Path) -> usize {
+        let key = FileKey::of(path);
+
+        // an id can only be claimed once per scan, which keeps hard links apart
+        let unseen = |id: &usize| !self.seen.contains(id);
+        let by_path = self.by_path.get(path).copied().filter(unseen);
+        let by_key = key
+            .and_then(|key| self.by_key.get(&key))
+            .copied()
+            .filter(unseen);
+        // hard links share an inode, their paths tell them apart
+        let exact = by_path.filter(|id| key.is_some() && self.entries[id].key == key);
+        let known = exact.or(by_key).or(by_path);
+
+        let id = match known.and_then(|id| self.remove(id)) {
+            Some(entry) => entry.id,
+            None => {
+                self.next_id += 1;
+                self.next_id
+            }
+        };
+
+        self.insert(Entry {
+            id,
+            path: path.to_path_buf(),
+            key,
+        });
+        self.seen.insert(id);
+
+        id
+    }
+
+    /// Forgets every file below `roots` that was not seen since loading, only
+    /// valid after a full scan of them.
+    ///
+    /// Dropping stale entries keeps a reused inode from inheriting the id of a
+    /// deleted file. Files on disabled or unmounted library folders keep theirs.
+    pub(crate) fn prune(&mut self, roots: &[PathBuf]) {
+        let stale: Vec<_> = self
+            .entries
+            .values()
+            .filter(|entry| {
+                !self.seen.contains(&entry.id)
+                    && roots.iter().any(|root| entry.path.starts_with(root))
+            })
+            .map(|entry| entry.id)
+            .collect();
+        for id in stale {
+            self.remove(id);
+        }
+    }
+
+    pub(crate) fn forget(&mut self, id: usize) {
+        self.remove(id);
+    }
+
+    pub(crate) fn save(mut self) -> Result<()> {
+        let tx = self.conn.transaction()?;
+        tx.execute("DELETE FROM track_ids", [])?;
+        {
+            let mut insert =
+                tx.prepare("INSERT INTO track_ids (id, path, dev, ino) VALUES (?1, ?2, ?3, ?4)")?;
+            // entries whose path was taken over and that were not found again are gone
+            let entries = self
+                .entries
+                .values()
+                .filter(|entry| self.by_path.get(&entry.path) == Some(&entry.id));
+            for entry in entries {
+                insert.execute(params![
+                    entry.id as i64,
+                    to_raw(entry.path.clone().into_os_string()),
+                    entry.key.map(|key| key.dev as i64),
+                    entry.key.map(|key| key.ino as i64),
+                ])?;
+            }
+        }
+        tx.execute(
+            "INSERT OR REPLACE INTO meta (key, value) VALUES ('next_track_id', ?1)",
+            [self.next_id as i64],
+        )?;
+        tx.commit()?;
+
+        Ok(())
+    }
+
+    // a file that lost its path to another one, e.g. after two files swapped
+    // names, stays reachable by its inode until the scan is saved
+    fn insert(&mut self, entry: Entry) {
+        if let Some(key) = entry.key {
+            self.by_key.insert(key, entry.id);
+        }
+        self.by_path.insert(entry.path.clone(), entry.id);
+        self.entries.insert(entry.id, entry);
+    }
+
+    fn remove(&mut self, id: usize) -> Option<Entry> {
+        let entry = self.entries.remove(&id)?;
+        if let Some(key) = entry.key {
+            if self.by_key.get(&key) == Some(&id) {
+                self.by_key.remove(&key);
+            }
+        }
+        if self.by_path.get(&entry.path) == Some(&id) {
+            self.by_path.remove(&entry.path);
+        }
+        Some(entry)
+    }
+}
+
+/// Copies the ids from `track_ids.json` into the database, part of the
+/// migration that created the `track_ids` table.
+///
+/// The tracks table already holds these ids, starting over would hand the
+/// same files new ones.
+pub(crate) fn import_legacy(tx: &Transaction) -> Result<()> {
+    let Ok(contents) = fs::read_to_string(data_dir()?.join(LEGACY_FILE)) else {
+        return Ok(());
+    };
+    // a malformed file failed every scan back then, there is nothing to keep
+    let Ok(store) = serde_json::from_str::<LegacyStore>(&contents) else {
+        return Ok(());
+    };
+
+    let mut insert =
+        tx.prepare("INSERT OR IGNORE INTO track_ids (id, path, dev, ino) VALUES (?1, ?2, ?3, ?4)")?;
+    for entry in store.entries {
+        insert.execute(params![
+            entry.id as i64,
+            to_raw(entry.path.into()),
+            entry.key.map(|key| key.dev as i64),
+            entry.key.map(|key| key.ino as i64),
+        ])?;
+    }
+    tx.execute(
+        "INSERT OR REPLACE INTO meta (key, value) VALUES ('next_track_id', ?1)",
+        [store.next_id as i64],
+    )?;
+
+    Ok(())
+}
+
+/// Deletes `track_ids.json` once its ids are safely in the database.
+pub(crate) fn remove_legacy() -> Result<()> {
+    match fs::remove_file(data_dir()?.join(LEGACY_FILE)) {
+        Err(err) if err.kind() != std::io::ErrorKind::NotFound => Err(err.into()),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    // a throwaway library folder with its own id database, removed again when the test ends
+    struct Folder(PathBuf);
+
+    impl Folder {
+        fn new(name: &str) -> Self {
+            let dir = std::env::temp_dir().join(format!("amai-ids-{}-{name}", std::process::id()));
+            let _ = fs::remove_dir_all(&dir);
+            fs::create_dir_all(dir.join("music")).unwrap();
+            let conn = Connection::open(dir.join("ids.sqlite")).unwrap();
+            for migration in library::MIGRATIONS {
+                conn.execute_batch(migration).unwrap();
+            }
+            Self(dir)
+        }
+
+        fn path(&self, name: &str) -> PathBuf {
+            self.0.join("music").join(name)
+        }
+
+        fn write(&self, name: &str) {
+            fs::write(self.path(name), name).unwrap();
+        }
+
+        fn rename(&self, from: &str, to: &str) {
+            fs::rename(self.path(from), self.path(to)).unwrap();
+        }
+
+        // a full scan of the folder that finds `names` in this order
+        fn scan(&self, names: &[&str]) -> Vec<usize> {
+            let conn = Connection::open(self.0.join("ids.sqlite")).unwrap();
+            let mut ids = TrackIds::read(conn).unwrap();
+            let found = names
+                .iter()
+                .map(|name| ids.id_for(&self.path(name)))
+                .collect();
+            ids.prune(&[self.0.join("music")]);
+            ids.save().unwrap();
+            found
+        }
+    }
+
+    impl Drop for Folder {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn keeps_ids_across_renames() {
+        let folder = Folder::new("renames");
+        folder.write("a.mp3");
+        folder.write("b.mp3");
+        assert_eq!(folder.scan(&["a.mp3", "b.mp3"]), [1, 2]);
+        assert_eq!(folder.scan(&["b.mp3", "a.mp3"]), [2, 1]);
+
+        folder.rename("a.mp3", "c.mp3");
+        assert_eq!(folder.scan(&["b.mp3", "c.mp3"]), [2, 1]);
+    }
+
+    #[test]
+    fn follows_swapped_names() {
+        let folder = Folder::new("swapped");
+        folder.write("a.mp3");
+        folder.write("b.mp3");
+        assert_eq!(folder.scan(&["a.mp3", "b.mp3"]), [1, 2]);
+
+        folder.rename("a.mp3", "c.mp3");
+        folder.rename("b.mp3", "a.mp3");
+        folder.rename("c.mp3", "b.mp3");
+        assert_eq!(folder.scan(&["a.mp3", "b.mp3"]), [2, 1]);
+        assert_eq!(folder.scan(&["a.mp3", "b.mp3"]), [2, 1]);
+    }
+
+    #[test]
+    fn keeps_hard_links_apart() {
+        let folder = Folder::new("hard-links");
+        folder.write("a.mp3");
+        fs::hard_link(folder.path("a.mp3"), folder.path("b.mp3")).unwrap();
+
+        assert_eq!(folder.scan(&["a.mp3", "b.mp3"]), [1, 2]);
+        assert_eq!(folder.scan(&["a.mp3", "b.mp3"]), [1, 2]);
+        assert_eq!(folder.scan(&["b.mp3", "a.mp3"]), [2, 1]);
+    }
+
+    #[test]
+    fn keeps_ids_of_rewritten_files() {
+        let folder = Folder::new("rewritten");
+        folder.write("a.mp3");
+        assert_eq!(folder.scan(&["a.mp3"]), [1]);
+
+        // editors that save into a new file and move it over the old one
+        folder.write("a.mp3.new");
+        folder.rename("a.mp3.new", "a.mp3");
+        assert_eq!(folder.scan(&["a.mp3"]), [1]);
+    }
+
+    #[test]
+    fn hands_out_new_ids_for_new_files() {
+        let folder = Folder::new("new-files");
+        folder.write("a.mp3");
+        assert_eq!(folder.scan(&["a.mp3"]), [1]);
+
+        fs::remove_file(folder.path("a.mp3")).unwrap();
+        assert_eq!(folder.scan(&[]), Vec::<usize>::new());
+        folder.write("b.mp3");
+        assert_eq!(folder.scan(&["b.mp3"]), [2]);
+    }
+}
@@ -1,38 +1,284 @@
-use anyhow::{Context, Result};
-use directories::UserDirs;
+use crate::api::config::Config;
+use crate::api::ignore_rules::IgnoreRules;
+use crate::api::path::TrackPath;
+use crate::api::scan::{scan_library, ScanContext, ScanPhase, SkipReason};
+use crate::api::track_ids::TrackIds;
+use anyhow::Result;
+use lofty::{FileType, Probe};
+use rayon::prelude::*;
+use std::collections::HashSet;
+use std::ffi::OsStr;
+use std::path::{Path, PathBuf};
+use walkdir::{DirEntry, WalkDir};
 
+// deep enough for `Artist/Album/Disc` style layouts with room to spare
+const DEFAULT_MAX_DEPTH: usize = 8;
+
+// audio that lofty can't read, anything lofty does read is recognized by its content
+const UNSUPPORTED_AUDIO_EXTENSIONS: &[&str] = &[
+    "ac3", "alac", "amr", "au", "caf", "dff", "dsf", "dts", "mid", "midi", "mka", "ra", "shn",
+    "tta", "wma",
+];
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum AudioFormat {
+    Aac,
+    Aiff,
+    Ape,
+    Flac,
+    Mpeg,
+    Mp4,
+    Mpc,
+    Opus,
+    Vorbis,
+    Speex,
+    Wav,
+    WavPack,
+}
+
+impl AudioFormat {
+    fn from_file_type(file_type: FileType) -> Option<Self> {
+        match file_type {
+            FileType::Aac => Some(Self::Aac),
+            FileType::Aiff => Some(Self::Aiff),
+            FileType::Ape => Some(Self::Ape),
+            FileType::Flac => Some(Self::Flac),
+            FileType::Mpeg => Some(Self::Mpeg),
+            FileType::Mp4 => Some(Self::Mp4),
+            FileType::Mpc => Some(Self::Mpc),
+            FileType::Opus => Some(Self::Opus),
+            FileType::Vorbis => Some(Self::Vorbis),
+            FileType::Speex => Some(Self::Speex),
+            FileType::Wav => Some(Self::Wav),
+            FileType::WavPack => Some(Self::WavPack),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Self::Aac => "aac",
+            Self::Aiff => "aiff",
+            Self::Ape => "ape",
+            Self::Flac => "flac",
+            Self::Mpeg => "mpeg",
+            Self::Mp4 => "mp4",
+            Self::Mpc => "mpc",
+            Self::Opus => "opus",
+            Self::Vorbis => "vorbis",
+            Self::Speex => "speex",
+            Self::Wav => "wav",
+            Self::WavPack => "wavpack",
+        }
+    }
+
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "aac" => Some(Self::Aac),
+            "aiff" => Some(Self::Aiff),
+            "ape" => Some(Self::Ape),
+            "flac" => Some(Self::Flac),
+            "mpeg" => Some(Self::Mpeg),
+            "mp4" => Some(Self::Mp4),
+            "mpc" => Some(Self::Mpc),
+            "opus" => Some(Self::Opus),
+            "vorbis" => Some(Self::Vorbis),
+            "speex" => Some(Self::Speex),
+            "wav" => Some(Self::Wav),
+            "wavpack" => Some(Self::WavPack),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone)]
 pub struct Track {
     pub name: String,
-    pub path: String, // TODO: replace with PathBuf
+    pub path: TrackPath,
+    /// Stays the same across rescans, renames and moves.
     pub id: Option<usize>,
+    pub format: AudioFormat,
 }
 
-pub fn get_music_files() -> Result<Vec<Track>> {
-    let user_dirs = UserDirs::new().context("Could not get user directory!")?;
-    let music_dir = user_dirs
-        .audio_dir()
-        .context("Audio directory not found!")?;
-    let entries = music_dir.read_dir()?;
+/// Scans every enabled library folder and records the result in the library database.
+pub fn get_music_files(max_depth: Option<usize>) -> Result<Vec<Track>> {
+    Ok(scan_library(max_depth)?.tracks)
+}
 
-    Ok(entries
+pub(crate) fn scan_music_files(
+    max_depth: Option<usize>,
+    context: &ScanContext,
+) -> Result<Vec<Track>> {
+    let config = Config::load()?;
+    let max_depth = max_depth.unwrap_or(DEFAULT_MAX_DEPTH);
+
+    context.phase(ScanPhase::Discovering, 0);
+    // roots may be nested inside each other, so only keep the first sighting of a file
+    let mut seen = HashSet::new();
+    let mut files = Vec::new();
+    let mut walked = Vec::new();
+    for root in config.enabled_roots() {
+        // an unmounted drive is missing rather than empty, its ids have to
+        // survive until it is back
+        if !root.is_dir() {
+            continue;
+        }
+        let rules = IgnoreRules::new(&root, &config.ignore_patterns);
+        for path in walk(&root, max_depth, rules, context) {
+            context.check()?;
+            if seen.insert(path.clone()) {
+                context.seen(&path);
+                files.push(path);
+            }
+        }
+        walked.push(root);
+    }
+
+    let mut ids = TrackIds::load()?;
+    let tracks = detect_tracks(files, &mut ids, context)?;
+    ids.prune(&walked);
+    ids.save()?;
+
+    Ok(tracks)
+}
+
+/// Builds tracks for a handful of changed files or folders, applying the same
+/// rules as a full scan of the library folder they are in.
+pub(crate) fn scan_paths(paths: &[PathBuf], context: &ScanContext) -> Result<Vec<Track>> {
+    let config = Config::load()?;
+
+    let mut seen = HashSet::new();
+    let files = paths
+        .iter()
+        .filter_map(|path| {
+            let root = config
+                .enabled_roots()
+                .filter(|root| path.starts_with(root))
+                .max_by_key(|root| root.components().count())?;
+            let relative = path.strip_prefix(&root).ok()?;
+
+            let depth = relative.components().count();
+            let hidden = relative
+                .components()
+                .any(|component| component.as_os_str().to_string_lossy().starts_with('.'));
+            let mut rules = IgnoreRules::new(&root, &config.ignore_patterns);
+            let ignored = rules.is_ignored(path, path.is_dir());
+            (depth <= DEFAULT_MAX_DEPTH && !hidden && !ignored)
+                .then(|| walk(path, DEFAULT_MAX_DEPTH - depth, rules, context))
+        })
+        .flatten()
+        .filter(|path| seen.insert(path.clone()))
+        .collect();
+
+    let mut ids = TrackIds::load()?;
+    let tracks = detect_tracks(files, &mut ids, context)?;
+    ids.save()?;
+
+    Ok(tracks)
+}
+
+pub(crate) fn forget_track_ids(ids: &[u64]) -> Result<()> {
+    let mut track_ids = TrackIds::load()?;
+    ids.iter().for_each(|id| track_ids.forget(*id as usize));
+    track_ids.save()
+}
+
+fn walk<'a>(
+    root: &Path,
+    max_depth: usize,
+    mut rules: IgnoreRules,
+    context: &'a ScanContext,
+) -> impl Iterator<Item = PathBuf> + 'a {
+    // following links lets walkdir detect symlink loops, which are reported
+    // as errors and skipped like any other unreadable entry
+    WalkDir::new(root)
+        .follow_links(true)
+        .max_depth(max_depth)
         .into_iter()
-        .enumerate()
-        .filter_map(|(id, entry)| {
-            let entry = entry.ok()?;
-            let path = entry.path();
-            let ext = path.extension()?.to_string_lossy().to_string();
-            // TODO: add support for other types
-            if ext == "mp3" || ext == "ogg" {
-                Some(
-                    Track {
-                        name: path.file_stem()?.to_str()?.to_string(),
-                        path: path.to_str()?.to_string(),
-                        id: Some(id), // TODO: find a better solution for track id
-                    }
-                )
-            } else {
+        .filter_entry(move |entry| {
+            entry.depth() == 0
+                || !(is_hidden(entry)
+                    || rules.is_ignored(entry.path(), entry.file_type().is_dir()))
+        })
+        .filter_map(|entry| match entry {
+            Ok(entry) => Some(entry),
+            Err(err) => {
+                if let Some(path) = err.path() {
+                    context.skip(path, SkipReason::from_walkdir(&err));
+                }
                 None
             }
         })
+        .filter(|entry| entry.file_type().is_file())
+        .map(DirEntry::into_path)
+}
+
+// sniffing means opening every file, which is where a scan spends most of its time
+fn detect_tracks(
+    files: Vec<PathBuf>,
+    ids: &mut TrackIds,
+    context: &ScanContext,
+) -> Result<Vec<Track>> {
+    context.phase(ScanPhase::Detecting, files.len());
+    let found: Vec<_> = files
+        .into_par_iter()
+        .filter_map(|path| {
+            if context.is_cancelled() {
+                return None;
+            }
+            let format = detect_format(&path).unwrap_or_else(|reason| {
+                context.skip(&path, reason);
+                None
+            });
+            context.done(&path);
+            Some((path, format?))
+        })
+        .collect();
+    context.check()?;
+
+    Ok(found
+        .into_iter()
+        .map(|(path, format)| Track {
+            id: Some(ids.id_for(&path)),
+            name: path
+                .file_stem()
+                .unwrap_or_default()
+                .to_string_lossy()
+                .to_string(),
+            path: TrackPath::new(&path),
+            format,
+        })
         .collect())
 }
+
+// sniffs the magic bytes first and only falls back to the (case-insensitive)
+// extension when the content is inconclusive
+fn detect_format(path: &Path) -> Result<Option<AudioFormat>, SkipReason> {
+    let probe = Probe::open(path)
+        .map_err(|err| SkipReason::from_lofty(&err))?
+        .guess_file_type()
+        .map_err(|err| SkipReason::from_io(&err))?;
+
+    match probe.file_type() {
+        Some(file_type) => AudioFormat::from_file_type(file_type)
+            .map(Some)
+            .ok_or(SkipReason::UnsupportedFormat),
+        // covers, playlists and the like aren't worth reporting
+        None if has_audio_extension(path) => Err(SkipReason::UnsupportedFormat),
+        None => Ok(None),
+    }
+}
+
+fn has_audio_extension(path: &Path) -> bool {
+    path.extension()
+        .and_then(OsStr::to_str)
+        .is_some_and(|ext| {
+            UNSUPPORTED_AUDIO_EXTENSIONS
+                .iter()
+                .any(|audio| audio.eq_ignore_ascii_case(ext))
+        })
+}
+
+fn is_hidden(entry: &DirEntry) -> bool {
+    entry.file_name().to_string_lossy().starts_with('.')
+}
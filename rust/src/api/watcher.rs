@@ -0,0 +1,124 @@
+use crate::api::config::Config;
+use crate::api::library::{self, LibraryDiff, LibraryTrack};
+use crate::api::path::TrackPath;
+use crate::api::scan::{scan_lock, ScanContext};
+use crate::api::ignore_rules::IGNORE_FILE;
+use crate::api::utils::{forget_track_ids, scan_music_files, scan_paths};
+use crate::frb_generated::StreamSink;
+use anyhow::Result;
+use notify::event::{AccessKind, AccessMode};
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::sync::mpsc::{self, Receiver};
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+
+// copying an album in fires a burst of events per file, wait for it to settle
+const DEBOUNCE: Duration = Duration::from_millis(500);
+
+static WATCHER: Mutex<Option<RecommendedWatcher>> = Mutex::new(None);
+
+pub enum LibraryEvent {
+    TrackAdded(LibraryTrack),
+    TrackRemoved { id: usize },
+    TrackChanged(LibraryTrack),
+    TrackMoved { from: TrackPath, track: LibraryTrack },
+}
+
+/// Watches every enabled library folder and streams changes to the library as they happen.
+///
+/// Replaces any previous watcher, e.g. after the library folders were changed.
+pub fn watch_library(sink: StreamSink<LibraryEvent>) -> Result<()> {
+    let (tx, rx) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(tx)?;
+    for root in Config::load()?.enabled_roots() {
+        watcher.watch(&root, RecursiveMode::Recursive)?;
+    }
+
+    // dropping the old watcher closes its channel, which ends its thread
+    *WATCHER.lock().unwrap() = Some(watcher);
+    thread::spawn(move || watch(rx, sink));
+
+    Ok(())
+}
+
+pub fn stop_watching_library() {
+    *WATCHER.lock().unwrap() = None;
+}
+
+fn watch(rx: Receiver<notify::Result<Event>>, sink: StreamSink<LibraryEvent>) {
+    while let Ok(event) = rx.recv() {
+        let mut paths = HashSet::new();
+        collect_paths(event, &mut paths);
+        while let Ok(event) = rx.recv_timeout(DEBOUNCE) {
+            collect_paths(event, &mut paths);
+        }
+
+        // a failed update is retried by the next event touching the same files
+        let Ok(diff) = apply(paths) else {
+            continue;
+        };
+        for event in events(diff) {
+            if sink.add(event).is_err() {
+                // nobody is listening anymore
+                return;
+            }
+        }
+    }
+}
+
+fn collect_paths(event: notify::Result<Event>, paths: &mut HashSet<PathBuf>) {
+    let Ok(event) = event else {
+        return;
+    };
+    let relevant = match event.kind {
+        EventKind::Access(kind) => matches!(kind, AccessKind::Close(AccessMode::Write)),
+        EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_) => true,
+        _ => false,
+    };
+    if relevant {
+        paths.extend(event.paths);
+    }
+}
+
+// renames show up as a removed and a created path, matching inodes in
+// `scan_paths` is what turns them back into a move
+fn apply(paths: HashSet<PathBuf>) -> Result<LibraryDiff> {
+    let _lock = scan_lock();
+    let context = ScanContext::silent();
+
+    // edited ignore rules can affect any file below them, start over
+    if paths
+        .iter()
+        .any(|path| path.file_name().is_some_and(|name| name == IGNORE_FILE))
+    {
+        return library::sync(scan_music_files(None, &context)?, &context);
+    }
+
+    let (existing, gone): (Vec<_>, Vec<_>) = paths.into_iter().partition(|path| path.exists());
+    let diff = library::sync_paths(scan_paths(&existing, &context)?, &gone, &context)?;
+    forget_track_ids(&diff.removed)?;
+
+    Ok(diff)
+}
+
+fn events(diff: LibraryDiff) -> impl Iterator<Item = LibraryEvent> {
+    let removed = diff
+        .removed
+        .into_iter()
+        .map(|id| LibraryEvent::TrackRemoved { id: id as usize });
+    let moved = diff
+        .moved
+        .into_iter()
+        .map(|moved| LibraryEvent::TrackMoved {
+            from: moved.from,
+            track: moved.track,
+        });
+
+    removed
+        .chain(moved)
+        .chain(diff.added.into_iter().map(LibraryEvent::TrackAdded))
+        .chain(diff.modified.into_iter().map(LibraryEvent::TrackChanged))
+}
@@ -4,7 +4,8 @@
 // Section: imports
 
 use super::*;
-use crate::api::metadata::*;
+use crate::api::lyrics::*;
+use crate::api::scan::*;
 use flutter_rust_bridge::for_generated::byteorder::{NativeEndian, ReadBytesExt, WriteBytesExt};
 use flutter_rust_bridge::for_generated::transform_result_dco;
 use flutter_rust_bridge::{Handler, IntoIntoDart};
@@ -14,19 +15,29 @@ use flutter_rust_bridge::{Handler, IntoIntoDart};
 flutter_rust_bridge::frb_generated_boilerplate_io!();
 
 #[no_mangle]
-pub extern "C" fn frbgen_amai_music_player_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockTag(
+pub extern "C" fn frbgen_amai_music_player_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockLyricsTimeline(
     ptr: *const std::ffi::c_void,
 ) {
-    MoiArc::<flutter_rust_bridge::for_generated::rust_async::RwLock<Tag>>::increment_strong_count(
-        ptr as _,
-    );
+    MoiArc::<flutter_rust_bridge::for_generated::rust_async::RwLock<LyricsTimeline>>::increment_strong_count(ptr as _);
 }
 
 #[no_mangle]
-pub extern "C" fn frbgen_amai_music_player_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockTag(
+pub extern "C" fn frbgen_amai_music_player_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockLyricsTimeline(
     ptr: *const std::ffi::c_void,
 ) {
-    MoiArc::<flutter_rust_bridge::for_generated::rust_async::RwLock<Tag>>::decrement_strong_count(
-        ptr as _,
-    );
+    MoiArc::<flutter_rust_bridge::for_generated::rust_async::RwLock<LyricsTimeline>>::decrement_strong_count(ptr as _);
+}
+
+#[no_mangle]
+pub extern "C" fn frbgen_amai_music_player_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken(
+    ptr: *const std::ffi::c_void,
+) {
+    MoiArc::<flutter_rust_bridge::for_generated::rust_async::RwLock<ScanCancelToken>>::increment_strong_count(ptr as _);
+}
+
+#[no_mangle]
+pub extern "C" fn frbgen_amai_music_player_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken(
+    ptr: *const std::ffi::c_void,
+) {
+    MoiArc::<flutter_rust_bridge::for_generated::rust_async::RwLock<ScanCancelToken>>::decrement_strong_count(ptr as _);
 }
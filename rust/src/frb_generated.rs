@@ -19,7 +19,8 @@
 
 // Section: imports
 
-use crate::api::metadata::*;
+use crate::api::lyrics::*;
+use crate::api::scan::*;
 use flutter_rust_bridge::for_generated::byteorder::{NativeEndian, ReadBytesExt, WriteBytesExt};
 use flutter_rust_bridge::for_generated::transform_result_dco;
 use flutter_rust_bridge::{Handler, IntoIntoDart};
@@ -39,7 +40,7 @@ flutter_rust_bridge::frb_generated_default_handler!();
 
 // Section: wire_funcs
 
-fn wire_get_metadata_impl(
+fn wire_get_picture_data_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -47,7 +48,7 @@ fn wire_get_metadata_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_metadata",
+            debug_name: "get_picture_data",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -61,15 +62,18 @@ fn wire_get_metadata_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_path = <crate::api::path::TrackPath>::sse_decode(&mut deserializer);
+            let api_index = <usize>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse((move || crate::api::metadata::get_metadata(api_path))())
+                transform_result_sse((move || {
+                    crate::api::artwork::get_picture_data(api_path, api_index)
+                })())
             }
         },
     )
 }
-fn wire_get_music_files_impl(
+fn wire_get_pictures_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -77,7 +81,7 @@ fn wire_get_music_files_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_music_files",
+            debug_name: "get_pictures",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -91,408 +95,5010 @@ fn wire_get_music_files_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <crate::api::path::TrackPath>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| transform_result_sse((move || crate::api::utils::get_music_files())())
+            move |context| {
+                transform_result_sse((move || crate::api::artwork::get_pictures(api_path))())
+            }
         },
     )
 }
-
-// Section: related_funcs
-
-flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
-    flutter_rust_bridge::for_generated::rust_async::RwLock<Tag>
-);
-
-// Section: dart2rust
-
-impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        unreachable!("");
-    }
+fn wire_apply_batch_edit_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "apply_batch_edit",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_files = <Vec<crate::api::batch_edit::BatchFile>>::sse_decode(&mut deserializer);
+            let api_edit = <crate::api::batch_edit::BatchEdit>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::batch_edit::apply_batch_edit(api_files, api_edit)
+                })())
+            }
+        },
+    )
 }
-
-impl SseDecode for Tag {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <RustOpaqueMoi<flutter_rust_bridge::for_generated::rust_async::RwLock<Tag>>>::sse_decode(deserializer);
-        return inner.rust_auto_opaque_decode_owned();
-    }
+fn wire_preview_batch_edit_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "preview_batch_edit",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_paths = <Vec<crate::api::path::TrackPath>>::sse_decode(&mut deserializer);
+            let api_edit = <crate::api::batch_edit::BatchEdit>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    Result::<_, ()>::Ok(crate::api::batch_edit::preview_batch_edit(
+                        api_paths, api_edit,
+                    ))
+                })())
+            }
+        },
+    )
 }
-
-impl SseDecode for RustOpaqueMoi<flutter_rust_bridge::for_generated::rust_async::RwLock<Tag>> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <usize>::sse_decode(deserializer);
-        return decode_rust_opaque_moi(inner);
-    }
+fn wire_undo_batch_edit_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "undo_batch_edit",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_journal_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::batch_edit::undo_batch_edit(api_journal_id)
+                })())
+            }
+        },
+    )
 }
-
-impl SseDecode for String {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<u8>>::sse_decode(deserializer);
-        return String::from_utf8(inner).unwrap();
-    }
+fn wire_add_library_root_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "add_library_root",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <crate::api::path::TrackPath>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || crate::api::config::add_library_root(api_path))())
+            }
+        },
+    )
 }
-
-impl SseDecode for Vec<u8> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
-        }
-        return ans_;
-    }
+fn wire_get_ignore_patterns_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_ignore_patterns",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || crate::api::config::get_ignore_patterns())())
+            }
+        },
+    )
 }
-
-impl SseDecode for Vec<crate::api::utils::Track> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::utils::Track>::sse_decode(deserializer));
-        }
-        return ans_;
-    }
+fn wire_list_library_roots_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "list_library_roots", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end(); move |context|  {
+                    transform_result_sse((move ||  {
+                         crate::api::config::list_library_roots()
+                    })())
+                } })
 }
-
-impl SseDecode for crate::api::metadata::Metadata {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_tag = <Option<Tag>>::sse_decode(deserializer);
-        let mut var_art = <Option<Vec<u8>>>::sse_decode(deserializer);
-        let mut var_title = <Option<String>>::sse_decode(deserializer);
-        return crate::api::metadata::Metadata {
-            tag: var_tag,
-            art: var_art,
-            title: var_title,
-        };
-    }
+fn wire_remove_library_root_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_library_root",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <crate::api::path::TrackPath>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || crate::api::config::remove_library_root(api_path))())
+            }
+        },
+    )
 }
-
-impl SseDecode for Option<String> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<String>::sse_decode(deserializer));
-        } else {
-            return None;
+fn wire_set_ignore_patterns_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_ignore_patterns",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_patterns = <Vec<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::config::set_ignore_patterns(api_patterns)
+                })())
+            }
+        },
+    )
+}
+fn wire_set_library_root_enabled_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_library_root_enabled",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <crate::api::path::TrackPath>::sse_decode(&mut deserializer);
+            let api_enabled = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::config::set_library_root_enabled(api_path, api_enabled)
+                })())
+            }
+        },
+    )
+}
+fn wire_get_album_tracks_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_album_tracks",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_album = <String>::sse_decode(&mut deserializer);
+            let api_artist = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::library::get_album_tracks(api_album, api_artist)
+                })())
+            }
+        },
+    )
+}
+fn wire_get_albums_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_albums",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| transform_result_sse((move || crate::api::library::get_albums())())
+        },
+    )
+}
+fn wire_get_library_track_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_library_track",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <usize>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || crate::api::library::get_library_track(api_id))())
+            }
+        },
+    )
+}
+fn wire_get_library_tracks_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_library_tracks",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || crate::api::library::get_library_tracks())())
+            }
+        },
+    )
+}
+fn wire_rescan_library_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "rescan_library",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_max_depth = <Option<usize>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || crate::api::library::rescan_library(api_max_depth))())
+            }
+        },
+    )
+}
+fn wire_search_library_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "search_library",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || crate::api::library::search_library(api_query))())
+            }
+        },
+    )
+}
+fn wire_analyze_loudness_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "analyze_loudness",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_paths = <Vec<crate::api::path::TrackPath>>::sse_decode(&mut deserializer);
+            let api_album = <bool>::sse_decode(&mut deserializer);
+            let api_write_tags = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::loudness::analyze_loudness(api_paths, api_album, api_write_tags)
+                })())
+            }
+        },
+    )
+}
+fn wire_get_track_loudness_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_track_loudness",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <crate::api::path::TrackPath>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || crate::api::loudness::get_track_loudness(api_path))())
+            }
+        },
+    )
+}
+fn wire_replay_gain_volume_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "replay_gain_volume",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_gain = <crate::api::metadata::ReplayGain>::sse_decode(&mut deserializer);
+            let api_mode = <crate::api::loudness::ReplayGainMode>::sse_decode(&mut deserializer);
+            let api_preamp_db = <f64>::sse_decode(&mut deserializer);
+            let api_prevent_clipping = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse((move || {
+                Result::<_, ()>::Ok(crate::api::loudness::replay_gain_volume(
+                    api_gain,
+                    api_mode,
+                    api_preamp_db,
+                    api_prevent_clipping,
+                ))
+            })())
+        },
+    )
+}
+fn wire_LyricsTimeline_line_index_at_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "LyricsTimeline_line_index_at",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::rust_async::RwLock<LyricsTimeline>,
+            >>::sse_decode(&mut deserializer);
+            let api_position_ms = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse((move || {
+                let api_that = api_that.rust_auto_opaque_decode_ref();
+                Result::<_, ()>::Ok(crate::api::lyrics::LyricsTimeline::line_index_at(
+                    &api_that,
+                    api_position_ms,
+                ))
+            })())
+        },
+    )
+}
+fn wire_LyricsTimeline_lyrics_at_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "LyricsTimeline_lyrics_at",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::rust_async::RwLock<LyricsTimeline>,
+            >>::sse_decode(&mut deserializer);
+            let api_position_ms = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse((move || {
+                let api_that = api_that.rust_auto_opaque_decode_ref();
+                Result::<_, ()>::Ok(crate::api::lyrics::LyricsTimeline::lyrics_at(
+                    &api_that,
+                    api_position_ms,
+                ))
+            })())
+        },
+    )
+}
+fn wire_build_lrc_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "build_lrc",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_text = <String>::sse_decode(&mut deserializer);
+            let api_taps_ms = <Vec<u64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::lyrics::build_lrc(api_text, api_taps_ms)
+                })())
+            }
+        },
+    )
+}
+fn wire_get_lyrics_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "get_lyrics", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <crate::api::path::TrackPath>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
+                    transform_result_sse((move ||  {
+                         crate::api::lyrics::get_lyrics(api_path)
+                    })())
+                } })
+}
+fn wire_nudge_lrc_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "nudge_lrc",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_lrc = <String>::sse_decode(&mut deserializer);
+            let api_delta_ms = <i64>::sse_decode(&mut deserializer);
+            let api_from_line = <Option<usize>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    Result::<_, ()>::Ok(crate::api::lyrics::nudge_lrc(
+                        api_lrc,
+                        api_delta_ms,
+                        api_from_line,
+                    ))
+                })())
+            }
+        },
+    )
+}
+fn wire_save_lrc_sidecar_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "save_lrc_sidecar",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <crate::api::path::TrackPath>::sse_decode(&mut deserializer);
+            let api_lrc = <String>::sse_decode(&mut deserializer);
+            let api_language = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::lyrics::save_lrc_sidecar(api_path, api_lrc, api_language)
+                })())
+            }
+        },
+    )
+}
+fn wire_get_audio_properties_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_audio_properties",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <crate::api::path::TrackPath>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::metadata::get_audio_properties(api_path)
+                })())
+            }
+        },
+    )
+}
+fn wire_get_metadata_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_metadata",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <crate::api::path::TrackPath>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || crate::api::metadata::get_metadata(api_path))())
+            }
+        },
+    )
+}
+fn wire_write_metadata_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "write_metadata",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <crate::api::path::TrackPath>::sse_decode(&mut deserializer);
+            let api_changes =
+                <crate::api::metadata::MetadataChanges>::sse_decode(&mut deserializer);
+            let api_expected = <crate::api::library::FileStamp>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::metadata::write_metadata(api_path, api_changes, api_expected)
+                })())
+            }
+        },
+    )
+}
+fn wire_get_palette_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_palette",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <crate::api::path::TrackPath>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || crate::api::palette::get_palette(api_path))())
+            }
+        },
+    )
+}
+fn wire_playback_path_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "playback_path",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <crate::api::path::TrackPath>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || crate::api::path::playback_path(api_path))())
+            }
+        },
+    )
+}
+fn wire_ScanCancelToken_cancel_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "ScanCancelToken_cancel",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::rust_async::RwLock<ScanCancelToken>,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse((move || {
+                let api_that = api_that.rust_auto_opaque_decode_ref();
+                Result::<_, ()>::Ok(crate::api::scan::ScanCancelToken::cancel(&api_that))
+            })())
+        },
+    )
+}
+fn wire_ScanCancelToken_new_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "ScanCancelToken_new",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse((move || {
+                Result::<_, ()>::Ok(crate::api::scan::ScanCancelToken::new())
+            })())
+        },
+    )
+}
+fn wire_scan_library_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "scan_library",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_max_depth = <Option<usize>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || crate::api::scan::scan_library(api_max_depth))())
+            }
+        },
+    )
+}
+fn wire_scan_library_with_progress_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "scan_library_with_progress",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Stream,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_token = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::rust_async::RwLock<ScanCancelToken>,
+            >>::sse_decode(&mut deserializer);
+            let api_max_depth = <Option<usize>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    let api_token = api_token.rust_auto_opaque_decode_ref();
+                    crate::api::scan::scan_library_with_progress(
+                        StreamSink::new(
+                            context
+                                .rust2dart_context()
+                                .stream_sink::<_, crate::api::scan::ScanEvent>(),
+                        ),
+                        &api_token,
+                        api_max_depth,
+                    )
+                })())
+            }
+        },
+    )
+}
+fn wire_clear_thumbnail_cache_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "clear_thumbnail_cache",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || crate::api::thumbnail::clear_thumbnail_cache())())
+            }
+        },
+    )
+}
+fn wire_get_thumbnail_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_thumbnail",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <crate::api::path::TrackPath>::sse_decode(&mut deserializer);
+            let api_size = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::thumbnail::get_thumbnail(api_path, api_size)
+                })())
+            }
+        },
+    )
+}
+fn wire_get_thumbnails_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_thumbnails",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_paths = <Vec<crate::api::path::TrackPath>>::sse_decode(&mut deserializer);
+            let api_size = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    Result::<_, ()>::Ok(crate::api::thumbnail::get_thumbnails(api_paths, api_size))
+                })())
+            }
+        },
+    )
+}
+fn wire_get_music_files_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_music_files",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_max_depth = <Option<usize>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || crate::api::utils::get_music_files(api_max_depth))())
+            }
+        },
+    )
+}
+fn wire_stop_watching_library_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "stop_watching_library",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    Result::<_, ()>::Ok(crate::api::watcher::stop_watching_library())
+                })())
+            }
+        },
+    )
+}
+fn wire_watch_library_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "watch_library",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Stream,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::watcher::watch_library(StreamSink::new(
+                        context
+                            .rust2dart_context()
+                            .stream_sink::<_, crate::api::watcher::LibraryEvent>(),
+                    ))
+                })())
+            }
+        },
+    )
+}
+
+// Section: related_funcs
+
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::rust_async::RwLock<LyricsTimeline>
+);
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::rust_async::RwLock<ScanCancelToken>
+);
+
+// Section: dart2rust
+
+impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        unreachable!("");
+    }
+}
+
+impl SseDecode for LyricsTimeline {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::rust_async::RwLock<LyricsTimeline>,
+        >>::sse_decode(deserializer);
+        return inner.rust_auto_opaque_decode_owned();
+    }
+}
+
+impl SseDecode for ScanCancelToken {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::rust_async::RwLock<ScanCancelToken>,
+        >>::sse_decode(deserializer);
+        return inner.rust_auto_opaque_decode_owned();
+    }
+}
+
+impl SseDecode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::rust_async::RwLock<LyricsTimeline>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::rust_async::RwLock<ScanCancelToken>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<u8>>::sse_decode(deserializer);
+        return String::from_utf8(inner).unwrap();
+    }
+}
+
+impl SseDecode for crate::api::library::Album {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_artist = <Option<String>>::sse_decode(deserializer);
+        let mut var_trackCount = <usize>::sse_decode(deserializer);
+        let mut var_durationMs = <u64>::sse_decode(deserializer);
+        return crate::api::library::Album {
+            name: var_name,
+            artist: var_artist,
+            track_count: var_trackCount,
+            duration_ms: var_durationMs,
+        };
+    }
+}
+
+impl SseDecode for crate::api::loudness::AlbumLoudness {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_integratedLufs = <f64>::sse_decode(deserializer);
+        let mut var_loudnessRangeLu = <f64>::sse_decode(deserializer);
+        let mut var_truePeak = <f64>::sse_decode(deserializer);
+        let mut var_gainDb = <f64>::sse_decode(deserializer);
+        return crate::api::loudness::AlbumLoudness {
+            integrated_lufs: var_integratedLufs,
+            loudness_range_lu: var_loudnessRangeLu,
+            true_peak: var_truePeak,
+            gain_db: var_gainDb,
+        };
+    }
+}
+
+impl SseDecode for crate::api::artwork::ArtSource {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_pictureType =
+                    <crate::api::artwork::PictureType>::sse_decode(deserializer);
+                return crate::api::artwork::ArtSource::Embedded {
+                    picture_type: var_pictureType,
+                };
+            }
+            1 => {
+                let mut var_path = <crate::api::path::TrackPath>::sse_decode(deserializer);
+                return crate::api::artwork::ArtSource::Folder { path: var_path };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::utils::AudioFormat {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::utils::AudioFormat::Aac,
+            1 => crate::api::utils::AudioFormat::Aiff,
+            2 => crate::api::utils::AudioFormat::Ape,
+            3 => crate::api::utils::AudioFormat::Flac,
+            4 => crate::api::utils::AudioFormat::Mpeg,
+            5 => crate::api::utils::AudioFormat::Mp4,
+            6 => crate::api::utils::AudioFormat::Mpc,
+            7 => crate::api::utils::AudioFormat::Opus,
+            8 => crate::api::utils::AudioFormat::Vorbis,
+            9 => crate::api::utils::AudioFormat::Speex,
+            10 => crate::api::utils::AudioFormat::Wav,
+            11 => crate::api::utils::AudioFormat::WavPack,
+            _ => unreachable!("Invalid variant for AudioFormat: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::metadata::AudioProperties {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_durationMs = <u64>::sse_decode(deserializer);
+        let mut var_overallBitrate = <Option<u32>>::sse_decode(deserializer);
+        let mut var_audioBitrate = <Option<u32>>::sse_decode(deserializer);
+        let mut var_sampleRate = <Option<u32>>::sse_decode(deserializer);
+        let mut var_bitDepth = <Option<u8>>::sse_decode(deserializer);
+        let mut var_channels = <Option<u8>>::sse_decode(deserializer);
+        return crate::api::metadata::AudioProperties {
+            duration_ms: var_durationMs,
+            overall_bitrate: var_overallBitrate,
+            audio_bitrate: var_audioBitrate,
+            sample_rate: var_sampleRate,
+            bit_depth: var_bitDepth,
+            channels: var_channels,
+        };
+    }
+}
+
+impl SseDecode for crate::api::batch_edit::BatchEdit {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_changes = <crate::api::metadata::MetadataChanges>::sse_decode(deserializer);
+        let mut var_renumber = <Option<crate::api::batch_edit::Renumber>>::sse_decode(deserializer);
+        return crate::api::batch_edit::BatchEdit {
+            changes: var_changes,
+            renumber: var_renumber,
+        };
+    }
+}
+
+impl SseDecode for crate::api::batch_edit::BatchFile {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_path = <crate::api::path::TrackPath>::sse_decode(deserializer);
+        let mut var_stamp = <crate::api::library::FileStamp>::sse_decode(deserializer);
+        return crate::api::batch_edit::BatchFile {
+            path: var_path,
+            stamp: var_stamp,
+        };
+    }
+}
+
+impl SseDecode for crate::api::batch_edit::BatchFileResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_path = <crate::api::path::TrackPath>::sse_decode(deserializer);
+        let mut var_stamp = <Option<crate::api::library::FileStamp>>::sse_decode(deserializer);
+        let mut var_changes = <Vec<crate::api::batch_edit::FieldChange>>::sse_decode(deserializer);
+        let mut var_error = <Option<crate::api::metadata::MetadataError>>::sse_decode(deserializer);
+        return crate::api::batch_edit::BatchFileResult {
+            path: var_path,
+            stamp: var_stamp,
+            changes: var_changes,
+            error: var_error,
+        };
+    }
+}
+
+impl SseDecode for crate::api::batch_edit::BatchOutcome {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_journalId = <Option<String>>::sse_decode(deserializer);
+        let mut var_results =
+            <Vec<crate::api::batch_edit::BatchFileResult>>::sse_decode(deserializer);
+        return crate::api::batch_edit::BatchOutcome {
+            journal_id: var_journalId,
+            results: var_results,
+        };
+    }
+}
+
+impl SseDecode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap() != 0
+    }
+}
+
+impl SseDecode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_f64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::api::batch_edit::FieldChange {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_field = <crate::api::metadata::MetadataField>::sse_decode(deserializer);
+        let mut var_before = <Option<String>>::sse_decode(deserializer);
+        let mut var_after = <Option<String>>::sse_decode(deserializer);
+        return crate::api::batch_edit::FieldChange {
+            field: var_field,
+            before: var_before,
+            after: var_after,
+        };
+    }
+}
+
+impl SseDecode for crate::api::library::FileStamp {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_size = <i64>::sse_decode(deserializer);
+        let mut var_mtime = <i64>::sse_decode(deserializer);
+        return crate::api::library::FileStamp {
+            size: var_size,
+            mtime: var_mtime,
+        };
+    }
+}
+
+impl SseDecode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for i64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::api::library::LibraryDiff {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_added = <Vec<crate::api::library::LibraryTrack>>::sse_decode(deserializer);
+        let mut var_removed = <Vec<u64>>::sse_decode(deserializer);
+        let mut var_modified = <Vec<crate::api::library::LibraryTrack>>::sse_decode(deserializer);
+        let mut var_moved = <Vec<crate::api::library::TrackMove>>::sse_decode(deserializer);
+        return crate::api::library::LibraryDiff {
+            added: var_added,
+            removed: var_removed,
+            modified: var_modified,
+            moved: var_moved,
+        };
+    }
+}
+
+impl SseDecode for crate::api::watcher::LibraryEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_field0 = <crate::api::library::LibraryTrack>::sse_decode(deserializer);
+                return crate::api::watcher::LibraryEvent::TrackAdded(var_field0);
+            }
+            1 => {
+                let mut var_id = <usize>::sse_decode(deserializer);
+                return crate::api::watcher::LibraryEvent::TrackRemoved { id: var_id };
+            }
+            2 => {
+                let mut var_field0 = <crate::api::library::LibraryTrack>::sse_decode(deserializer);
+                return crate::api::watcher::LibraryEvent::TrackChanged(var_field0);
+            }
+            3 => {
+                let mut var_from = <crate::api::path::TrackPath>::sse_decode(deserializer);
+                let mut var_track = <crate::api::library::LibraryTrack>::sse_decode(deserializer);
+                return crate::api::watcher::LibraryEvent::TrackMoved {
+                    from: var_from,
+                    track: var_track,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::config::LibraryRoot {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_path = <crate::api::path::TrackPath>::sse_decode(deserializer);
+        let mut var_enabled = <bool>::sse_decode(deserializer);
+        return crate::api::config::LibraryRoot {
+            path: var_path,
+            enabled: var_enabled,
+        };
+    }
+}
+
+impl SseDecode for crate::api::library::LibraryTrack {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_track = <crate::api::utils::Track>::sse_decode(deserializer);
+        let mut var_title = <Option<String>>::sse_decode(deserializer);
+        let mut var_artist = <Option<String>>::sse_decode(deserializer);
+        let mut var_album = <Option<String>>::sse_decode(deserializer);
+        let mut var_albumArtist = <Option<String>>::sse_decode(deserializer);
+        let mut var_genre = <Option<String>>::sse_decode(deserializer);
+        let mut var_year = <Option<u32>>::sse_decode(deserializer);
+        let mut var_trackNumber = <Option<u32>>::sse_decode(deserializer);
+        let mut var_discNumber = <Option<u32>>::sse_decode(deserializer);
+        let mut var_durationMs = <Option<u64>>::sse_decode(deserializer);
+        return crate::api::library::LibraryTrack {
+            track: var_track,
+            title: var_title,
+            artist: var_artist,
+            album: var_album,
+            album_artist: var_albumArtist,
+            genre: var_genre,
+            year: var_year,
+            track_number: var_trackNumber,
+            disc_number: var_discNumber,
+            duration_ms: var_durationMs,
+        };
+    }
+}
+
+impl SseDecode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<String>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::library::Album> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::library::Album>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::batch_edit::BatchFile> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::batch_edit::BatchFile>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::batch_edit::BatchFileResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::batch_edit::BatchFileResult>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::batch_edit::FieldChange> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::batch_edit::FieldChange>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::config::LibraryRoot> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::config::LibraryRoot>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::library::LibraryTrack> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::library::LibraryTrack>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::loudness::LoudnessFailure> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::loudness::LoudnessFailure>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::lyrics::LyricLine> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::lyrics::LyricLine>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::lyrics::LyricWord> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::lyrics::LyricWord>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::lyrics::Lyrics> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::lyrics::Lyrics>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::metadata::MetadataField> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::metadata::MetadataField>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<Option<crate::api::thumbnail::Thumbnail>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<Option<crate::api::thumbnail::Thumbnail>>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::artwork::PictureInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::artwork::PictureInfo>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u64>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u8>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::scan::SkippedFile> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::scan::SkippedFile>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::utils::Track> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::utils::Track>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::loudness::TrackLoudness> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::loudness::TrackLoudness>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::library::TrackMove> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::library::TrackMove>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::path::TrackPath> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::path::TrackPath>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for crate::api::loudness::LoudnessFailure {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_path = <crate::api::path::TrackPath>::sse_decode(deserializer);
+        let mut var_error = <String>::sse_decode(deserializer);
+        return crate::api::loudness::LoudnessFailure {
+            path: var_path,
+            error: var_error,
+        };
+    }
+}
+
+impl SseDecode for crate::api::loudness::LoudnessReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_tracks = <Vec<crate::api::loudness::TrackLoudness>>::sse_decode(deserializer);
+        let mut var_album = <Option<crate::api::loudness::AlbumLoudness>>::sse_decode(deserializer);
+        let mut var_failed = <Vec<crate::api::loudness::LoudnessFailure>>::sse_decode(deserializer);
+        return crate::api::loudness::LoudnessReport {
+            tracks: var_tracks,
+            album: var_album,
+            failed: var_failed,
+        };
+    }
+}
+
+impl SseDecode for crate::api::lyrics::LyricLine {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_startMs = <Option<u64>>::sse_decode(deserializer);
+        let mut var_text = <String>::sse_decode(deserializer);
+        let mut var_words = <Vec<crate::api::lyrics::LyricWord>>::sse_decode(deserializer);
+        return crate::api::lyrics::LyricLine {
+            start_ms: var_startMs,
+            text: var_text,
+            words: var_words,
+        };
+    }
+}
+
+impl SseDecode for crate::api::lyrics::LyricWord {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_startMs = <u64>::sse_decode(deserializer);
+        let mut var_endMs = <Option<u64>>::sse_decode(deserializer);
+        let mut var_text = <String>::sse_decode(deserializer);
+        return crate::api::lyrics::LyricWord {
+            start_ms: var_startMs,
+            end_ms: var_endMs,
+            text: var_text,
+        };
+    }
+}
+
+impl SseDecode for crate::api::lyrics::Lyrics {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_source = <crate::api::lyrics::LyricsSource>::sse_decode(deserializer);
+        let mut var_language = <Option<String>>::sse_decode(deserializer);
+        let mut var_synced = <bool>::sse_decode(deserializer);
+        let mut var_offsetMs = <i64>::sse_decode(deserializer);
+        let mut var_lines = <Vec<crate::api::lyrics::LyricLine>>::sse_decode(deserializer);
+        let mut var_timeline = <LyricsTimeline>::sse_decode(deserializer);
+        return crate::api::lyrics::Lyrics {
+            source: var_source,
+            language: var_language,
+            synced: var_synced,
+            offset_ms: var_offsetMs,
+            lines: var_lines,
+            timeline: var_timeline,
+        };
+    }
+}
+
+impl SseDecode for crate::api::lyrics::LyricsPosition {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_line = <Option<usize>>::sse_decode(deserializer);
+        let mut var_word = <Option<usize>>::sse_decode(deserializer);
+        let mut var_wordProgress = <f64>::sse_decode(deserializer);
+        return crate::api::lyrics::LyricsPosition {
+            line: var_line,
+            word: var_word,
+            word_progress: var_wordProgress,
+        };
+    }
+}
+
+impl SseDecode for crate::api::lyrics::LyricsSource {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::api::lyrics::LyricsSource::Tag;
+            }
+            1 => {
+                return crate::api::lyrics::LyricsSource::SyncedTag;
+            }
+            2 => {
+                let mut var_path = <crate::api::path::TrackPath>::sse_decode(deserializer);
+                return crate::api::lyrics::LyricsSource::Sidecar { path: var_path };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::metadata::Metadata {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_art = <Option<Vec<u8>>>::sse_decode(deserializer);
+        let mut var_artSource = <Option<crate::api::artwork::ArtSource>>::sse_decode(deserializer);
+        let mut var_title = <Option<String>>::sse_decode(deserializer);
+        let mut var_artist = <Option<String>>::sse_decode(deserializer);
+        let mut var_album = <Option<String>>::sse_decode(deserializer);
+        let mut var_albumArtist = <Option<String>>::sse_decode(deserializer);
+        let mut var_trackNumber = <Option<u32>>::sse_decode(deserializer);
+        let mut var_trackTotal = <Option<u32>>::sse_decode(deserializer);
+        let mut var_discNumber = <Option<u32>>::sse_decode(deserializer);
+        let mut var_discTotal = <Option<u32>>::sse_decode(deserializer);
+        let mut var_year = <Option<u32>>::sse_decode(deserializer);
+        let mut var_genre = <Option<String>>::sse_decode(deserializer);
+        let mut var_composer = <Option<String>>::sse_decode(deserializer);
+        let mut var_comment = <Option<String>>::sse_decode(deserializer);
+        let mut var_titleSort = <Option<String>>::sse_decode(deserializer);
+        let mut var_artistSort = <Option<String>>::sse_decode(deserializer);
+        let mut var_albumSort = <Option<String>>::sse_decode(deserializer);
+        let mut var_albumArtistSort = <Option<String>>::sse_decode(deserializer);
+        let mut var_properties = <crate::api::metadata::AudioProperties>::sse_decode(deserializer);
+        let mut var_replayGain = <crate::api::metadata::ReplayGain>::sse_decode(deserializer);
+        let mut var_stamp = <crate::api::library::FileStamp>::sse_decode(deserializer);
+        return crate::api::metadata::Metadata {
+            art: var_art,
+            art_source: var_artSource,
+            title: var_title,
+            artist: var_artist,
+            album: var_album,
+            album_artist: var_albumArtist,
+            track_number: var_trackNumber,
+            track_total: var_trackTotal,
+            disc_number: var_discNumber,
+            disc_total: var_discTotal,
+            year: var_year,
+            genre: var_genre,
+            composer: var_composer,
+            comment: var_comment,
+            title_sort: var_titleSort,
+            artist_sort: var_artistSort,
+            album_sort: var_albumSort,
+            album_artist_sort: var_albumArtistSort,
+            properties: var_properties,
+            replay_gain: var_replayGain,
+            stamp: var_stamp,
+        };
+    }
+}
+
+impl SseDecode for crate::api::metadata::MetadataChanges {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_title = <Option<String>>::sse_decode(deserializer);
+        let mut var_artist = <Option<String>>::sse_decode(deserializer);
+        let mut var_album = <Option<String>>::sse_decode(deserializer);
+        let mut var_albumArtist = <Option<String>>::sse_decode(deserializer);
+        let mut var_trackNumber = <Option<u32>>::sse_decode(deserializer);
+        let mut var_trackTotal = <Option<u32>>::sse_decode(deserializer);
+        let mut var_discNumber = <Option<u32>>::sse_decode(deserializer);
+        let mut var_discTotal = <Option<u32>>::sse_decode(deserializer);
+        let mut var_year = <Option<u32>>::sse_decode(deserializer);
+        let mut var_genre = <Option<String>>::sse_decode(deserializer);
+        let mut var_composer = <Option<String>>::sse_decode(deserializer);
+        let mut var_comment = <Option<String>>::sse_decode(deserializer);
+        let mut var_lyrics = <Option<String>>::sse_decode(deserializer);
+        let mut var_clear = <Vec<crate::api::metadata::MetadataField>>::sse_decode(deserializer);
+        return crate::api::metadata::MetadataChanges {
+            title: var_title,
+            artist: var_artist,
+            album: var_album,
+            album_artist: var_albumArtist,
+            track_number: var_trackNumber,
+            track_total: var_trackTotal,
+            disc_number: var_discNumber,
+            disc_total: var_discTotal,
+            year: var_year,
+            genre: var_genre,
+            composer: var_composer,
+            comment: var_comment,
+            lyrics: var_lyrics,
+            clear: var_clear,
+        };
+    }
+}
+
+impl SseDecode for crate::api::metadata::MetadataError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::api::metadata::MetadataError::NotFound;
+            }
+            1 => {
+                return crate::api::metadata::MetadataError::UnsupportedFormat;
+            }
+            2 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::metadata::MetadataError::CorruptTag(var_field0);
+            }
+            3 => {
+                return crate::api::metadata::MetadataError::Modified;
+            }
+            4 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::metadata::MetadataError::Io(var_field0);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::metadata::MetadataField {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::metadata::MetadataField::Title,
+            1 => crate::api::metadata::MetadataField::Artist,
+            2 => crate::api::metadata::MetadataField::Album,
+            3 => crate::api::metadata::MetadataField::AlbumArtist,
+            4 => crate::api::metadata::MetadataField::TrackNumber,
+            5 => crate::api::metadata::MetadataField::TrackTotal,
+            6 => crate::api::metadata::MetadataField::DiscNumber,
+            7 => crate::api::metadata::MetadataField::DiscTotal,
+            8 => crate::api::metadata::MetadataField::Year,
+            9 => crate::api::metadata::MetadataField::Genre,
+            10 => crate::api::metadata::MetadataField::Composer,
+            11 => crate::api::metadata::MetadataField::Comment,
+            12 => crate::api::metadata::MetadataField::Lyrics,
+            _ => unreachable!("Invalid variant for MetadataField: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<String>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::loudness::AlbumLoudness> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::loudness::AlbumLoudness>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::artwork::ArtSource> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::artwork::ArtSource>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<f64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<f64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::library::FileStamp> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::library::FileStamp>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::library::LibraryTrack> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::library::LibraryTrack>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::metadata::MetadataError> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::metadata::MetadataError>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::palette::Palette> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::palette::Palette>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::batch_edit::Renumber> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::batch_edit::Renumber>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::palette::Swatch> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::palette::Swatch>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::thumbnail::Thumbnail> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::thumbnail::Thumbnail>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::loudness::TrackLoudness> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::loudness::TrackLoudness>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u8>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<usize> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<usize>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<Vec<u8>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<Vec<u8>>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for crate::api::palette::Palette {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_dominant = <crate::api::palette::Swatch>::sse_decode(deserializer);
+        let mut var_vibrant = <Option<crate::api::palette::Swatch>>::sse_decode(deserializer);
+        let mut var_muted = <Option<crate::api::palette::Swatch>>::sse_decode(deserializer);
+        return crate::api::palette::Palette {
+            dominant: var_dominant,
+            vibrant: var_vibrant,
+            muted: var_muted,
+        };
+    }
+}
+
+impl SseDecode for crate::api::artwork::PictureInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_index = <usize>::sse_decode(deserializer);
+        let mut var_pictureType = <crate::api::artwork::PictureType>::sse_decode(deserializer);
+        let mut var_mimeType = <Option<String>>::sse_decode(deserializer);
+        let mut var_width = <Option<u32>>::sse_decode(deserializer);
+        let mut var_height = <Option<u32>>::sse_decode(deserializer);
+        let mut var_description = <Option<String>>::sse_decode(deserializer);
+        let mut var_size = <usize>::sse_decode(deserializer);
+        return crate::api::artwork::PictureInfo {
+            index: var_index,
+            picture_type: var_pictureType,
+            mime_type: var_mimeType,
+            width: var_width,
+            height: var_height,
+            description: var_description,
+            size: var_size,
+        };
+    }
+}
+
+impl SseDecode for crate::api::artwork::PictureType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::api::artwork::PictureType::Other;
+            }
+            1 => {
+                return crate::api::artwork::PictureType::Icon;
+            }
+            2 => {
+                return crate::api::artwork::PictureType::OtherIcon;
+            }
+            3 => {
+                return crate::api::artwork::PictureType::CoverFront;
+            }
+            4 => {
+                return crate::api::artwork::PictureType::CoverBack;
+            }
+            5 => {
+                return crate::api::artwork::PictureType::Leaflet;
+            }
+            6 => {
+                return crate::api::artwork::PictureType::Media;
+            }
+            7 => {
+                return crate::api::artwork::PictureType::LeadArtist;
+            }
+            8 => {
+                return crate::api::artwork::PictureType::Artist;
+            }
+            9 => {
+                return crate::api::artwork::PictureType::Conductor;
+            }
+            10 => {
+                return crate::api::artwork::PictureType::Band;
+            }
+            11 => {
+                return crate::api::artwork::PictureType::Composer;
+            }
+            12 => {
+                return crate::api::artwork::PictureType::Lyricist;
+            }
+            13 => {
+                return crate::api::artwork::PictureType::RecordingLocation;
+            }
+            14 => {
+                return crate::api::artwork::PictureType::DuringRecording;
+            }
+            15 => {
+                return crate::api::artwork::PictureType::DuringPerformance;
+            }
+            16 => {
+                return crate::api::artwork::PictureType::ScreenCapture;
+            }
+            17 => {
+                return crate::api::artwork::PictureType::BrightFish;
+            }
+            18 => {
+                return crate::api::artwork::PictureType::Illustration;
+            }
+            19 => {
+                return crate::api::artwork::PictureType::BandLogo;
+            }
+            20 => {
+                return crate::api::artwork::PictureType::PublisherLogo;
+            }
+            21 => {
+                let mut var_field0 = <u8>::sse_decode(deserializer);
+                return crate::api::artwork::PictureType::Undefined(var_field0);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::batch_edit::Renumber {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_start = <u32>::sse_decode(deserializer);
+        let mut var_setTotal = <bool>::sse_decode(deserializer);
+        return crate::api::batch_edit::Renumber {
+            start: var_start,
+            set_total: var_setTotal,
+        };
+    }
+}
+
+impl SseDecode for crate::api::metadata::ReplayGain {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_trackGainDb = <Option<f64>>::sse_decode(deserializer);
+        let mut var_trackPeak = <Option<f64>>::sse_decode(deserializer);
+        let mut var_albumGainDb = <Option<f64>>::sse_decode(deserializer);
+        let mut var_albumPeak = <Option<f64>>::sse_decode(deserializer);
+        return crate::api::metadata::ReplayGain {
+            track_gain_db: var_trackGainDb,
+            track_peak: var_trackPeak,
+            album_gain_db: var_albumGainDb,
+            album_peak: var_albumPeak,
+        };
+    }
+}
+
+impl SseDecode for crate::api::loudness::ReplayGainMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::api::loudness::ReplayGainMode::Off;
+            }
+            1 => {
+                return crate::api::loudness::ReplayGainMode::Track;
+            }
+            2 => {
+                return crate::api::loudness::ReplayGainMode::Album;
+            }
+            3 => {
+                let mut var_albumInOrder = <bool>::sse_decode(deserializer);
+                return crate::api::loudness::ReplayGainMode::Smart {
+                    album_in_order: var_albumInOrder,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::scan::ScanEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_field0 = <crate::api::scan::ScanProgress>::sse_decode(deserializer);
+                return crate::api::scan::ScanEvent::Progress(var_field0);
+            }
+            1 => {
+                let mut var_field0 = <crate::api::scan::SkippedFile>::sse_decode(deserializer);
+                return crate::api::scan::ScanEvent::Skipped(var_field0);
+            }
+            2 => {
+                let mut var_field0 = <crate::api::library::LibraryDiff>::sse_decode(deserializer);
+                return crate::api::scan::ScanEvent::Finished(var_field0);
+            }
+            3 => {
+                return crate::api::scan::ScanEvent::Cancelled;
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::scan::ScanPhase {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::scan::ScanPhase::Discovering,
+            1 => crate::api::scan::ScanPhase::Detecting,
+            2 => crate::api::scan::ScanPhase::ReadingTags,
+            3 => crate::api::scan::ScanPhase::Saving,
+            _ => unreachable!("Invalid variant for ScanPhase: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::scan::ScanProgress {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_phase = <crate::api::scan::ScanPhase>::sse_decode(deserializer);
+        let mut var_filesSeen = <usize>::sse_decode(deserializer);
+        let mut var_filesDone = <usize>::sse_decode(deserializer);
+        let mut var_filesTotal = <usize>::sse_decode(deserializer);
+        let mut var_currentPath = <Option<String>>::sse_decode(deserializer);
+        return crate::api::scan::ScanProgress {
+            phase: var_phase,
+            files_seen: var_filesSeen,
+            files_done: var_filesDone,
+            files_total: var_filesTotal,
+            current_path: var_currentPath,
+        };
+    }
+}
+
+impl SseDecode for crate::api::scan::ScanReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_tracks = <Vec<crate::api::utils::Track>>::sse_decode(deserializer);
+        let mut var_skipped = <Vec<crate::api::scan::SkippedFile>>::sse_decode(deserializer);
+        return crate::api::scan::ScanReport {
+            tracks: var_tracks,
+            skipped: var_skipped,
+        };
+    }
+}
+
+impl SseDecode for crate::api::scan::SkipReason {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::api::scan::SkipReason::PermissionDenied;
+            }
+            1 => {
+                return crate::api::scan::SkipReason::UnsupportedFormat;
+            }
+            2 => {
+                return crate::api::scan::SkipReason::CorruptHeader;
+            }
+            3 => {
+                return crate::api::scan::SkipReason::SymlinkLoop;
+            }
+            4 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::scan::SkipReason::Io(var_field0);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::scan::SkippedFile {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_path = <crate::api::path::TrackPath>::sse_decode(deserializer);
+        let mut var_reason = <crate::api::scan::SkipReason>::sse_decode(deserializer);
+        return crate::api::scan::SkippedFile {
+            path: var_path,
+            reason: var_reason,
+        };
+    }
+}
+
+impl SseDecode for crate::api::palette::Swatch {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_color = <u32>::sse_decode(deserializer);
+        let mut var_foreground = <u32>::sse_decode(deserializer);
+        let mut var_population = <f64>::sse_decode(deserializer);
+        return crate::api::palette::Swatch {
+            color: var_color,
+            foreground: var_foreground,
+            population: var_population,
+        };
+    }
+}
+
+impl SseDecode for crate::api::thumbnail::Thumbnail {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_data = <Vec<u8>>::sse_decode(deserializer);
+        let mut var_artHash = <String>::sse_decode(deserializer);
+        return crate::api::thumbnail::Thumbnail {
+            data: var_data,
+            art_hash: var_artHash,
+        };
+    }
+}
+
+impl SseDecode for crate::api::utils::Track {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_path = <crate::api::path::TrackPath>::sse_decode(deserializer);
+        let mut var_id = <Option<usize>>::sse_decode(deserializer);
+        let mut var_format = <crate::api::utils::AudioFormat>::sse_decode(deserializer);
+        return crate::api::utils::Track {
+            name: var_name,
+            path: var_path,
+            id: var_id,
+            format: var_format,
+        };
+    }
+}
+
+impl SseDecode for crate::api::loudness::TrackLoudness {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_path = <crate::api::path::TrackPath>::sse_decode(deserializer);
+        let mut var_integratedLufs = <f64>::sse_decode(deserializer);
+        let mut var_loudnessRangeLu = <f64>::sse_decode(deserializer);
+        let mut var_truePeak = <f64>::sse_decode(deserializer);
+        let mut var_trackGainDb = <f64>::sse_decode(deserializer);
+        let mut var_albumGainDb = <Option<f64>>::sse_decode(deserializer);
+        let mut var_albumTruePeak = <Option<f64>>::sse_decode(deserializer);
+        let mut var_tagError = <Option<String>>::sse_decode(deserializer);
+        return crate::api::loudness::TrackLoudness {
+            path: var_path,
+            integrated_lufs: var_integratedLufs,
+            loudness_range_lu: var_loudnessRangeLu,
+            true_peak: var_truePeak,
+            track_gain_db: var_trackGainDb,
+            album_gain_db: var_albumGainDb,
+            album_true_peak: var_albumTruePeak,
+            tag_error: var_tagError,
+        };
+    }
+}
+
+impl SseDecode for crate::api::library::TrackMove {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_from = <crate::api::path::TrackPath>::sse_decode(deserializer);
+        let mut var_track = <crate::api::library::LibraryTrack>::sse_decode(deserializer);
+        return crate::api::library::TrackMove {
+            from: var_from,
+            track: var_track,
+        };
+    }
+}
+
+impl SseDecode for crate::api::path::TrackPath {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_raw = <Vec<u8>>::sse_decode(deserializer);
+        let mut var_display = <String>::sse_decode(deserializer);
+        return crate::api::path::TrackPath {
+            raw: var_raw,
+            display: var_display,
+        };
+    }
+}
+
+impl SseDecode for u32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u8 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap()
+    }
+}
+
+impl SseDecode for () {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+}
+
+impl SseDecode for usize {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap() as _
+    }
+}
+
+fn pde_ffi_dispatcher_primary_impl(
+    func_id: i32,
+    port: flutter_rust_bridge::for_generated::MessagePort,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        2 => wire_get_picture_data_impl(port, ptr, rust_vec_len, data_len),
+        1 => wire_get_pictures_impl(port, ptr, rust_vec_len, data_len),
+        4 => wire_apply_batch_edit_impl(port, ptr, rust_vec_len, data_len),
+        3 => wire_preview_batch_edit_impl(port, ptr, rust_vec_len, data_len),
+        5 => wire_undo_batch_edit_impl(port, ptr, rust_vec_len, data_len),
+        7 => wire_add_library_root_impl(port, ptr, rust_vec_len, data_len),
+        10 => wire_get_ignore_patterns_impl(port, ptr, rust_vec_len, data_len),
+        6 => wire_list_library_roots_impl(port, ptr, rust_vec_len, data_len),
+        8 => wire_remove_library_root_impl(port, ptr, rust_vec_len, data_len),
+        11 => wire_set_ignore_patterns_impl(port, ptr, rust_vec_len, data_len),
+        9 => wire_set_library_root_enabled_impl(port, ptr, rust_vec_len, data_len),
+        17 => wire_get_album_tracks_impl(port, ptr, rust_vec_len, data_len),
+        16 => wire_get_albums_impl(port, ptr, rust_vec_len, data_len),
+        14 => wire_get_library_track_impl(port, ptr, rust_vec_len, data_len),
+        13 => wire_get_library_tracks_impl(port, ptr, rust_vec_len, data_len),
+        12 => wire_rescan_library_impl(port, ptr, rust_vec_len, data_len),
+        15 => wire_search_library_impl(port, ptr, rust_vec_len, data_len),
+        18 => wire_analyze_loudness_impl(port, ptr, rust_vec_len, data_len),
+        19 => wire_get_track_loudness_impl(port, ptr, rust_vec_len, data_len),
+        21 => wire_build_lrc_impl(port, ptr, rust_vec_len, data_len),
+        24 => wire_get_lyrics_impl(port, ptr, rust_vec_len, data_len),
+        22 => wire_nudge_lrc_impl(port, ptr, rust_vec_len, data_len),
+        23 => wire_save_lrc_sidecar_impl(port, ptr, rust_vec_len, data_len),
+        29 => wire_get_audio_properties_impl(port, ptr, rust_vec_len, data_len),
+        27 => wire_get_metadata_impl(port, ptr, rust_vec_len, data_len),
+        28 => wire_write_metadata_impl(port, ptr, rust_vec_len, data_len),
+        30 => wire_get_palette_impl(port, ptr, rust_vec_len, data_len),
+        31 => wire_playback_path_impl(port, ptr, rust_vec_len, data_len),
+        32 => wire_scan_library_impl(port, ptr, rust_vec_len, data_len),
+        33 => wire_scan_library_with_progress_impl(port, ptr, rust_vec_len, data_len),
+        38 => wire_clear_thumbnail_cache_impl(port, ptr, rust_vec_len, data_len),
+        36 => wire_get_thumbnail_impl(port, ptr, rust_vec_len, data_len),
+        37 => wire_get_thumbnails_impl(port, ptr, rust_vec_len, data_len),
+        39 => wire_get_music_files_impl(port, ptr, rust_vec_len, data_len),
+        41 => wire_stop_watching_library_impl(port, ptr, rust_vec_len, data_len),
+        40 => wire_watch_library_impl(port, ptr, rust_vec_len, data_len),
+        _ => unreachable!(),
+    }
+}
+
+fn pde_ffi_dispatcher_sync_impl(
+    func_id: i32,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        20 => wire_replay_gain_volume_impl(ptr, rust_vec_len, data_len),
+        25 => wire_LyricsTimeline_line_index_at_impl(ptr, rust_vec_len, data_len),
+        26 => wire_LyricsTimeline_lyrics_at_impl(ptr, rust_vec_len, data_len),
+        35 => wire_ScanCancelToken_cancel_impl(ptr, rust_vec_len, data_len),
+        34 => wire_ScanCancelToken_new_impl(ptr, rust_vec_len, data_len),
+        _ => unreachable!(),
+    }
+}
+
+// Section: rust2dart
+
+pub struct Local_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockLyricsTimeline(
+    LyricsTimeline,
+);
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for Local_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockLyricsTimeline
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for Local_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockLyricsTimeline
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        Local_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockLyricsTimeline,
+    > for LyricsTimeline
+{
+    fn into_into_dart(
+        self,
+    ) -> Local_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockLyricsTimeline
+    {
+        Local_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockLyricsTimeline(
+            self,
+        )
+    }
+}
+pub struct Local_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken(
+    ScanCancelToken,
+);
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for Local_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for Local_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        Local_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken,
+    > for ScanCancelToken
+{
+    fn into_into_dart(
+        self,
+    ) -> Local_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken
+    {
+        Local_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken(
+            self,
+        )
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::library::Album {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.artist.into_into_dart().into_dart(),
+            self.track_count.into_into_dart().into_dart(),
+            self.duration_ms.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::library::Album {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::library::Album> for crate::api::library::Album {
+    fn into_into_dart(self) -> crate::api::library::Album {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::loudness::AlbumLoudness {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.integrated_lufs.into_into_dart().into_dart(),
+            self.loudness_range_lu.into_into_dart().into_dart(),
+            self.true_peak.into_into_dart().into_dart(),
+            self.gain_db.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::loudness::AlbumLoudness
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::loudness::AlbumLoudness>
+    for crate::api::loudness::AlbumLoudness
+{
+    fn into_into_dart(self) -> crate::api::loudness::AlbumLoudness {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::artwork::ArtSource {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::artwork::ArtSource::Embedded { picture_type } => {
+                [0.into_dart(), picture_type.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::artwork::ArtSource::Folder { path } => {
+                [1.into_dart(), path.into_into_dart().into_dart()].into_dart()
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::artwork::ArtSource
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::artwork::ArtSource>
+    for crate::api::artwork::ArtSource
+{
+    fn into_into_dart(self) -> crate::api::artwork::ArtSource {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::utils::AudioFormat {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Aac => 0.into_dart(),
+            Self::Aiff => 1.into_dart(),
+            Self::Ape => 2.into_dart(),
+            Self::Flac => 3.into_dart(),
+            Self::Mpeg => 4.into_dart(),
+            Self::Mp4 => 5.into_dart(),
+            Self::Mpc => 6.into_dart(),
+            Self::Opus => 7.into_dart(),
+            Self::Vorbis => 8.into_dart(),
+            Self::Speex => 9.into_dart(),
+            Self::Wav => 10.into_dart(),
+            Self::WavPack => 11.into_dart(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::utils::AudioFormat
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::utils::AudioFormat>
+    for crate::api::utils::AudioFormat
+{
+    fn into_into_dart(self) -> crate::api::utils::AudioFormat {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::metadata::AudioProperties {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.duration_ms.into_into_dart().into_dart(),
+            self.overall_bitrate.into_into_dart().into_dart(),
+            self.audio_bitrate.into_into_dart().into_dart(),
+            self.sample_rate.into_into_dart().into_dart(),
+            self.bit_depth.into_into_dart().into_dart(),
+            self.channels.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::metadata::AudioProperties
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::metadata::AudioProperties>
+    for crate::api::metadata::AudioProperties
+{
+    fn into_into_dart(self) -> crate::api::metadata::AudioProperties {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::batch_edit::BatchEdit {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.changes.into_into_dart().into_dart(),
+            self.renumber.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::batch_edit::BatchEdit
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::batch_edit::BatchEdit>
+    for crate::api::batch_edit::BatchEdit
+{
+    fn into_into_dart(self) -> crate::api::batch_edit::BatchEdit {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::batch_edit::BatchFile {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.path.into_into_dart().into_dart(),
+            self.stamp.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::batch_edit::BatchFile
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::batch_edit::BatchFile>
+    for crate::api::batch_edit::BatchFile
+{
+    fn into_into_dart(self) -> crate::api::batch_edit::BatchFile {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::batch_edit::BatchFileResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.path.into_into_dart().into_dart(),
+            self.stamp.into_into_dart().into_dart(),
+            self.changes.into_into_dart().into_dart(),
+            self.error.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::batch_edit::BatchFileResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::batch_edit::BatchFileResult>
+    for crate::api::batch_edit::BatchFileResult
+{
+    fn into_into_dart(self) -> crate::api::batch_edit::BatchFileResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::batch_edit::BatchOutcome {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.journal_id.into_into_dart().into_dart(),
+            self.results.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::batch_edit::BatchOutcome
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::batch_edit::BatchOutcome>
+    for crate::api::batch_edit::BatchOutcome
+{
+    fn into_into_dart(self) -> crate::api::batch_edit::BatchOutcome {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::batch_edit::FieldChange {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.field.into_into_dart().into_dart(),
+            self.before.into_into_dart().into_dart(),
+            self.after.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::batch_edit::FieldChange
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::batch_edit::FieldChange>
+    for crate::api::batch_edit::FieldChange
+{
+    fn into_into_dart(self) -> crate::api::batch_edit::FieldChange {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::library::FileStamp {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.size.into_into_dart().into_dart(),
+            self.mtime.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::library::FileStamp
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::library::FileStamp>
+    for crate::api::library::FileStamp
+{
+    fn into_into_dart(self) -> crate::api::library::FileStamp {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::library::LibraryDiff {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.added.into_into_dart().into_dart(),
+            self.removed.into_into_dart().into_dart(),
+            self.modified.into_into_dart().into_dart(),
+            self.moved.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::library::LibraryDiff
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::library::LibraryDiff>
+    for crate::api::library::LibraryDiff
+{
+    fn into_into_dart(self) -> crate::api::library::LibraryDiff {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::watcher::LibraryEvent {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::watcher::LibraryEvent::TrackAdded(field0) => {
+                [0.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::watcher::LibraryEvent::TrackRemoved { id } => {
+                [1.into_dart(), id.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::watcher::LibraryEvent::TrackChanged(field0) => {
+                [2.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::watcher::LibraryEvent::TrackMoved { from, track } => [
+                3.into_dart(),
+                from.into_into_dart().into_dart(),
+                track.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::watcher::LibraryEvent
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::watcher::LibraryEvent>
+    for crate::api::watcher::LibraryEvent
+{
+    fn into_into_dart(self) -> crate::api::watcher::LibraryEvent {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::config::LibraryRoot {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.path.into_into_dart().into_dart(),
+            self.enabled.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::config::LibraryRoot
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::config::LibraryRoot>
+    for crate::api::config::LibraryRoot
+{
+    fn into_into_dart(self) -> crate::api::config::LibraryRoot {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::library::LibraryTrack {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.track.into_into_dart().into_dart(),
+            self.title.into_into_dart().into_dart(),
+            self.artist.into_into_dart().into_dart(),
+            self.album.into_into_dart().into_dart(),
+            self.album_artist.into_into_dart().into_dart(),
+            self.genre.into_into_dart().into_dart(),
+            self.year.into_into_dart().into_dart(),
+            self.track_number.into_into_dart().into_dart(),
+            self.disc_number.into_into_dart().into_dart(),
+            self.duration_ms.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::library::LibraryTrack
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::library::LibraryTrack>
+    for crate::api::library::LibraryTrack
+{
+    fn into_into_dart(self) -> crate::api::library::LibraryTrack {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::loudness::LoudnessFailure {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.path.into_into_dart().into_dart(),
+            self.error.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::loudness::LoudnessFailure
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::loudness::LoudnessFailure>
+    for crate::api::loudness::LoudnessFailure
+{
+    fn into_into_dart(self) -> crate::api::loudness::LoudnessFailure {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::loudness::LoudnessReport {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.tracks.into_into_dart().into_dart(),
+            self.album.into_into_dart().into_dart(),
+            self.failed.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::loudness::LoudnessReport
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::loudness::LoudnessReport>
+    for crate::api::loudness::LoudnessReport
+{
+    fn into_into_dart(self) -> crate::api::loudness::LoudnessReport {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::lyrics::LyricLine {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.start_ms.into_into_dart().into_dart(),
+            self.text.into_into_dart().into_dart(),
+            self.words.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::lyrics::LyricLine {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::lyrics::LyricLine>
+    for crate::api::lyrics::LyricLine
+{
+    fn into_into_dart(self) -> crate::api::lyrics::LyricLine {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::lyrics::LyricWord {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.start_ms.into_into_dart().into_dart(),
+            self.end_ms.into_into_dart().into_dart(),
+            self.text.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::lyrics::LyricWord {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::lyrics::LyricWord>
+    for crate::api::lyrics::LyricWord
+{
+    fn into_into_dart(self) -> crate::api::lyrics::LyricWord {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::lyrics::Lyrics {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.source.into_into_dart().into_dart(),
+            self.language.into_into_dart().into_dart(),
+            self.synced.into_into_dart().into_dart(),
+            self.offset_ms.into_into_dart().into_dart(),
+            self.lines.into_into_dart().into_dart(),
+            self.timeline.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::lyrics::Lyrics {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::lyrics::Lyrics> for crate::api::lyrics::Lyrics {
+    fn into_into_dart(self) -> crate::api::lyrics::Lyrics {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::lyrics::LyricsPosition {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.line.into_into_dart().into_dart(),
+            self.word.into_into_dart().into_dart(),
+            self.word_progress.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::lyrics::LyricsPosition
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::lyrics::LyricsPosition>
+    for crate::api::lyrics::LyricsPosition
+{
+    fn into_into_dart(self) -> crate::api::lyrics::LyricsPosition {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::lyrics::LyricsSource {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::lyrics::LyricsSource::Tag => [0.into_dart()].into_dart(),
+            crate::api::lyrics::LyricsSource::SyncedTag => [1.into_dart()].into_dart(),
+            crate::api::lyrics::LyricsSource::Sidecar { path } => {
+                [2.into_dart(), path.into_into_dart().into_dart()].into_dart()
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::lyrics::LyricsSource
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::lyrics::LyricsSource>
+    for crate::api::lyrics::LyricsSource
+{
+    fn into_into_dart(self) -> crate::api::lyrics::LyricsSource {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::metadata::Metadata {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.art.into_into_dart().into_dart(),
+            self.art_source.into_into_dart().into_dart(),
+            self.title.into_into_dart().into_dart(),
+            self.artist.into_into_dart().into_dart(),
+            self.album.into_into_dart().into_dart(),
+            self.album_artist.into_into_dart().into_dart(),
+            self.track_number.into_into_dart().into_dart(),
+            self.track_total.into_into_dart().into_dart(),
+            self.disc_number.into_into_dart().into_dart(),
+            self.disc_total.into_into_dart().into_dart(),
+            self.year.into_into_dart().into_dart(),
+            self.genre.into_into_dart().into_dart(),
+            self.composer.into_into_dart().into_dart(),
+            self.comment.into_into_dart().into_dart(),
+            self.title_sort.into_into_dart().into_dart(),
+            self.artist_sort.into_into_dart().into_dart(),
+            self.album_sort.into_into_dart().into_dart(),
+            self.album_artist_sort.into_into_dart().into_dart(),
+            self.properties.into_into_dart().into_dart(),
+            self.replay_gain.into_into_dart().into_dart(),
+            self.stamp.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::metadata::Metadata
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::metadata::Metadata>
+    for crate::api::metadata::Metadata
+{
+    fn into_into_dart(self) -> crate::api::metadata::Metadata {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::metadata::MetadataChanges {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.title.into_into_dart().into_dart(),
+            self.artist.into_into_dart().into_dart(),
+            self.album.into_into_dart().into_dart(),
+            self.album_artist.into_into_dart().into_dart(),
+            self.track_number.into_into_dart().into_dart(),
+            self.track_total.into_into_dart().into_dart(),
+            self.disc_number.into_into_dart().into_dart(),
+            self.disc_total.into_into_dart().into_dart(),
+            self.year.into_into_dart().into_dart(),
+            self.genre.into_into_dart().into_dart(),
+            self.composer.into_into_dart().into_dart(),
+            self.comment.into_into_dart().into_dart(),
+            self.lyrics.into_into_dart().into_dart(),
+            self.clear.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::metadata::MetadataChanges
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::metadata::MetadataChanges>
+    for crate::api::metadata::MetadataChanges
+{
+    fn into_into_dart(self) -> crate::api::metadata::MetadataChanges {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::metadata::MetadataError {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::metadata::MetadataError::NotFound => [0.into_dart()].into_dart(),
+            crate::api::metadata::MetadataError::UnsupportedFormat => [1.into_dart()].into_dart(),
+            crate::api::metadata::MetadataError::CorruptTag(field0) => {
+                [2.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::metadata::MetadataError::Modified => [3.into_dart()].into_dart(),
+            crate::api::metadata::MetadataError::Io(field0) => {
+                [4.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::metadata::MetadataError
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::metadata::MetadataError>
+    for crate::api::metadata::MetadataError
+{
+    fn into_into_dart(self) -> crate::api::metadata::MetadataError {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::metadata::MetadataField {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Title => 0.into_dart(),
+            Self::Artist => 1.into_dart(),
+            Self::Album => 2.into_dart(),
+            Self::AlbumArtist => 3.into_dart(),
+            Self::TrackNumber => 4.into_dart(),
+            Self::TrackTotal => 5.into_dart(),
+            Self::DiscNumber => 6.into_dart(),
+            Self::DiscTotal => 7.into_dart(),
+            Self::Year => 8.into_dart(),
+            Self::Genre => 9.into_dart(),
+            Self::Composer => 10.into_dart(),
+            Self::Comment => 11.into_dart(),
+            Self::Lyrics => 12.into_dart(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::metadata::MetadataField
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::metadata::MetadataField>
+    for crate::api::metadata::MetadataField
+{
+    fn into_into_dart(self) -> crate::api::metadata::MetadataField {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::palette::Palette {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.dominant.into_into_dart().into_dart(),
+            self.vibrant.into_into_dart().into_dart(),
+            self.muted.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::palette::Palette {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::palette::Palette>
+    for crate::api::palette::Palette
+{
+    fn into_into_dart(self) -> crate::api::palette::Palette {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::artwork::PictureInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.index.into_into_dart().into_dart(),
+            self.picture_type.into_into_dart().into_dart(),
+            self.mime_type.into_into_dart().into_dart(),
+            self.width.into_into_dart().into_dart(),
+            self.height.into_into_dart().into_dart(),
+            self.description.into_into_dart().into_dart(),
+            self.size.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::artwork::PictureInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::artwork::PictureInfo>
+    for crate::api::artwork::PictureInfo
+{
+    fn into_into_dart(self) -> crate::api::artwork::PictureInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::artwork::PictureType {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::artwork::PictureType::Other => [0.into_dart()].into_dart(),
+            crate::api::artwork::PictureType::Icon => [1.into_dart()].into_dart(),
+            crate::api::artwork::PictureType::OtherIcon => [2.into_dart()].into_dart(),
+            crate::api::artwork::PictureType::CoverFront => [3.into_dart()].into_dart(),
+            crate::api::artwork::PictureType::CoverBack => [4.into_dart()].into_dart(),
+            crate::api::artwork::PictureType::Leaflet => [5.into_dart()].into_dart(),
+            crate::api::artwork::PictureType::Media => [6.into_dart()].into_dart(),
+            crate::api::artwork::PictureType::LeadArtist => [7.into_dart()].into_dart(),
+            crate::api::artwork::PictureType::Artist => [8.into_dart()].into_dart(),
+            crate::api::artwork::PictureType::Conductor => [9.into_dart()].into_dart(),
+            crate::api::artwork::PictureType::Band => [10.into_dart()].into_dart(),
+            crate::api::artwork::PictureType::Composer => [11.into_dart()].into_dart(),
+            crate::api::artwork::PictureType::Lyricist => [12.into_dart()].into_dart(),
+            crate::api::artwork::PictureType::RecordingLocation => [13.into_dart()].into_dart(),
+            crate::api::artwork::PictureType::DuringRecording => [14.into_dart()].into_dart(),
+            crate::api::artwork::PictureType::DuringPerformance => [15.into_dart()].into_dart(),
+            crate::api::artwork::PictureType::ScreenCapture => [16.into_dart()].into_dart(),
+            crate::api::artwork::PictureType::BrightFish => [17.into_dart()].into_dart(),
+            crate::api::artwork::PictureType::Illustration => [18.into_dart()].into_dart(),
+            crate::api::artwork::PictureType::BandLogo => [19.into_dart()].into_dart(),
+            crate::api::artwork::PictureType::PublisherLogo => [20.into_dart()].into_dart(),
+            crate::api::artwork::PictureType::Undefined(field0) => {
+                [21.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::artwork::PictureType
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::artwork::PictureType>
+    for crate::api::artwork::PictureType
+{
+    fn into_into_dart(self) -> crate::api::artwork::PictureType {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::batch_edit::Renumber {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.start.into_into_dart().into_dart(),
+            self.set_total.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::batch_edit::Renumber
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::batch_edit::Renumber>
+    for crate::api::batch_edit::Renumber
+{
+    fn into_into_dart(self) -> crate::api::batch_edit::Renumber {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::metadata::ReplayGain {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.track_gain_db.into_into_dart().into_dart(),
+            self.track_peak.into_into_dart().into_dart(),
+            self.album_gain_db.into_into_dart().into_dart(),
+            self.album_peak.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::metadata::ReplayGain
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::metadata::ReplayGain>
+    for crate::api::metadata::ReplayGain
+{
+    fn into_into_dart(self) -> crate::api::metadata::ReplayGain {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::loudness::ReplayGainMode {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::loudness::ReplayGainMode::Off => [0.into_dart()].into_dart(),
+            crate::api::loudness::ReplayGainMode::Track => [1.into_dart()].into_dart(),
+            crate::api::loudness::ReplayGainMode::Album => [2.into_dart()].into_dart(),
+            crate::api::loudness::ReplayGainMode::Smart { album_in_order } => {
+                [3.into_dart(), album_in_order.into_into_dart().into_dart()].into_dart()
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::loudness::ReplayGainMode
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::loudness::ReplayGainMode>
+    for crate::api::loudness::ReplayGainMode
+{
+    fn into_into_dart(self) -> crate::api::loudness::ReplayGainMode {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::scan::ScanEvent {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::scan::ScanEvent::Progress(field0) => {
+                [0.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::scan::ScanEvent::Skipped(field0) => {
+                [1.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::scan::ScanEvent::Finished(field0) => {
+                [2.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::scan::ScanEvent::Cancelled => [3.into_dart()].into_dart(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::scan::ScanEvent {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::scan::ScanEvent>
+    for crate::api::scan::ScanEvent
+{
+    fn into_into_dart(self) -> crate::api::scan::ScanEvent {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::scan::ScanPhase {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Discovering => 0.into_dart(),
+            Self::Detecting => 1.into_dart(),
+            Self::ReadingTags => 2.into_dart(),
+            Self::Saving => 3.into_dart(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::scan::ScanPhase {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::scan::ScanPhase>
+    for crate::api::scan::ScanPhase
+{
+    fn into_into_dart(self) -> crate::api::scan::ScanPhase {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::scan::ScanProgress {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.phase.into_into_dart().into_dart(),
+            self.files_seen.into_into_dart().into_dart(),
+            self.files_done.into_into_dart().into_dart(),
+            self.files_total.into_into_dart().into_dart(),
+            self.current_path.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::scan::ScanProgress
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::scan::ScanProgress>
+    for crate::api::scan::ScanProgress
+{
+    fn into_into_dart(self) -> crate::api::scan::ScanProgress {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::scan::ScanReport {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.tracks.into_into_dart().into_dart(),
+            self.skipped.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::scan::ScanReport {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::scan::ScanReport>
+    for crate::api::scan::ScanReport
+{
+    fn into_into_dart(self) -> crate::api::scan::ScanReport {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::scan::SkipReason {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::scan::SkipReason::PermissionDenied => [0.into_dart()].into_dart(),
+            crate::api::scan::SkipReason::UnsupportedFormat => [1.into_dart()].into_dart(),
+            crate::api::scan::SkipReason::CorruptHeader => [2.into_dart()].into_dart(),
+            crate::api::scan::SkipReason::SymlinkLoop => [3.into_dart()].into_dart(),
+            crate::api::scan::SkipReason::Io(field0) => {
+                [4.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::scan::SkipReason {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::scan::SkipReason>
+    for crate::api::scan::SkipReason
+{
+    fn into_into_dart(self) -> crate::api::scan::SkipReason {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::scan::SkippedFile {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.path.into_into_dart().into_dart(),
+            self.reason.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::scan::SkippedFile {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::scan::SkippedFile>
+    for crate::api::scan::SkippedFile
+{
+    fn into_into_dart(self) -> crate::api::scan::SkippedFile {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::palette::Swatch {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.color.into_into_dart().into_dart(),
+            self.foreground.into_into_dart().into_dart(),
+            self.population.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::palette::Swatch {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::palette::Swatch>
+    for crate::api::palette::Swatch
+{
+    fn into_into_dart(self) -> crate::api::palette::Swatch {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::thumbnail::Thumbnail {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.data.into_into_dart().into_dart(),
+            self.art_hash.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::thumbnail::Thumbnail
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::thumbnail::Thumbnail>
+    for crate::api::thumbnail::Thumbnail
+{
+    fn into_into_dart(self) -> crate::api::thumbnail::Thumbnail {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::utils::Track {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.path.into_into_dart().into_dart(),
+            self.id.into_into_dart().into_dart(),
+            self.format.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::utils::Track {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::utils::Track> for crate::api::utils::Track {
+    fn into_into_dart(self) -> crate::api::utils::Track {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::loudness::TrackLoudness {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.path.into_into_dart().into_dart(),
+            self.integrated_lufs.into_into_dart().into_dart(),
+            self.loudness_range_lu.into_into_dart().into_dart(),
+            self.true_peak.into_into_dart().into_dart(),
+            self.track_gain_db.into_into_dart().into_dart(),
+            self.album_gain_db.into_into_dart().into_dart(),
+            self.album_true_peak.into_into_dart().into_dart(),
+            self.tag_error.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::loudness::TrackLoudness
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::loudness::TrackLoudness>
+    for crate::api::loudness::TrackLoudness
+{
+    fn into_into_dart(self) -> crate::api::loudness::TrackLoudness {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::library::TrackMove {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.from.into_into_dart().into_dart(),
+            self.track.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::library::TrackMove
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::library::TrackMove>
+    for crate::api::library::TrackMove
+{
+    fn into_into_dart(self) -> crate::api::library::TrackMove {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::path::TrackPath {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.raw.into_into_dart().into_dart(),
+            self.display.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::path::TrackPath {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::path::TrackPath>
+    for crate::api::path::TrackPath
+{
+    fn into_into_dart(self) -> crate::api::path::TrackPath {
+        self
+    }
+}
+
+impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(format!("{:?}", self), serializer);
+    }
+}
+
+impl SseEncode for LyricsTimeline {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<flutter_rust_bridge::for_generated::rust_async::RwLock<LyricsTimeline>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
+    }
+}
+
+impl SseEncode for ScanCancelToken {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<flutter_rust_bridge::for_generated::rust_async::RwLock<ScanCancelToken>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
+    }
+}
+
+impl SseEncode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::rust_async::RwLock<LyricsTimeline>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
+    }
+}
+
+impl SseEncode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::rust_async::RwLock<ScanCancelToken>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
+    }
+}
+
+impl SseEncode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+    }
+}
+
+impl SseEncode for crate::api::library::Album {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.name, serializer);
+        <Option<String>>::sse_encode(self.artist, serializer);
+        <usize>::sse_encode(self.track_count, serializer);
+        <u64>::sse_encode(self.duration_ms, serializer);
+    }
+}
+
+impl SseEncode for crate::api::loudness::AlbumLoudness {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <f64>::sse_encode(self.integrated_lufs, serializer);
+        <f64>::sse_encode(self.loudness_range_lu, serializer);
+        <f64>::sse_encode(self.true_peak, serializer);
+        <f64>::sse_encode(self.gain_db, serializer);
+    }
+}
+
+impl SseEncode for crate::api::artwork::ArtSource {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::artwork::ArtSource::Embedded { picture_type } => {
+                <i32>::sse_encode(0, serializer);
+                <crate::api::artwork::PictureType>::sse_encode(picture_type, serializer);
+            }
+            crate::api::artwork::ArtSource::Folder { path } => {
+                <i32>::sse_encode(1, serializer);
+                <crate::api::path::TrackPath>::sse_encode(path, serializer);
+            }
+        }
+    }
+}
+
+impl SseEncode for crate::api::utils::AudioFormat {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::utils::AudioFormat::Aac => 0,
+                crate::api::utils::AudioFormat::Aiff => 1,
+                crate::api::utils::AudioFormat::Ape => 2,
+                crate::api::utils::AudioFormat::Flac => 3,
+                crate::api::utils::AudioFormat::Mpeg => 4,
+                crate::api::utils::AudioFormat::Mp4 => 5,
+                crate::api::utils::AudioFormat::Mpc => 6,
+                crate::api::utils::AudioFormat::Opus => 7,
+                crate::api::utils::AudioFormat::Vorbis => 8,
+                crate::api::utils::AudioFormat::Speex => 9,
+                crate::api::utils::AudioFormat::Wav => 10,
+                crate::api::utils::AudioFormat::WavPack => 11,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::metadata::AudioProperties {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.duration_ms, serializer);
+        <Option<u32>>::sse_encode(self.overall_bitrate, serializer);
+        <Option<u32>>::sse_encode(self.audio_bitrate, serializer);
+        <Option<u32>>::sse_encode(self.sample_rate, serializer);
+        <Option<u8>>::sse_encode(self.bit_depth, serializer);
+        <Option<u8>>::sse_encode(self.channels, serializer);
+    }
+}
+
+impl SseEncode for crate::api::batch_edit::BatchEdit {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::metadata::MetadataChanges>::sse_encode(self.changes, serializer);
+        <Option<crate::api::batch_edit::Renumber>>::sse_encode(self.renumber, serializer);
+    }
+}
+
+impl SseEncode for crate::api::batch_edit::BatchFile {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::path::TrackPath>::sse_encode(self.path, serializer);
+        <crate::api::library::FileStamp>::sse_encode(self.stamp, serializer);
+    }
+}
+
+impl SseEncode for crate::api::batch_edit::BatchFileResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::path::TrackPath>::sse_encode(self.path, serializer);
+        <Option<crate::api::library::FileStamp>>::sse_encode(self.stamp, serializer);
+        <Vec<crate::api::batch_edit::FieldChange>>::sse_encode(self.changes, serializer);
+        <Option<crate::api::metadata::MetadataError>>::sse_encode(self.error, serializer);
+    }
+}
+
+impl SseEncode for crate::api::batch_edit::BatchOutcome {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.journal_id, serializer);
+        <Vec<crate::api::batch_edit::BatchFileResult>>::sse_encode(self.results, serializer);
+    }
+}
+
+impl SseEncode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u8(self as _).unwrap();
+    }
+}
+
+impl SseEncode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_f64::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for crate::api::batch_edit::FieldChange {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::metadata::MetadataField>::sse_encode(self.field, serializer);
+        <Option<String>>::sse_encode(self.before, serializer);
+        <Option<String>>::sse_encode(self.after, serializer);
+    }
+}
+
+impl SseEncode for crate::api::library::FileStamp {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.size, serializer);
+        <i64>::sse_encode(self.mtime, serializer);
+    }
+}
+
+impl SseEncode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for i64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i64::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for crate::api::library::LibraryDiff {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::library::LibraryTrack>>::sse_encode(self.added, serializer);
+        <Vec<u64>>::sse_encode(self.removed, serializer);
+        <Vec<crate::api::library::LibraryTrack>>::sse_encode(self.modified, serializer);
+        <Vec<crate::api::library::TrackMove>>::sse_encode(self.moved, serializer);
+    }
+}
+
+impl SseEncode for crate::api::watcher::LibraryEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::watcher::LibraryEvent::TrackAdded(field0) => {
+                <i32>::sse_encode(0, serializer);
+                <crate::api::library::LibraryTrack>::sse_encode(field0, serializer);
+            }
+            crate::api::watcher::LibraryEvent::TrackRemoved { id } => {
+                <i32>::sse_encode(1, serializer);
+                <usize>::sse_encode(id, serializer);
+            }
+            crate::api::watcher::LibraryEvent::TrackChanged(field0) => {
+                <i32>::sse_encode(2, serializer);
+                <crate::api::library::LibraryTrack>::sse_encode(field0, serializer);
+            }
+            crate::api::watcher::LibraryEvent::TrackMoved { from, track } => {
+                <i32>::sse_encode(3, serializer);
+                <crate::api::path::TrackPath>::sse_encode(from, serializer);
+                <crate::api::library::LibraryTrack>::sse_encode(track, serializer);
+            }
+        }
+    }
+}
+
+impl SseEncode for crate::api::config::LibraryRoot {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::path::TrackPath>::sse_encode(self.path, serializer);
+        <bool>::sse_encode(self.enabled, serializer);
+    }
+}
+
+impl SseEncode for crate::api::library::LibraryTrack {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::utils::Track>::sse_encode(self.track, serializer);
+        <Option<String>>::sse_encode(self.title, serializer);
+        <Option<String>>::sse_encode(self.artist, serializer);
+        <Option<String>>::sse_encode(self.album, serializer);
+        <Option<String>>::sse_encode(self.album_artist, serializer);
+        <Option<String>>::sse_encode(self.genre, serializer);
+        <Option<u32>>::sse_encode(self.year, serializer);
+        <Option<u32>>::sse_encode(self.track_number, serializer);
+        <Option<u32>>::sse_encode(self.disc_number, serializer);
+        <Option<u64>>::sse_encode(self.duration_ms, serializer);
+    }
+}
+
+impl SseEncode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <String>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::library::Album> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::library::Album>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::batch_edit::BatchFile> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::batch_edit::BatchFile>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::batch_edit::BatchFileResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::batch_edit::BatchFileResult>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::batch_edit::FieldChange> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::batch_edit::FieldChange>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::config::LibraryRoot> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::config::LibraryRoot>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::library::LibraryTrack> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::library::LibraryTrack>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::loudness::LoudnessFailure> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::loudness::LoudnessFailure>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::lyrics::LyricLine> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::lyrics::LyricLine>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::lyrics::LyricWord> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::lyrics::LyricWord>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::lyrics::Lyrics> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::lyrics::Lyrics>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::metadata::MetadataField> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::metadata::MetadataField>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<Option<crate::api::thumbnail::Thumbnail>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <Option<crate::api::thumbnail::Thumbnail>>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::artwork::PictureInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::artwork::PictureInfo>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u64>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u8>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::scan::SkippedFile> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::scan::SkippedFile>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::utils::Track> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::utils::Track>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::loudness::TrackLoudness> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::loudness::TrackLoudness>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::library::TrackMove> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::library::TrackMove>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::path::TrackPath> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::path::TrackPath>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for crate::api::loudness::LoudnessFailure {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::path::TrackPath>::sse_encode(self.path, serializer);
+        <String>::sse_encode(self.error, serializer);
+    }
+}
+
+impl SseEncode for crate::api::loudness::LoudnessReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::loudness::TrackLoudness>>::sse_encode(self.tracks, serializer);
+        <Option<crate::api::loudness::AlbumLoudness>>::sse_encode(self.album, serializer);
+        <Vec<crate::api::loudness::LoudnessFailure>>::sse_encode(self.failed, serializer);
+    }
+}
+
+impl SseEncode for crate::api::lyrics::LyricLine {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<u64>>::sse_encode(self.start_ms, serializer);
+        <String>::sse_encode(self.text, serializer);
+        <Vec<crate::api::lyrics::LyricWord>>::sse_encode(self.words, serializer);
+    }
+}
+
+impl SseEncode for crate::api::lyrics::LyricWord {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.start_ms, serializer);
+        <Option<u64>>::sse_encode(self.end_ms, serializer);
+        <String>::sse_encode(self.text, serializer);
+    }
+}
+
+impl SseEncode for crate::api::lyrics::Lyrics {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::lyrics::LyricsSource>::sse_encode(self.source, serializer);
+        <Option<String>>::sse_encode(self.language, serializer);
+        <bool>::sse_encode(self.synced, serializer);
+        <i64>::sse_encode(self.offset_ms, serializer);
+        <Vec<crate::api::lyrics::LyricLine>>::sse_encode(self.lines, serializer);
+        <LyricsTimeline>::sse_encode(self.timeline, serializer);
+    }
+}
+
+impl SseEncode for crate::api::lyrics::LyricsPosition {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<usize>>::sse_encode(self.line, serializer);
+        <Option<usize>>::sse_encode(self.word, serializer);
+        <f64>::sse_encode(self.word_progress, serializer);
+    }
+}
+
+impl SseEncode for crate::api::lyrics::LyricsSource {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::lyrics::LyricsSource::Tag => {
+                <i32>::sse_encode(0, serializer);
+            }
+            crate::api::lyrics::LyricsSource::SyncedTag => {
+                <i32>::sse_encode(1, serializer);
+            }
+            crate::api::lyrics::LyricsSource::Sidecar { path } => {
+                <i32>::sse_encode(2, serializer);
+                <crate::api::path::TrackPath>::sse_encode(path, serializer);
+            }
+        }
+    }
+}
+
+impl SseEncode for crate::api::metadata::Metadata {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<Vec<u8>>>::sse_encode(self.art, serializer);
+        <Option<crate::api::artwork::ArtSource>>::sse_encode(self.art_source, serializer);
+        <Option<String>>::sse_encode(self.title, serializer);
+        <Option<String>>::sse_encode(self.artist, serializer);
+        <Option<String>>::sse_encode(self.album, serializer);
+        <Option<String>>::sse_encode(self.album_artist, serializer);
+        <Option<u32>>::sse_encode(self.track_number, serializer);
+        <Option<u32>>::sse_encode(self.track_total, serializer);
+        <Option<u32>>::sse_encode(self.disc_number, serializer);
+        <Option<u32>>::sse_encode(self.disc_total, serializer);
+        <Option<u32>>::sse_encode(self.year, serializer);
+        <Option<String>>::sse_encode(self.genre, serializer);
+        <Option<String>>::sse_encode(self.composer, serializer);
+        <Option<String>>::sse_encode(self.comment, serializer);
+        <Option<String>>::sse_encode(self.title_sort, serializer);
+        <Option<String>>::sse_encode(self.artist_sort, serializer);
+        <Option<String>>::sse_encode(self.album_sort, serializer);
+        <Option<String>>::sse_encode(self.album_artist_sort, serializer);
+        <crate::api::metadata::AudioProperties>::sse_encode(self.properties, serializer);
+        <crate::api::metadata::ReplayGain>::sse_encode(self.replay_gain, serializer);
+        <crate::api::library::FileStamp>::sse_encode(self.stamp, serializer);
+    }
+}
+
+impl SseEncode for crate::api::metadata::MetadataChanges {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.title, serializer);
+        <Option<String>>::sse_encode(self.artist, serializer);
+        <Option<String>>::sse_encode(self.album, serializer);
+        <Option<String>>::sse_encode(self.album_artist, serializer);
+        <Option<u32>>::sse_encode(self.track_number, serializer);
+        <Option<u32>>::sse_encode(self.track_total, serializer);
+        <Option<u32>>::sse_encode(self.disc_number, serializer);
+        <Option<u32>>::sse_encode(self.disc_total, serializer);
+        <Option<u32>>::sse_encode(self.year, serializer);
+        <Option<String>>::sse_encode(self.genre, serializer);
+        <Option<String>>::sse_encode(self.composer, serializer);
+        <Option<String>>::sse_encode(self.comment, serializer);
+        <Option<String>>::sse_encode(self.lyrics, serializer);
+        <Vec<crate::api::metadata::MetadataField>>::sse_encode(self.clear, serializer);
+    }
+}
+
+impl SseEncode for crate::api::metadata::MetadataError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::metadata::MetadataError::NotFound => {
+                <i32>::sse_encode(0, serializer);
+            }
+            crate::api::metadata::MetadataError::UnsupportedFormat => {
+                <i32>::sse_encode(1, serializer);
+            }
+            crate::api::metadata::MetadataError::CorruptTag(field0) => {
+                <i32>::sse_encode(2, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::metadata::MetadataError::Modified => {
+                <i32>::sse_encode(3, serializer);
+            }
+            crate::api::metadata::MetadataError::Io(field0) => {
+                <i32>::sse_encode(4, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+        }
+    }
+}
+
+impl SseEncode for crate::api::metadata::MetadataField {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::metadata::MetadataField::Title => 0,
+                crate::api::metadata::MetadataField::Artist => 1,
+                crate::api::metadata::MetadataField::Album => 2,
+                crate::api::metadata::MetadataField::AlbumArtist => 3,
+                crate::api::metadata::MetadataField::TrackNumber => 4,
+                crate::api::metadata::MetadataField::TrackTotal => 5,
+                crate::api::metadata::MetadataField::DiscNumber => 6,
+                crate::api::metadata::MetadataField::DiscTotal => 7,
+                crate::api::metadata::MetadataField::Year => 8,
+                crate::api::metadata::MetadataField::Genre => 9,
+                crate::api::metadata::MetadataField::Composer => 10,
+                crate::api::metadata::MetadataField::Comment => 11,
+                crate::api::metadata::MetadataField::Lyrics => 12,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <String>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::loudness::AlbumLoudness> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::loudness::AlbumLoudness>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::artwork::ArtSource> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::artwork::ArtSource>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<f64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <f64>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::library::FileStamp> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::library::FileStamp>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::library::LibraryTrack> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::library::LibraryTrack>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::metadata::MetadataError> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::metadata::MetadataError>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::palette::Palette> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::palette::Palette>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::batch_edit::Renumber> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::batch_edit::Renumber>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseDecode for Option<Tag> {
+impl SseEncode for Option<crate::api::palette::Swatch> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Tag>::sse_decode(deserializer));
-        } else {
-            return None;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::palette::Swatch>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseDecode for Option<usize> {
+impl SseEncode for Option<crate::api::thumbnail::Thumbnail> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<usize>::sse_decode(deserializer));
-        } else {
-            return None;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::thumbnail::Thumbnail>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseDecode for Option<Vec<u8>> {
+impl SseEncode for Option<crate::api::loudness::TrackLoudness> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Vec<u8>>::sse_decode(deserializer));
-        } else {
-            return None;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::loudness::TrackLoudness>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseDecode for crate::api::utils::Track {
+impl SseEncode for Option<u32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_name = <String>::sse_decode(deserializer);
-        let mut var_path = <String>::sse_decode(deserializer);
-        let mut var_id = <Option<usize>>::sse_decode(deserializer);
-        return crate::api::utils::Track {
-            name: var_name,
-            path: var_path,
-            id: var_id,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u32>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseDecode for u8 {
+impl SseEncode for Option<u64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u64>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseDecode for usize {
+impl SseEncode for Option<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u64::<NativeEndian>().unwrap() as _
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u8>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseDecode for i32 {
+impl SseEncode for Option<usize> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <usize>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseDecode for bool {
+impl SseEncode for Option<Vec<u8>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap() != 0
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <Vec<u8>>::sse_encode(value, serializer);
+        }
     }
 }
 
-fn pde_ffi_dispatcher_primary_impl(
-    func_id: i32,
-    port: flutter_rust_bridge::for_generated::MessagePort,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        1 => wire_get_metadata_impl(port, ptr, rust_vec_len, data_len),
-        2 => wire_get_music_files_impl(port, ptr, rust_vec_len, data_len),
-        _ => unreachable!(),
+impl SseEncode for crate::api::palette::Palette {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::palette::Swatch>::sse_encode(self.dominant, serializer);
+        <Option<crate::api::palette::Swatch>>::sse_encode(self.vibrant, serializer);
+        <Option<crate::api::palette::Swatch>>::sse_encode(self.muted, serializer);
     }
 }
 
-fn pde_ffi_dispatcher_sync_impl(
-    func_id: i32,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        _ => unreachable!(),
+impl SseEncode for crate::api::artwork::PictureInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <usize>::sse_encode(self.index, serializer);
+        <crate::api::artwork::PictureType>::sse_encode(self.picture_type, serializer);
+        <Option<String>>::sse_encode(self.mime_type, serializer);
+        <Option<u32>>::sse_encode(self.width, serializer);
+        <Option<u32>>::sse_encode(self.height, serializer);
+        <Option<String>>::sse_encode(self.description, serializer);
+        <usize>::sse_encode(self.size, serializer);
     }
 }
 
-// Section: rust2dart
-
-pub struct Local_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockTag(Tag);
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart
-    for Local_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockTag
-{
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
-    }
-}
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for Local_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockTag
-{
-}
-impl
-    flutter_rust_bridge::IntoIntoDart<
-        Local_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockTag,
-    > for Tag
-{
-    fn into_into_dart(
-        self,
-    ) -> Local_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockTag {
-        Local_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockTag(self)
-    }
-}
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::metadata::Metadata {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.tag.into_into_dart().into_dart(),
-            self.art.into_into_dart().into_dart(),
-            self.title.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+impl SseEncode for crate::api::artwork::PictureType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::artwork::PictureType::Other => {
+                <i32>::sse_encode(0, serializer);
+            }
+            crate::api::artwork::PictureType::Icon => {
+                <i32>::sse_encode(1, serializer);
+            }
+            crate::api::artwork::PictureType::OtherIcon => {
+                <i32>::sse_encode(2, serializer);
+            }
+            crate::api::artwork::PictureType::CoverFront => {
+                <i32>::sse_encode(3, serializer);
+            }
+            crate::api::artwork::PictureType::CoverBack => {
+                <i32>::sse_encode(4, serializer);
+            }
+            crate::api::artwork::PictureType::Leaflet => {
+                <i32>::sse_encode(5, serializer);
+            }
+            crate::api::artwork::PictureType::Media => {
+                <i32>::sse_encode(6, serializer);
+            }
+            crate::api::artwork::PictureType::LeadArtist => {
+                <i32>::sse_encode(7, serializer);
+            }
+            crate::api::artwork::PictureType::Artist => {
+                <i32>::sse_encode(8, serializer);
+            }
+            crate::api::artwork::PictureType::Conductor => {
+                <i32>::sse_encode(9, serializer);
+            }
+            crate::api::artwork::PictureType::Band => {
+                <i32>::sse_encode(10, serializer);
+            }
+            crate::api::artwork::PictureType::Composer => {
+                <i32>::sse_encode(11, serializer);
+            }
+            crate::api::artwork::PictureType::Lyricist => {
+                <i32>::sse_encode(12, serializer);
+            }
+            crate::api::artwork::PictureType::RecordingLocation => {
+                <i32>::sse_encode(13, serializer);
+            }
+            crate::api::artwork::PictureType::DuringRecording => {
+                <i32>::sse_encode(14, serializer);
+            }
+            crate::api::artwork::PictureType::DuringPerformance => {
+                <i32>::sse_encode(15, serializer);
+            }
+            crate::api::artwork::PictureType::ScreenCapture => {
+                <i32>::sse_encode(16, serializer);
+            }
+            crate::api::artwork::PictureType::BrightFish => {
+                <i32>::sse_encode(17, serializer);
+            }
+            crate::api::artwork::PictureType::Illustration => {
+                <i32>::sse_encode(18, serializer);
+            }
+            crate::api::artwork::PictureType::BandLogo => {
+                <i32>::sse_encode(19, serializer);
+            }
+            crate::api::artwork::PictureType::PublisherLogo => {
+                <i32>::sse_encode(20, serializer);
+            }
+            crate::api::artwork::PictureType::Undefined(field0) => {
+                <i32>::sse_encode(21, serializer);
+                <u8>::sse_encode(field0, serializer);
+            }
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::metadata::Metadata
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::metadata::Metadata>
-    for crate::api::metadata::Metadata
-{
-    fn into_into_dart(self) -> crate::api::metadata::Metadata {
-        self
+
+impl SseEncode for crate::api::batch_edit::Renumber {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.start, serializer);
+        <bool>::sse_encode(self.set_total, serializer);
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::utils::Track {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.name.into_into_dart().into_dart(),
-            self.path.into_into_dart().into_dart(),
-            self.id.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for crate::api::metadata::ReplayGain {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<f64>>::sse_encode(self.track_gain_db, serializer);
+        <Option<f64>>::sse_encode(self.track_peak, serializer);
+        <Option<f64>>::sse_encode(self.album_gain_db, serializer);
+        <Option<f64>>::sse_encode(self.album_peak, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::utils::Track {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::utils::Track> for crate::api::utils::Track {
-    fn into_into_dart(self) -> crate::api::utils::Track {
-        self
+
+impl SseEncode for crate::api::loudness::ReplayGainMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::loudness::ReplayGainMode::Off => {
+                <i32>::sse_encode(0, serializer);
+            }
+            crate::api::loudness::ReplayGainMode::Track => {
+                <i32>::sse_encode(1, serializer);
+            }
+            crate::api::loudness::ReplayGainMode::Album => {
+                <i32>::sse_encode(2, serializer);
+            }
+            crate::api::loudness::ReplayGainMode::Smart { album_in_order } => {
+                <i32>::sse_encode(3, serializer);
+                <bool>::sse_encode(album_in_order, serializer);
+            }
+        }
     }
 }
 
-impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
+impl SseEncode for crate::api::scan::ScanEvent {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(format!("{:?}", self), serializer);
+        match self {
+            crate::api::scan::ScanEvent::Progress(field0) => {
+                <i32>::sse_encode(0, serializer);
+                <crate::api::scan::ScanProgress>::sse_encode(field0, serializer);
+            }
+            crate::api::scan::ScanEvent::Skipped(field0) => {
+                <i32>::sse_encode(1, serializer);
+                <crate::api::scan::SkippedFile>::sse_encode(field0, serializer);
+            }
+            crate::api::scan::ScanEvent::Finished(field0) => {
+                <i32>::sse_encode(2, serializer);
+                <crate::api::library::LibraryDiff>::sse_encode(field0, serializer);
+            }
+            crate::api::scan::ScanEvent::Cancelled => {
+                <i32>::sse_encode(3, serializer);
+            }
+        }
     }
 }
 
-impl SseEncode for Tag {
+impl SseEncode for crate::api::scan::ScanPhase {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <RustOpaqueMoi<flutter_rust_bridge::for_generated::rust_async::RwLock<Tag>>>::sse_encode(
-            flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self),
+        <i32>::sse_encode(
+            match self {
+                crate::api::scan::ScanPhase::Discovering => 0,
+                crate::api::scan::ScanPhase::Detecting => 1,
+                crate::api::scan::ScanPhase::ReadingTags => 2,
+                crate::api::scan::ScanPhase::Saving => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
             serializer,
         );
     }
 }
 
-impl SseEncode for RustOpaqueMoi<flutter_rust_bridge::for_generated::rust_async::RwLock<Tag>> {
+impl SseEncode for crate::api::scan::ScanProgress {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        let (ptr, size) = self.sse_encode_raw();
-        <usize>::sse_encode(ptr, serializer);
-        <i32>::sse_encode(size, serializer);
+        <crate::api::scan::ScanPhase>::sse_encode(self.phase, serializer);
+        <usize>::sse_encode(self.files_seen, serializer);
+        <usize>::sse_encode(self.files_done, serializer);
+        <usize>::sse_encode(self.files_total, serializer);
+        <Option<String>>::sse_encode(self.current_path, serializer);
     }
 }
 
-impl SseEncode for String {
+impl SseEncode for crate::api::scan::ScanReport {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+        <Vec<crate::api::utils::Track>>::sse_encode(self.tracks, serializer);
+        <Vec<crate::api::scan::SkippedFile>>::sse_encode(self.skipped, serializer);
     }
 }
 
-impl SseEncode for Vec<u8> {
+impl SseEncode for crate::api::scan::SkipReason {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <u8>::sse_encode(item, serializer);
+        match self {
+            crate::api::scan::SkipReason::PermissionDenied => {
+                <i32>::sse_encode(0, serializer);
+            }
+            crate::api::scan::SkipReason::UnsupportedFormat => {
+                <i32>::sse_encode(1, serializer);
+            }
+            crate::api::scan::SkipReason::CorruptHeader => {
+                <i32>::sse_encode(2, serializer);
+            }
+            crate::api::scan::SkipReason::SymlinkLoop => {
+                <i32>::sse_encode(3, serializer);
+            }
+            crate::api::scan::SkipReason::Io(field0) => {
+                <i32>::sse_encode(4, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
         }
     }
 }
 
-impl SseEncode for Vec<crate::api::utils::Track> {
+impl SseEncode for crate::api::scan::SkippedFile {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::utils::Track>::sse_encode(item, serializer);
-        }
+        <crate::api::path::TrackPath>::sse_encode(self.path, serializer);
+        <crate::api::scan::SkipReason>::sse_encode(self.reason, serializer);
     }
 }
 
-impl SseEncode for crate::api::metadata::Metadata {
+impl SseEncode for crate::api::palette::Swatch {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Option<Tag>>::sse_encode(self.tag, serializer);
-        <Option<Vec<u8>>>::sse_encode(self.art, serializer);
-        <Option<String>>::sse_encode(self.title, serializer);
+        <u32>::sse_encode(self.color, serializer);
+        <u32>::sse_encode(self.foreground, serializer);
+        <f64>::sse_encode(self.population, serializer);
     }
 }
 
-impl SseEncode for Option<String> {
+impl SseEncode for crate::api::thumbnail::Thumbnail {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <String>::sse_encode(value, serializer);
-        }
+        <Vec<u8>>::sse_encode(self.data, serializer);
+        <String>::sse_encode(self.art_hash, serializer);
     }
 }
 
-impl SseEncode for Option<Tag> {
+impl SseEncode for crate::api::utils::Track {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Tag>::sse_encode(value, serializer);
-        }
+        <String>::sse_encode(self.name, serializer);
+        <crate::api::path::TrackPath>::sse_encode(self.path, serializer);
+        <Option<usize>>::sse_encode(self.id, serializer);
+        <crate::api::utils::AudioFormat>::sse_encode(self.format, serializer);
     }
 }
 
-impl SseEncode for Option<usize> {
+impl SseEncode for crate::api::loudness::TrackLoudness {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <usize>::sse_encode(value, serializer);
-        }
+        <crate::api::path::TrackPath>::sse_encode(self.path, serializer);
+        <f64>::sse_encode(self.integrated_lufs, serializer);
+        <f64>::sse_encode(self.loudness_range_lu, serializer);
+        <f64>::sse_encode(self.true_peak, serializer);
+        <f64>::sse_encode(self.track_gain_db, serializer);
+        <Option<f64>>::sse_encode(self.album_gain_db, serializer);
+        <Option<f64>>::sse_encode(self.album_true_peak, serializer);
+        <Option<String>>::sse_encode(self.tag_error, serializer);
     }
 }
 
-impl SseEncode for Option<Vec<u8>> {
+impl SseEncode for crate::api::library::TrackMove {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Vec<u8>>::sse_encode(value, serializer);
-        }
+        <crate::api::path::TrackPath>::sse_encode(self.from, serializer);
+        <crate::api::library::LibraryTrack>::sse_encode(self.track, serializer);
     }
 }
 
-impl SseEncode for crate::api::utils::Track {
+impl SseEncode for crate::api::path::TrackPath {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.name, serializer);
-        <String>::sse_encode(self.path, serializer);
-        <Option<usize>>::sse_encode(self.id, serializer);
+        <Vec<u8>>::sse_encode(self.raw, serializer);
+        <String>::sse_encode(self.display, serializer);
     }
 }
 
-impl SseEncode for u8 {
+impl SseEncode for u32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_u8(self).unwrap();
+        serializer.cursor.write_u32::<NativeEndian>(self).unwrap();
     }
 }
 
-impl SseEncode for usize {
+impl SseEncode for u64 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer
-            .cursor
-            .write_u64::<NativeEndian>(self as _)
-            .unwrap();
+        serializer.cursor.write_u64::<NativeEndian>(self).unwrap();
     }
 }
 
-impl SseEncode for i32 {
+impl SseEncode for u8 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+        serializer.cursor.write_u8(self).unwrap();
     }
 }
 
-impl SseEncode for bool {
+impl SseEncode for () {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {}
+}
+
+impl SseEncode for usize {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_u8(self as _).unwrap();
+        serializer
+            .cursor
+            .write_u64::<NativeEndian>(self as _)
+            .unwrap();
     }
 }
 